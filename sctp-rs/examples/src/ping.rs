@@ -26,12 +26,13 @@ async fn main() -> std::io::Result<()> {
     let (connected, assoc_id) = client_socket.sctp_connectx(&[server_address]).await?;
     eprintln!("conected: {:#?}, assoc_id: {}", connected, assoc_id);
 
+    // Each ping waits for the pong, disable the delayed SACK so that the pings are acknowledged
+    // right away.
+    connected.set_delayed_sack(assoc_id, 0, 0)?;
+
     for i in 0..10 {
         let message = format!("sctp-rs ping : {}", i);
-        let send_data = sctp_rs::SendData {
-            payload: message.as_bytes().to_vec(),
-            snd_info: None,
-        };
+        let send_data = sctp_rs::SendData::new(message.as_bytes().to_vec());
         connected.sctp_send(send_data).await?;
         let received = connected.sctp_recv().await?;
         eprintln!("received: {:#?}", received);
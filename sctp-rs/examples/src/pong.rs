@@ -30,16 +30,13 @@ async fn main() -> std::io::Result<()> {
 
     loop {
         let received = accepted.sctp_recv().await?;
+        if let sctp_rs::NotificationOrData::Eof = received {
+            break;
+        }
         if let sctp_rs::NotificationOrData::Data(data) = received {
             eprintln!("received: {:#?}", data);
-            if data.payload.is_empty() {
-                break;
-            }
             let response = format!("pong: {}", String::from_utf8(data.payload).unwrap());
-            let send_data = sctp_rs::SendData {
-                payload: response.as_bytes().to_vec(),
-                snd_info: None,
-            };
+            let send_data = sctp_rs::SendData::new(response.as_bytes().to_vec());
             accepted.sctp_send(send_data).await?;
         }
     }
@@ -0,0 +1,95 @@
+//! A simple benchmark comparing `sctp_recv` and `sctp_recv_batch` on a `OneToMany` socket.
+//!
+//! A client sends a number of messages to a listener on the loopback and the time taken by the
+//! listener for receiving all the messages is reported, first receiving one message per call
+//! and then receiving them in batches.
+//!
+
+use clap::{Arg, Command};
+
+use sctp_rs::{NotificationOrData, SendData, Socket, SocketToAssociation};
+
+async fn send_messages(connected: &sctp_rs::ConnectedSocket, count: usize) -> std::io::Result<()> {
+    for i in 0..count {
+        let send_data = SendData {
+            payload: format!("sctp-rs recv_batch : {}", i).into_bytes(),
+            ..Default::default()
+        };
+        connected.sctp_send(send_data).await?;
+    }
+    Ok(())
+}
+
+fn data_count(messages: &[NotificationOrData]) -> usize {
+    messages
+        .iter()
+        .filter(|m| matches!(m, NotificationOrData::Data(_)))
+        .count()
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> std::io::Result<()> {
+    let app = Command::new("sctp-rs recv_batch benchmark")
+        .author("Abhijit Gadgil <gabhijit@iitbombay.org>")
+        .arg(
+            Arg::new("count")
+                .num_args(1)
+                .long("count")
+                .default_value("10000")
+                .value_parser(clap::value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("batch")
+                .num_args(1)
+                .long("batch")
+                .default_value("32")
+                .value_parser(clap::value_parser!(usize)),
+        );
+
+    let matches = app.get_matches();
+    let count = *matches.get_one::<usize>("count").unwrap();
+    let batch = *matches.get_one::<usize>("batch").unwrap();
+
+    let server_address: std::net::SocketAddr = "127.0.0.1:0".parse().unwrap();
+    let server_socket = Socket::new_v4(SocketToAssociation::OneToMany)?;
+    server_socket.bind(server_address)?;
+    let listener = server_socket.listen(10)?;
+    let server_address = listener.sctp_getladdrs(0.into())?[0];
+
+    let client_socket = Socket::new_v4(SocketToAssociation::OneToOne)?;
+    let (connected, _) = client_socket.sctp_connectx(&[server_address]).await?;
+
+    // One `recvmsg` per message.
+    let start = std::time::Instant::now();
+    let receive = async {
+        let mut received = 0;
+        while received < count {
+            if let NotificationOrData::Data(_) = listener.sctp_recv().await? {
+                received += 1;
+            }
+        }
+        Ok::<_, std::io::Error>(received)
+    };
+    tokio::try_join!(send_messages(&connected, count), receive)?;
+    let single = start.elapsed();
+
+    // One `recvmmsg` per up to `batch` messages.
+    let start = std::time::Instant::now();
+    let receive = async {
+        let mut received = 0;
+        let mut calls = 0;
+        while received < count {
+            received += data_count(&listener.sctp_recv_batch(batch).await?);
+            calls += 1;
+        }
+        Ok::<_, std::io::Error>(calls)
+    };
+    let (_, calls) = tokio::try_join!(send_messages(&connected, count), receive)?;
+    let batched = start.elapsed();
+
+    eprintln!("messages: {}", count);
+    eprintln!("sctp_recv: {} calls, {:?}", count, single);
+    eprintln!("sctp_recv_batch({}): {} calls, {:?}", batch, calls, batched);
+
+    Ok(())
+}
@@ -0,0 +1,318 @@
+//! Blocking SCTP Sockets (enabled by the `blocking` feature).
+//!
+//! The types in this module are the blocking counterparts of the [`Socket`][`crate::Socket`],
+//! [`Listener`][`crate::Listener`] and [`ConnectedSocket`][`crate::ConnectedSocket`]. The
+//! underlying sockets are left in the blocking mode and are not registered with any async runtime,
+//! so these types can be used without an async runtime (eg. in CLI tools or tests). The calls that
+//! send, receive, `accept` or `connect` block the calling thread till they complete.
+//!
+//! ```rust,no_run
+//! # fn main() -> std::io::Result<()> {
+//! use sctp_rs::blocking::Socket;
+//! use sctp_rs::{NotificationOrData, SendData, SocketToAssociation};
+//!
+//! let server_addr: std::net::SocketAddr = "127.0.0.1:8080".parse().unwrap();
+//!
+//! let client = Socket::new_v4(SocketToAssociation::OneToOne)?;
+//! let (connected, _assoc_id) = client.sctp_connectx(&[server_addr])?;
+//!
+//! connected.sctp_send(SendData::new(b"hello".to_vec()))?;
+//! if let NotificationOrData::Data(data) = connected.sctp_recv()? {
+//!     println!("Received: {:?}", data.payload);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::net::SocketAddr;
+use std::os::unix::io::{AsRawFd, RawFd};
+
+use crate::{
+    AssociationId, BindxFlags, ConnStatus, Event, NotificationOrData, SendData, SocketFlags,
+    SocketToAssociation, SubscribeEventAssocId,
+};
+
+#[allow(unused)]
+use crate::internal::*;
+
+/// A blocking unconnected SCTP Socket. See [`Socket`][`crate::Socket`].
+pub struct Socket {
+    fd: RawFd,
+}
+
+impl Socket {
+    /// Create a New IPv4 family blocking socket.
+    ///
+    /// See [`Socket::new_v4`][`crate::Socket::new_v4`] for details.
+    pub fn new_v4(assoc: SocketToAssociation) -> std::io::Result<Self> {
+        Self::new(libc::AF_INET, assoc)
+    }
+
+    /// Create a New IPv6 family blocking socket.
+    ///
+    /// See [`Socket::new_v6`][`crate::Socket::new_v6`] for details.
+    pub fn new_v6(assoc: SocketToAssociation) -> std::io::Result<Self> {
+        Self::new(libc::AF_INET6, assoc)
+    }
+
+    fn new(domain: libc::c_int, assoc: SocketToAssociation) -> std::io::Result<Self> {
+        Ok(Self {
+            fd: sctp_socket_internal(domain, assoc, SocketFlags::default(), false)?,
+        })
+    }
+
+    /// Bind a socket to a given IP Address.
+    pub fn bind(&self, addr: SocketAddr) -> std::io::Result<()> {
+        self.sctp_bindx(&[addr], BindxFlags::Add)
+    }
+
+    /// SCTP Specific extension for binding to multiple addresses on a given socket. See Section
+    /// 9.1 RFC 6458.
+    pub fn sctp_bindx(&self, addrs: &[SocketAddr], flags: BindxFlags) -> std::io::Result<()> {
+        sctp_bindx_internal(&self.fd, addrs, flags)
+    }
+
+    /// Get's the Local Addresses for the association. See: Section 9.4 RFC 6458
+    pub fn sctp_getladdrs(&self, assoc_id: AssociationId) -> std::io::Result<Vec<SocketAddr>> {
+        sctp_getladdrs_internal(&self.fd, assoc_id)
+    }
+
+    /// Listen on a given socket.
+    ///
+    /// This successful operation  returns [`Listener`] consuming this structure. The `backlog`
+    /// parameter determines the length of the listen queue.
+    pub fn listen(self, backlog: i32) -> std::io::Result<Listener> {
+        sctp_listen_syscall(self.fd, backlog)?;
+        Ok(Listener {
+            fd: self.into_raw_fd(),
+        })
+    }
+
+    /// Connect to a multi-homed Peer. See Section 9.9 RFC 6458
+    ///
+    /// This blocks till the association is set up. See
+    /// [`Socket::sctp_connectx`][`crate::Socket::sctp_connectx`] for details.
+    pub fn sctp_connectx(
+        self,
+        addrs: &[SocketAddr],
+    ) -> std::io::Result<(ConnectedSocket, AssociationId)> {
+        let assoc_id = sctp_connectx_syscall(self.fd, addrs)?;
+        let assoc_id = connected_assoc_id(&self.fd, assoc_id)?;
+        Ok((
+            ConnectedSocket {
+                fd: self.into_raw_fd(),
+            },
+            assoc_id,
+        ))
+    }
+
+    /// Subscribe to SCTP Events. See section 6.2.1 of RFC6458.
+    pub fn sctp_subscribe_events(
+        &self,
+        events: &[Event],
+        assoc_id: SubscribeEventAssocId,
+    ) -> std::io::Result<()> {
+        subscribe_events_result(sctp_subscribe_events_internal(
+            &self.fd, events, assoc_id, true,
+        ))
+    }
+
+    /// Request to receive `RcvInfo` ancillary data.
+    pub fn sctp_request_rcvinfo(&self, on: bool) -> std::io::Result<()> {
+        request_rcvinfo_internal(&self.fd, on)
+    }
+
+    /// Request to receive `NxtInfo` ancillary data.
+    pub fn sctp_request_nxtinfo(&self, on: bool) -> std::io::Result<()> {
+        request_nxtinfo_internal(&self.fd, on)
+    }
+
+    // Returns the `fd`, which is now owned by the caller.
+    fn into_raw_fd(self) -> RawFd {
+        let fd = self.fd;
+        std::mem::forget(self);
+        fd
+    }
+}
+
+impl AsRawFd for Socket {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+impl std::fmt::Debug for Socket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_socket_internal(&self.fd, "Socket", f)
+    }
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        close_internal(&self.fd);
+    }
+}
+
+/// A blocking listening SCTP Socket. See [`Listener`][`crate::Listener`].
+pub struct Listener {
+    fd: RawFd,
+}
+
+impl Listener {
+    /// Accept on a given socket (valid only for `OneToOne` type sockets).
+    ///
+    /// This blocks till a new association is set up.
+    pub fn accept(&self) -> std::io::Result<(ConnectedSocket, SocketAddr)> {
+        let (fd, addr) = retry_on_interrupt(|| sctp_accept_syscall(self.fd, 0))?;
+        Ok((ConnectedSocket { fd }, addr))
+    }
+
+    /// Receive Data or Notification from the listening socket.
+    ///
+    /// This blocks till a message is received. Messages larger than 4096 bytes are received in
+    /// parts (See [`RecvFlags::EOR`][`crate::RecvFlags::EOR`]).
+    pub fn sctp_recv(&self) -> std::io::Result<NotificationOrData> {
+        retry_on_interrupt(|| sctp_recvmsg_syscall(self.fd, RECV_BUFFER_SIZE, 0))
+    }
+
+    /// Send Data and Anciliary data if any on the SCTP Socket to the given peer.
+    ///
+    /// This blocks till the message is accepted by the kernel. See
+    /// [`Listener::sctp_send`][`crate::Listener::sctp_send`] for details.
+    pub fn sctp_send(&self, to: SocketAddr, data: SendData) -> std::io::Result<usize> {
+        retry_on_interrupt(|| sctp_sendmsg_syscall(self.fd, Some(to), &data))
+    }
+
+    /// Get's the Local Addresses for the association. See: Section 9.4 RFC 6458
+    pub fn sctp_getladdrs(&self, assoc_id: AssociationId) -> std::io::Result<Vec<SocketAddr>> {
+        sctp_getladdrs_internal(&self.fd, assoc_id)
+    }
+
+    /// Subscribe to SCTP Events. See section 6.2.1 of RFC6458.
+    pub fn sctp_subscribe_events(
+        &self,
+        events: &[Event],
+        assoc_id: SubscribeEventAssocId,
+    ) -> std::io::Result<()> {
+        subscribe_events_result(sctp_subscribe_events_internal(
+            &self.fd, events, assoc_id, true,
+        ))
+    }
+
+    /// Request to receive `RcvInfo` ancillary data.
+    pub fn sctp_request_rcvinfo(&self, on: bool) -> std::io::Result<()> {
+        request_rcvinfo_internal(&self.fd, on)
+    }
+
+    /// Request to receive `NxtInfo` ancillary data.
+    pub fn sctp_request_nxtinfo(&self, on: bool) -> std::io::Result<()> {
+        request_nxtinfo_internal(&self.fd, on)
+    }
+
+    /// Shuts down the read, write, or both halves of this connection.
+    pub fn shutdown(&self, how: std::net::Shutdown) -> std::io::Result<()> {
+        shutdown_internal(&self.fd, how)
+    }
+}
+
+impl AsRawFd for Listener {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+impl std::fmt::Debug for Listener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_socket_internal(&self.fd, "Listener", f)
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        close_internal(&self.fd);
+    }
+}
+
+/// A blocking connected SCTP Socket. See [`ConnectedSocket`][`crate::ConnectedSocket`].
+pub struct ConnectedSocket {
+    fd: RawFd,
+}
+
+impl ConnectedSocket {
+    /// Receive Data or Notification from the connected socket.
+    ///
+    /// This blocks till a message is received. Messages larger than 4096 bytes are received in
+    /// parts (See [`RecvFlags::EOR`][`crate::RecvFlags::EOR`]).
+    pub fn sctp_recv(&self) -> std::io::Result<NotificationOrData> {
+        retry_on_interrupt(|| sctp_recvmsg_syscall(self.fd, RECV_BUFFER_SIZE, 0))
+    }
+
+    /// Send Data and Anciliary data if any on the SCTP Socket.
+    ///
+    /// This blocks till the message is accepted by the kernel. See
+    /// [`ConnectedSocket::sctp_send`][`crate::ConnectedSocket::sctp_send`] for details.
+    pub fn sctp_send(&self, data: SendData) -> std::io::Result<usize> {
+        retry_on_interrupt(|| sctp_sendmsg_syscall(self.fd, None, &data))
+    }
+
+    /// Get Peer addresses for the association. See Section 9.3 RFC 6458.
+    pub fn sctp_getpaddrs(&self, assoc_id: AssociationId) -> std::io::Result<Vec<SocketAddr>> {
+        sctp_getpaddrs_internal(&self.fd, assoc_id)
+    }
+
+    /// Get Local addresses for the association. See section 9.5 RFC 6458.
+    pub fn sctp_getladdrs(&self, assoc_id: AssociationId) -> std::io::Result<Vec<SocketAddr>> {
+        sctp_getladdrs_internal(&self.fd, assoc_id)
+    }
+
+    /// Get the status of the connection associated with the association ID.
+    pub fn sctp_get_status(&self, assoc_id: AssociationId) -> std::io::Result<ConnStatus> {
+        sctp_get_status_internal(&self.fd, assoc_id)
+    }
+
+    /// Subscribe to SCTP Events. See section 6.2.1 of RFC6458.
+    pub fn sctp_subscribe_events(
+        &self,
+        events: &[Event],
+        assoc_id: SubscribeEventAssocId,
+    ) -> std::io::Result<()> {
+        subscribe_events_result(sctp_subscribe_events_internal(
+            &self.fd, events, assoc_id, true,
+        ))
+    }
+
+    /// Request to receive `RcvInfo` ancillary data.
+    pub fn sctp_request_rcvinfo(&self, on: bool) -> std::io::Result<()> {
+        request_rcvinfo_internal(&self.fd, on)
+    }
+
+    /// Request to receive `NxtInfo` ancillary data.
+    pub fn sctp_request_nxtinfo(&self, on: bool) -> std::io::Result<()> {
+        request_nxtinfo_internal(&self.fd, on)
+    }
+
+    /// Perform a TCP like half close.
+    ///
+    /// See [`ConnectedSocket::shutdown`][`crate::ConnectedSocket::shutdown`] for details.
+    pub fn shutdown(&self, how: std::net::Shutdown) -> std::io::Result<()> {
+        shutdown_internal(&self.fd, how)
+    }
+}
+
+impl AsRawFd for ConnectedSocket {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+impl std::fmt::Debug for ConnectedSocket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_socket_internal(&self.fd, "ConnectedSocket", f)
+    }
+}
+
+impl Drop for ConnectedSocket {
+    fn drop(&mut self) {
+        close_internal(&self.fd);
+    }
+}
@@ -1,16 +1,28 @@
 //! A Connected SCTP Socket. This is similar to `TCPStream`.
 
-use tokio::io::unix::AsyncFd;
+#[cfg(feature = "tokio")]
+use tokio::io::{Interest, Ready};
 
+use std::collections::VecDeque;
+use std::io::IoSlice;
 use std::net::SocketAddr;
 use std::os::unix::io::RawFd;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 
+#[cfg(feature = "tokio")]
+use crate::events::split_events;
 #[allow(unused)]
 use crate::internal::*;
+use crate::reactor::{self, RegisteredFd};
+use crate::split::split_owned;
 use crate::{
-    AssociationId, BindxFlags, ConnStatus, Event, NotificationOrData, SendData, SendInfo,
+    AssociationId, BindxFlags, ConnStatus, Event, EventMask, NotificationOrData, OwnedReadHalf,
+    OwnedWriteHalf, PeerAddress, ReceivedInfo, SctpError, SendData, SendInfo, StreamScheduler,
     SubscribeEventAssocId,
 };
+#[cfg(feature = "tokio")]
+use crate::{DataReceiver, NotificationReceiver};
 
 /// A structure representing a Connected SCTP socket.
 ///
@@ -23,7 +35,18 @@ use crate::{
 /// listening socket and the peeled socket is an [`ConnectedSocket`].
 #[derive(Debug)]
 pub struct ConnectedSocket {
-    inner: AsyncFd<RawFd>,
+    inner: RegisteredFd,
+
+    // Messages received while waiting for a notification (See `wait_sender_dry`), that are
+    // returned by the subsequent receive calls, before receiving from the socket.
+    pending: Mutex<VecDeque<NotificationOrData>>,
+
+    // Size of the buffer allocated for receiving a message (See `set_recv_capacity`).
+    recv_capacity: AtomicUsize,
+
+    // Number of the outbound streams of the association, `0` till it is fetched (See
+    // `sctp_send_checked`).
+    outstreams: AtomicUsize,
 }
 
 impl ConnectedSocket {
@@ -36,10 +59,93 @@ impl ConnectedSocket {
     /// One to Many Association) would use this API to create new [`ConnectedSocket`].
     pub fn from_rawfd(rawfd: RawFd) -> std::io::Result<Self> {
         Ok(Self {
-            inner: AsyncFd::new(rawfd)?,
+            inner: RegisteredFd::new(rawfd)?,
+            pending: Mutex::new(VecDeque::new()),
+            recv_capacity: AtomicUsize::new(RECV_BUFFER_SIZE),
+            outstreams: AtomicUsize::new(0),
         })
     }
 
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The new handle refers to the same kernel socket (and hence the same association), the fd
+    /// is duplicated and registered for polling separately. Closing one of the handles (by
+    /// dropping it) does not close the other, the socket is closed when all the handles are
+    /// dropped. This is useful for example, for handing a handle to a background task that sends
+    /// heartbeats.
+    ///
+    /// Note: The messages received while waiting for a notification (See
+    /// [`wait_sender_dry`][`Self::wait_sender_dry`]) are not shared between the handles.
+    pub fn try_clone(&self) -> std::io::Result<Self> {
+        let cloned = Self::from_rawfd(try_clone_internal(&self.inner)?)?;
+        cloned
+            .recv_capacity
+            .store(self.recv_capacity(), Ordering::Relaxed);
+        Ok(cloned)
+    }
+
+    /// Set the size of the buffer allocated for receiving a message.
+    ///
+    /// The buffer of `capacity` bytes is allocated for every message received by the subsequent
+    /// [`sctp_recv`][`Self::sctp_recv`] (or other receive) calls, the default is 4096 bytes. The
+    /// kernel reassembles the fragmented messages before delivering them, a message larger than the
+    /// `capacity` is received in parts (only the last part has the [`EOR`][`crate::RecvFlags::EOR`]
+    /// flag set), so this should be at-least as large as the largest expected message. A smaller
+    /// value avoids over allocation for small messages. A `capacity` of `0` is rejected with
+    /// `InvalidInput`. The handles created by [`try_clone`][`Self::try_clone`] start with the same
+    /// value.
+    pub fn set_recv_capacity(&self, capacity: usize) -> std::io::Result<()> {
+        if capacity == 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "receive capacity must be non-zero",
+            ));
+        }
+        self.recv_capacity.store(capacity, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Get the size of the buffer allocated for receiving a message.
+    ///
+    /// See [`set_recv_capacity`][`Self::set_recv_capacity`] for details.
+    pub fn recv_capacity(&self) -> usize {
+        self.recv_capacity.load(Ordering::Relaxed)
+    }
+
+    /// Split the socket into an owned Read half and an owned Write half.
+    ///
+    /// This is similar to `tokio::net::TcpStream::into_split`, the halves can be moved to
+    /// different tasks, so that receiving and sending can be scheduled independently. The
+    /// underlying socket is closed when both the halves are dropped.
+    pub fn into_split(self) -> (OwnedReadHalf, OwnedWriteHalf) {
+        split_owned(self)
+    }
+
+    /// Split the received Data and Notifications into separate receivers.
+    ///
+    /// A task is spawned (hence this must be called from within a `tokio` runtime) that receives
+    /// from the socket and routes the Data to the [`DataReceiver`] and the Notifications to the
+    /// [`NotificationReceiver`], so that these can be handled in different tasks. The messages
+    /// of each kind are received in order. The channels are bounded by `data_capacity` and
+    /// `notification_capacity` respectively (which must be non-zero). When a channel is full, the
+    /// task stops receiving from the socket till there is space in the channel, so the messages of
+    /// the other kind are *not* delivered either, and the peer is flow controlled eventually.
+    ///
+    /// Dropping one of the receivers discards the messages of that kind only. The socket is
+    /// closed when both the receivers are dropped or when the association is closed. The
+    /// socket can no longer be used for sending, use [`into_split`][`Self::into_split`] for
+    /// sending and receiving from different tasks.
+    ///
+    /// This is available only with the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    pub fn split_events(
+        self,
+        data_capacity: usize,
+        notification_capacity: usize,
+    ) -> (DataReceiver, NotificationReceiver) {
+        split_events(self, data_capacity, notification_capacity)
+    }
+
     /// Perform a TCP like half close.
     ///
     /// Note: however that the semantics for TCP and SCTP half close are different. See section
@@ -48,12 +154,63 @@ impl ConnectedSocket {
         shutdown_internal(&self.inner, how)
     }
 
+    /// Gracefully close the socket.
+    ///
+    /// Shuts down the sending side of the association (See [`shutdown`][`Self::shutdown`]) and
+    /// waits until the SCTP shutdown is complete, before closing the socket. This ensures that
+    /// any data queued for sending is delivered to the peer. Any data received from the peer
+    /// while waiting for the shutdown to complete (or not yet received by the application) is
+    /// discarded, the number of discarded messages is logged. The shutdown is considered
+    /// complete when either a [`Shutdown`][`crate::Notification::Shutdown`] or an
+    /// [`AssociationChange`][`crate::Notification::AssociationChange`] notification with
+    /// [`ShutdownComplete`][`crate::AssocChangeState::ShutdownComplete`] state is received or the
+    /// peer closes the association. The [`Event::Association`] is subscribed to for this.
+    ///
+    /// Note: Dropping the [`ConnectedSocket`] closes the socket immediately without waiting for
+    /// the shutdown to complete (or aborts the association, if [`set_linger`][`Self::set_linger`]
+    /// is set to zero).
+    pub async fn close(self) -> std::io::Result<()> {
+        let discarded =
+            self.pending_data_count() + sctp_graceful_close_internal(&self.inner).await?;
+        if discarded > 0 {
+            log::warn!("Discarded {} message(s) received while closing.", discarded);
+        }
+        Ok(())
+    }
+
+    /// Gracefully close the socket, waiting at-most `timeout` for the shutdown to complete.
+    ///
+    /// This is same as [`close`][`Self::close`], except that if the shutdown does not complete
+    /// within the `timeout`, the socket is closed (the shutdown continues in the background) and
+    /// an error with the OS error code `ETIMEDOUT` is returned.
+    pub async fn close_timeout(self, timeout: std::time::Duration) -> std::io::Result<()> {
+        reactor::timeout(timeout, self.close())
+            .await
+            .unwrap_or_else(|| Err(std::io::Error::from_raw_os_error(libc::ETIMEDOUT)))
+    }
+
+    /// Abort the association sending the `reason` to the peer.
+    ///
+    /// Unlike [`shutdown`][`Self::shutdown`], which performs a graceful close of the association,
+    /// an `ABORT` is sent to the peer immediately and any data queued for sending is discarded.
+    /// The `reason` is delivered to the peer as a 'User Initiated Abort' error cause. See Section
+    /// 5.3.4 of RFC 6458 (`SCTP_ABORT` flag).
+    ///
+    /// Note: The Linux kernel does not allow the `SCTP_ABORT` flag on One to One style sockets
+    /// and such a call will return `EINVAL`. This works only on the sockets peeled off from a One
+    /// to Many style socket (See [`Listener::sctp_peeloff`][`crate::Listener::sctp_peeloff`]).
+    /// A One to One style association can be aborted (without a `reason`) by setting
+    /// [`set_linger`][`Self::set_linger`] to zero and dropping the socket.
+    pub async fn abort(&self, reason: &[u8]) -> std::io::Result<()> {
+        sctp_abort_internal(&self.inner, 0.into(), reason).await
+    }
+
     /// Bind to addresses on the given socket. See Section 9.1 RFC 6458.
     ///
     /// For the connected sockets, this feature is optional and hence will *always* return
     /// `ENOTSUP(EOPNOTSUP)` error.
     pub fn sctp_bindx(&self, _addrs: &[SocketAddr], _flags: BindxFlags) -> std::io::Result<()> {
-        Err(std::io::Error::from_raw_os_error(95))
+        Err(SctpError::NotSupported.into())
     }
 
     /// Get Peer addresses for the association. See Section 9.3 RFC 6458.
@@ -72,17 +229,248 @@ impl ConnectedSocket {
     /// This function returns either the notification (which the user should have subscribed for)
     /// or the data.
     pub async fn sctp_recv(&self) -> std::io::Result<NotificationOrData> {
-        sctp_recvmsg_internal(&self.inner).await
+        if let Some(received) = self.pop_pending() {
+            return Ok(received);
+        }
+        sctp_recvmsg_internal(&self.inner, self.recv_capacity()).await
+    }
+
+    /// Receive Data or Notification into the passed buffer.
+    ///
+    /// This is same as [`sctp_recv`][`Self::sctp_recv`], except that the message is received in the
+    /// spare capacity of the `buf` (`buf` is cleared first) and the length of the `buf` is set to
+    /// the length of the received message. If `buf` has no capacity, the receive capacity (See
+    /// [`set_recv_capacity`][`Self::set_recv_capacity`]) is reserved. Reusing the same `buf` across
+    /// the calls avoids allocations on every received message. Messages larger than the capacity of
+    /// the `buf` are received in parts. Note: The messages queued by
+    /// [`wait_sender_dry`][`Self::wait_sender_dry`] are always returned in full.
+    pub async fn sctp_recv_into(&self, buf: &mut Vec<u8>) -> std::io::Result<ReceivedInfo> {
+        if let Some(received) = self.pop_pending() {
+            return Ok(pending_into_received_info(received, buf));
+        }
+        sctp_recvmsg_into_internal(&self.inner, buf, self.recv_capacity()).await
+    }
+
+    /// Peek at the next Data or Notification without removing it from the receive queue.
+    ///
+    /// This is same as [`sctp_recv`][`Self::sctp_recv`], except that the message is received
+    /// with the `MSG_PEEK` flag, so the next receive call returns the same message again. This is
+    /// useful to look at the first bytes of a message (eg. to route it) before it is actually
+    /// received elsewhere. The ancillary data (eg. [`RcvInfo`][`crate::RcvInfo`]) returned is as
+    /// reported by the kernel for the peeked message. For messages larger than the receive
+    /// capacity, only the first part is returned (without the
+    /// [`RecvFlags::EOR`][`crate::RecvFlags::EOR`] flag set) and nothing is consumed.
+    pub async fn sctp_peek(&self) -> std::io::Result<NotificationOrData> {
+        if let Some(received) = self.pending.lock().unwrap().front() {
+            return Ok(received.clone());
+        }
+        sctp_peekmsg_internal(&self.inner, self.recv_capacity()).await
+    }
+
+    /// Wait till there is no user data outstanding on the association.
+    ///
+    /// This waits for the `SCTP_SENDER_DRY_EVENT` notification (See Section 6.1.9 of RFC 6458),
+    /// which is delivered, when all the data sent on the association is acknowledged by the peer.
+    /// This is useful to make sure that the sent data is delivered, before shutting down the
+    /// association. Any Data or Notifications received while waiting are queued and returned by
+    /// the subsequent [`sctp_recv`][`Self::sctp_recv`] (or other receive) calls, so nothing is
+    /// lost. If the association is closed while waiting, an error with the OS error code
+    /// `ENOTCONN` is returned.
+    ///
+    /// The [`Event::SenderDry`] is subscribed to while waiting, if it was not subscribed to
+    /// already. Note: On One to Many sockets, this returns when any of the associations is dry.
+    pub async fn wait_sender_dry(&self) -> std::io::Result<()> {
+        sctp_wait_sender_dry_internal(&self.inner, &self.pending, self.recv_capacity()).await
     }
 
     /// Send Data and Anciliary data if any on the SCTP Socket.
     ///
     /// SCTP supports sending the actual SCTP message together with sending any anciliary data on
-    /// the SCTP association. The anciliary data is optional.
-    pub async fn sctp_send(&self, data: SendData) -> std::io::Result<()> {
+    /// the SCTP association. The anciliary data is optional. Returns the number of bytes of the
+    /// payload accepted by the kernel, which is always the full payload, since an SCTP message is
+    /// sent as a whole. If the kernel accepts only a part of the message, an error wrapping
+    /// [`SctpError::PartialSend`][`crate::SctpError::PartialSend`] is returned.
+    pub async fn sctp_send(&self, data: SendData) -> std::io::Result<usize> {
         sctp_sendmsg_internal(&self.inner, None, data).await
     }
 
+    /// Send Data and Anciliary data if any on the SCTP Socket, after checking the stream ID.
+    ///
+    /// This is same as [`sctp_send`][`Self::sctp_send`], except that if the `sid` of the
+    /// `snd_info` is not less than the number of the outbound streams of the association, an
+    /// error of the kind [`InvalidInput`][`std::io::ErrorKind::InvalidInput`] wrapping
+    /// [`SctpError::InvalidStream`][`crate::SctpError::InvalidStream`] is returned, without
+    /// sending the data. The number of the outbound streams is cached, it is fetched again only
+    /// when the `sid` is out of range (as the streams may have been added since).
+    pub async fn sctp_send_checked(&self, data: SendData) -> std::io::Result<usize> {
+        if let Some(ref snd_info) = data.snd_info {
+            self.check_outbound_stream(snd_info.sid)?;
+        }
+        self.sctp_send(data).await
+    }
+
+    // Checks that the `sid` is less than the number of the outbound streams.
+    fn check_outbound_stream(&self, sid: u16) -> std::io::Result<()> {
+        if usize::from(sid) < self.outstreams.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        let (_, outstreams) = self.stream_counts(0.into())?;
+        self.outstreams
+            .store(usize::from(outstreams), Ordering::Relaxed);
+        if sid < outstreams {
+            Ok(())
+        } else {
+            Err(SctpError::InvalidStream { sid, outstreams }.into())
+        }
+    }
+
+    /// Send Data and Anciliary data if any to the peer address `to`.
+    ///
+    /// This is same as [`sctp_send`][`Self::sctp_send`], except that the destination address is
+    /// passed to the kernel. This is mainly useful on a socket that is
+    /// [`peeled off`][`crate::Listener::sctp_peeloff`] from a One to Many socket. The `to` should
+    /// be one of the addresses of the peer of the association. Otherwise, the kernel rejects the
+    /// send (For example, on a One to One socket with the OS error code `EADDRNOTAVAIL`) and the
+    /// error is returned.
+    pub async fn sctp_send_to(&self, to: SocketAddr, data: SendData) -> std::io::Result<usize> {
+        sctp_sendmsg_internal(&self.inner, Some(to), data).await
+    }
+
+    /// Send a message made up of multiple buffers on the SCTP Socket.
+    ///
+    /// This is same as [`sctp_send`][`Self::sctp_send`], except that the payload is taken from
+    /// the `bufs` (for example, a header and a body) without having to concatenate them first.
+    /// All the `bufs` together form a single SCTP message. The ancillary data is sent using the
+    /// `snd_info`. Returns the number of bytes of the payload accepted by the kernel.
+    ///
+    /// This can also be used for sending a large message that is not in a single buffer. Note:
+    /// Linux does not support the explicit End of Record (`SCTP_EXPLICIT_EOR`, See Section
+    /// 8.1.26 of RFC 6458), hence a message cannot be sent in parts using multiple sends.
+    pub async fn sctp_send_vectored(
+        &self,
+        bufs: &[IoSlice<'_>],
+        snd_info: Option<SendInfo>,
+    ) -> std::io::Result<usize> {
+        sctp_sendmsg_vectored_internal(&self.inner, None, bufs, snd_info).await
+    }
+
+    /// Send a message taken from a [`bytes::Buf`] on the SCTP Socket.
+    ///
+    /// This is same as [`sctp_send_vectored`][`Self::sctp_send_vectored`], with the chunks of the
+    /// `buf` (eg. [`bytes::Bytes`] or a chain of them) forming a single SCTP message. The chunks
+    /// are not copied, unless there are too many of them. This is available only with the
+    /// `bytes` feature.
+    #[cfg(feature = "bytes")]
+    pub async fn sctp_send_buf(
+        &self,
+        buf: impl bytes::Buf,
+        snd_info: Option<SendInfo>,
+    ) -> std::io::Result<usize> {
+        sctp_sendmsg_buf_internal(&self.inner, None, buf, snd_info).await
+    }
+
+    /// Wait for the socket to be readable.
+    ///
+    /// Nothing is received from the socket, hence this can be used (for example, in a
+    /// `select!`) without losing a message if the future is dropped before it completes. The
+    /// readiness may be spurious: It is cleared only when a receive without waiting (like
+    /// [`try_sctp_recv`][`Self::try_sctp_recv`]) fails with
+    /// [`WouldBlock`][`std::io::ErrorKind::WouldBlock`], so the messages should be received
+    /// using `try_sctp_recv` till it fails with `WouldBlock`, before waiting again.
+    pub async fn readable(&self) -> std::io::Result<()> {
+        if !self.pending.lock().unwrap().is_empty() {
+            return Ok(());
+        }
+        self.inner.readable().await
+    }
+
+    /// Wait for the socket to be writable.
+    ///
+    /// This is paired with [`try_sctp_send`][`Self::try_sctp_send`] in the same way as
+    /// [`readable`][`Self::readable`] is paired with `try_sctp_recv`.
+    pub async fn writable(&self) -> std::io::Result<()> {
+        self.inner.writable().await
+    }
+
+    /// Poll for the socket to be readable.
+    ///
+    /// This is the `poll` based variant of [`readable`][`Self::readable`], useful for implementing
+    /// custom [`Future`][`std::future::Future`]s. Returns
+    /// [`Poll::Pending`][`std::task::Poll::Pending`] if the socket is not readable, in which case
+    /// the waker from `cx` is notified when the socket becomes readable. Only the waker passed to
+    /// the most recent call is notified. The readiness may be spurious as in `readable`.
+    pub fn poll_read_ready(
+        &self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        if !self.pending.lock().unwrap().is_empty() {
+            return std::task::Poll::Ready(Ok(()));
+        }
+        self.inner.poll_read_ready(cx)
+    }
+
+    /// Poll for the socket to be writable.
+    ///
+    /// This is the `poll` based variant of [`writable`][`Self::writable`]. See
+    /// [`poll_read_ready`][`Self::poll_read_ready`] for the details.
+    pub fn poll_write_ready(
+        &self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        self.inner.poll_write_ready(cx)
+    }
+
+    /// Wait for any of the requested ready states.
+    ///
+    /// This function is usually paired with [`try_sctp_recv`][`Self::try_sctp_recv`] or
+    /// [`try_sctp_send`][`Self::try_sctp_send`] to implement readiness driven loops, similar to
+    /// `tokio::net::TcpStream::ready`. The function may complete without the socket being ready,
+    /// in which case the `try_*` functions return an `EWOULDBLOCK` error.
+    ///
+    /// This is available only with the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    pub async fn ready(&self, interest: Interest) -> std::io::Result<Ready> {
+        if interest.is_readable() && !self.pending.lock().unwrap().is_empty() {
+            return Ok(Ready::READABLE);
+        }
+        self.inner.ready(interest).await
+    }
+
+    /// Try to receive Data or Notification from the connected socket without waiting.
+    ///
+    /// If no Data or Notification is available, an error of the kind
+    /// [`WouldBlock`][`std::io::ErrorKind::WouldBlock`] is returned and the readiness of the
+    /// socket is cleared. See also [`sctp_recv`][`Self::sctp_recv`].
+    pub fn try_sctp_recv(&self) -> std::io::Result<NotificationOrData> {
+        if let Some(received) = self.pop_pending() {
+            return Ok(received);
+        }
+        sctp_try_recvmsg_internal(&self.inner, self.recv_capacity())
+    }
+
+    /// Try to receive Data or Notification from the connected socket without waiting.
+    ///
+    /// This is same as [`try_sctp_recv`][`Self::try_sctp_recv`], except that `Ok(None)` is
+    /// returned, if no Data or Notification is available. The readiness of the socket is cleared
+    /// only in this case, so that a subsequent [`ready`][`Self::ready`] waits for the new data.
+    pub fn try_recv(&self) -> std::io::Result<Option<NotificationOrData>> {
+        match self.try_sctp_recv() {
+            Ok(received) => Ok(Some(received)),
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Try to send Data and Anciliary data if any on the SCTP Socket without waiting.
+    ///
+    /// If the socket is not ready for sending, an error of the kind
+    /// [`WouldBlock`][`std::io::ErrorKind::WouldBlock`] is returned and the readiness of the
+    /// socket is cleared. See also [`sctp_send`][`Self::sctp_send`].
+    pub fn try_sctp_send(&self, data: SendData) -> std::io::Result<usize> {
+        sctp_try_sendmsg_internal(&self.inner, None, data)
+    }
+
     /// Subscribe to a given SCTP Event on the given socket. See section 6.2.1 of RFC6458.
     ///
     /// SCTP allows receiving notifications about the changes to SCTP associations etc from the
@@ -114,27 +502,50 @@ impl ConnectedSocket {
     /// SCTP allows receiving notifications about the changes to SCTP associations etc from the
     /// user space. For these notification events to be received, this API is used to subsribe for
     /// the events while receiving the data on the SCTP Socket.
+    ///
+    /// On the kernels that do not support the `SCTP_EVENT` socket option, the legacy
+    /// `SCTP_EVENTS` option is used. It applies to all the associations on the socket, hence only
+    /// [`Future`][`SubscribeEventAssocId::Future`] and [`All`][`SubscribeEventAssocId::All`] are
+    /// supported as the `assoc_id` and the others fail with `EOPNOTSUPP`. The events newer than
+    /// the kernel (eg. [`SenderDry`][`Event::SenderDry`]) also fail with `EOPNOTSUPP`.
     pub fn sctp_subscribe_events(
         &self,
         events: &[Event],
         assoc_id: SubscribeEventAssocId,
     ) -> std::io::Result<()> {
-        let mut failures = vec![];
-        for ev in events {
-            let result = sctp_subscribe_event_internal(&self.inner, ev.clone(), assoc_id, true);
-            if result.is_err() {
-                failures.push(result.err().unwrap());
-            }
-        }
+        subscribe_events_result(sctp_subscribe_events_internal(
+            &self.inner,
+            events,
+            assoc_id,
+            true,
+        ))
+    }
 
-        if failures.is_empty() {
-            Ok(())
-        } else {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("{:?}", failures),
-            ))
-        }
+    /// Subscribe to SCTP Events, reporting the result for each of the events.
+    ///
+    /// Unlike [`sctp_subscribe_events`][`Self::sctp_subscribe_events`], which fails if
+    /// subscription to any of the events fails, this returns the result of subscription for every
+    /// event, in the order of the `events`. This is useful when some of the events may not be
+    /// supported by the running kernel and the application can proceed with the remaining events.
+    pub fn sctp_subscribe_events_checked(
+        &self,
+        events: &[Event],
+        assoc_id: SubscribeEventAssocId,
+    ) -> Vec<(Event, std::io::Result<()>)> {
+        sctp_subscribe_events_internal(&self.inner, events, assoc_id, true)
+    }
+
+    /// Subscribe to all the events in the `mask` (and unsubscribe from the rest) in one call.
+    ///
+    /// This uses the legacy `SCTP_EVENTS` socket option, which sets all the events with a single
+    /// `setsockopt` and applies to the socket as a whole (and all its associations). If the
+    /// running kernel does not support the option (or supports fewer events than this crate),
+    /// the events are subscribed to one by one as in
+    /// [`sctp_subscribe_events`][`Self::sctp_subscribe_events`]. Note: `SCTP_EVENTS` is
+    /// deprecated by RFC 6458, `sctp_subscribe_events` is preferred unless the subscription is
+    /// performed often.
+    pub fn sctp_subscribe_all_events(&self, mask: EventMask) -> std::io::Result<()> {
+        sctp_subscribe_all_events_internal(&self.inner, mask)
     }
 
     /// Unsubscribe from a given SCTP Event on the given socket. See section 6.2.1 of RFC6458.
@@ -145,45 +556,380 @@ impl ConnectedSocket {
         events: &[Event],
         assoc_id: SubscribeEventAssocId,
     ) -> std::io::Result<()> {
-        let mut failures = vec![];
-        for ev in events {
-            let result = sctp_subscribe_event_internal(&self.inner, ev.clone(), assoc_id, false);
-            if result.is_err() {
-                failures.push(result.err().unwrap());
-            }
-        }
-
-        if failures.is_empty() {
-            Ok(())
-        } else {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("{:?}", failures),
-            ))
-        }
+        subscribe_events_result(sctp_subscribe_events_internal(
+            &self.inner,
+            events,
+            assoc_id,
+            false,
+        ))
     }
 
     /// Request to receive `RcvInfo` ancillary data.
     ///
     /// SCTP allows receiving ancillary data about the curent data received on the given socket.
     /// This API is used to obtain receive side additional info when the data is to be received.
+    ///
+    /// Note: This is a socket level option (See Section 8.1.29 of RFC 6458), on a One to Many
+    /// socket it applies to all the associations, it cannot be set for a single association.
     pub fn sctp_request_rcvinfo(&self, on: bool) -> std::io::Result<()> {
         request_rcvinfo_internal(&self.inner, on)
     }
 
+    /// Get whether receiving `RcvInfo` ancillary data is requested on the socket.
+    ///
+    /// See [`sctp_request_rcvinfo`][`Self::sctp_request_rcvinfo`].
+    pub fn rcvinfo_requested(&self) -> std::io::Result<bool> {
+        rcvinfo_requested_internal(&self.inner)
+    }
+
     /// Request to receive `NxtInfo` ancillary data.
     ///
     /// SCTP allows receiving ancillary data about the curent data received on the given socket.
     /// This API is used to obtain information about the next datagram that will be received.
+    ///
+    /// Note: This is a socket level option (See Section 8.1.30 of RFC 6458), on a One to Many
+    /// socket it applies to all the associations, it cannot be set for a single association.
     pub fn sctp_request_nxtinfo(&self, on: bool) -> std::io::Result<()> {
         request_nxtinfo_internal(&self.inner, on)
     }
 
+    /// Get whether receiving `NxtInfo` ancillary data is requested on the socket.
+    ///
+    /// See [`sctp_request_nxtinfo`][`Self::sctp_request_nxtinfo`].
+    pub fn nxtinfo_requested(&self) -> std::io::Result<bool> {
+        nxtinfo_requested_internal(&self.inner)
+    }
+
     /// Get the status of the connection associated with the association ID.
     pub fn sctp_get_status(&self, assoc_id: AssociationId) -> std::io::Result<ConnStatus> {
         sctp_get_status_internal(&self.inner, assoc_id)
     }
 
+    /// Get the number of the inbound and the outbound streams of the association.
+    ///
+    /// Returns `(instreams, outstreams)` as in the [`ConnStatus`] returned by
+    /// [`sctp_get_status`][`Self::sctp_get_status`], without getting the rest of the status. The
+    /// stream ID (`sid`) of the [`SendInfo`] must be less than the `outstreams`.
+    pub fn stream_counts(&self, assoc_id: AssociationId) -> std::io::Result<(u16, u16)> {
+        sctp_get_stream_counts_internal(&self.inner, assoc_id)
+    }
+
+    /// Get the information about a Peer Address of the association. See Section 8.2.2 of RFC
+    /// 6458.
+    ///
+    /// Returns the state, the congestion window, the smoothed RTT, the RTO and the path MTU of
+    /// the path to the given peer address `addr`.
+    pub fn sctp_get_peer_addr_info(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+    ) -> std::io::Result<PeerAddress> {
+        sctp_get_peer_addr_info_internal(&self.inner, assoc_id, addr)
+    }
+
+    /// Send a heartbeat to the peer address `addr` of the association immediately. See Section
+    /// 8.1.12 of RFC 6458 (`SPP_HB_DEMAND`).
+    ///
+    /// This is useful for checking whether a path is alive, without waiting for the periodic
+    /// heartbeat interval. The result of the check is reflected in the state of the peer address
+    /// (See [`sctp_get_peer_addr_info`][`Self::sctp_get_peer_addr_info`]). If the `addr` is not
+    /// one of the peer addresses of the association, an error with the OS error code `EINVAL` is
+    /// returned.
+    pub fn request_heartbeat(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+    ) -> std::io::Result<()> {
+        sctp_request_heartbeat_internal(&self.inner, assoc_id, addr)
+    }
+
+    /// Enable or Disable advertising the support for Partial Reliability (PR-SCTP).
+    ///
+    /// The value is used for the associations set up after this call. An `assoc_id` of `0` is
+    /// used to set the value for the future associations on the socket. Setting this on an
+    /// already established association has no effect.
+    pub fn set_pr_supported(&self, assoc_id: AssociationId, on: bool) -> std::io::Result<()> {
+        sctp_set_pr_supported_internal(&self.inner, assoc_id, on)
+    }
+
+    /// Get whether Partial Reliability (PR-SCTP) is supported.
+    ///
+    /// For an established association, this returns whether PR-SCTP was negotiated with the
+    /// peer. Partial Reliability parameters (See [`PrInfo`][`crate::PrInfo`]) are honored only
+    /// if this returns `true`.
+    pub fn pr_supported(&self, assoc_id: AssociationId) -> std::io::Result<bool> {
+        sctp_get_pr_supported_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the default Partial Reliability policy for the association. See Section 4.5 RFC 7496
+    ///
+    /// The policy is used for the sent messages, that do not specify a
+    /// [`PrInfo`][`crate::PrInfo`] in the [`SendData`][`crate::SendData`]. An `assoc_id` of `0`
+    /// is used to set the value for the future associations on the socket.
+    pub fn set_default_prinfo(
+        &self,
+        assoc_id: AssociationId,
+        pr_info: crate::PrInfo,
+    ) -> std::io::Result<()> {
+        sctp_set_default_prinfo_internal(&self.inner, assoc_id, pr_info)
+    }
+
+    /// Get the default Partial Reliability policy for the association. See Section 4.5 RFC 7496
+    pub fn get_default_prinfo(&self, assoc_id: AssociationId) -> std::io::Result<crate::PrInfo> {
+        sctp_get_default_prinfo_internal(&self.inner, assoc_id)
+    }
+
+    /// Get the number of messages abandoned on the association. See Section 4.7 RFC 7496
+    ///
+    /// The counters are for all the Partial Reliability policies.
+    pub fn pr_status(&self, assoc_id: AssociationId) -> std::io::Result<crate::PrStatus> {
+        sctp_get_pr_status_internal(&self.inner, assoc_id)
+    }
+
+    /// Enable or Disable advertising the support for Stream Reconfiguration (RE-CONFIG).
+    ///
+    /// The value is used for the associations set up after this call, the Linux kernel applies
+    /// it to the whole socket irrespective of the `assoc_id` (`0` can be used). Setting this on
+    /// an already established association has no effect. See RFC 6525.
+    pub fn set_reconfig_supported(&self, assoc_id: AssociationId, on: bool) -> std::io::Result<()> {
+        sctp_set_reconfig_supported_internal(&self.inner, assoc_id, on)
+    }
+
+    /// Get whether Stream Reconfiguration (RE-CONFIG) is supported.
+    ///
+    /// For an established association, this returns whether RE-CONFIG was negotiated with the
+    /// peer. If this returns `false`, Stream Reset requests on the association fail with
+    /// `ENOPROTOOPT`.
+    pub fn reconfig_supported(&self, assoc_id: AssociationId) -> std::io::Result<bool> {
+        sctp_get_reconfig_supported_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the maximum size of the DATA chunks (fragmentation point). See Section 8.1.16 of RFC
+    /// 6458.
+    ///
+    /// User messages larger than `maxseg` are fragmented by SCTP into multiple DATA chunks. An
+    /// `assoc_id` of `0` is used to set the value for the future associations on the socket. A
+    /// `maxseg` of `0` means the fragmentation point is determined by the path MTU. Note: The
+    /// kernel always fragments based on the smaller of `maxseg` and the value derived from the
+    /// current path MTU, so this can only lower the fragmentation point. The path MTU itself is
+    /// controlled by the path MTU discovery, which can be disabled and the path MTU set per peer
+    /// address using the `SCTP_PEER_ADDR_PARAMS` socket option.
+    pub fn set_maxseg(&self, assoc_id: AssociationId, maxseg: u32) -> std::io::Result<()> {
+        sctp_set_maxseg_internal(&self.inner, assoc_id, maxseg)
+    }
+
+    /// Get the maximum size of the DATA chunks (fragmentation point).
+    ///
+    /// See [`set_maxseg`][`Self::set_maxseg`] for details.
+    pub fn get_maxseg(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        sctp_get_maxseg_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the maximum number of packets that can be sent in a single burst. See Section 8.1.24
+    /// of RFC 6458.
+    ///
+    /// This limits the burst of packets sent by the congestion control at any time. An
+    /// `assoc_id` of `0` is used to set the value for the future associations on the socket. A
+    /// `max_burst` of `0` disables the burst limit.
+    pub fn set_max_burst(&self, assoc_id: AssociationId, max_burst: u32) -> std::io::Result<()> {
+        sctp_set_max_burst_internal(&self.inner, assoc_id, max_burst)
+    }
+
+    /// Get the maximum number of packets that can be sent in a single burst.
+    ///
+    /// See [`set_max_burst`][`Self::set_max_burst`] for details.
+    pub fn get_max_burst(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        sctp_get_max_burst_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the delayed SACK timer and frequency. See Section 8.1.19 of RFC 6458.
+    ///
+    /// The `delay_ms` is the time in milliseconds after which a SACK is sent, if a SACK was not
+    /// already sent because `freq` packets were received. An `assoc_id` of `0` is used to set
+    /// the values for the future associations on the socket. A `delay_ms` of `0` disables the
+    /// delayed SACK (every packet is acknowledged immediately), `freq` is ignored in this case.
+    /// Disabling the delayed SACK reduces the latency for the small request/response messages.
+    pub fn set_delayed_sack(
+        &self,
+        assoc_id: AssociationId,
+        delay_ms: u32,
+        freq: u32,
+    ) -> std::io::Result<()> {
+        sctp_set_delayed_sack_internal(&self.inner, assoc_id, delay_ms, freq)
+    }
+
+    /// Get the delayed SACK timer (in milliseconds) and frequency as `(delay_ms, freq)`.
+    ///
+    /// See [`set_delayed_sack`][`Self::set_delayed_sack`] for details.
+    pub fn get_delayed_sack(&self, assoc_id: AssociationId) -> std::io::Result<(u32, u32)> {
+        sctp_get_delayed_sack_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the default context used for the messages sent on the association. See Section
+    /// 8.1.25 of RFC 6458.
+    ///
+    /// The context is an opaque value that is not sent to the peer, but is returned in the
+    /// [`SendFailed`][`crate::SendFailed`] notification for a message that could not be sent.
+    /// This is used for the messages sent without the [`SendInfo`][`crate::SendInfo`]. An
+    /// `assoc_id` of `0` is used to set the value for the future associations on the socket.
+    pub fn set_context(&self, assoc_id: AssociationId, context: u32) -> std::io::Result<()> {
+        sctp_set_context_internal(&self.inner, assoc_id, context)
+    }
+
+    /// Get the default context used for the messages sent on the association.
+    ///
+    /// See [`set_context`][`Self::set_context`] for details.
+    pub fn context(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        sctp_get_context_internal(&self.inner, assoc_id)
+    }
+
+    /// Set a socket option from the raw bytes of the `value`.
+    ///
+    /// This is an escape hatch for the socket options that are not (yet) supported by this
+    /// crate, the `value` should be the in memory representation of the option as expected by
+    /// the kernel (eg. the bytes of a C `struct`). Note: The crate does not validate the `level`,
+    /// the `optname` or the `value`, an incorrect use can leave the socket in a state, that the
+    /// other APIs of this crate do not expect. Prefer the typed APIs, where available.
+    pub fn setsockopt_raw(&self, level: i32, optname: i32, value: &[u8]) -> std::io::Result<()> {
+        setsockopt_raw_internal(&self.inner, level, optname, value)
+    }
+
+    /// Get a socket option as raw bytes in the `buf`.
+    ///
+    /// Returns the number of bytes of the `buf` written by the kernel. Some options require the
+    /// `buf` to be filled in with the input values (eg. an Association ID) before the call. See
+    /// [`setsockopt_raw`][`Self::setsockopt_raw`] for details.
+    pub fn getsockopt_raw(
+        &self,
+        level: i32,
+        optname: i32,
+        buf: &mut [u8],
+    ) -> std::io::Result<usize> {
+        getsockopt_raw_internal(&self.inner, level, optname, buf)
+    }
+
+    /// Set the remote UDP encapsulation port for SCTP over UDP (See RFC 6951).
+    ///
+    /// SCTP packets to the peer are encapsulated in UDP with the destination `port`, which is
+    /// useful for traversing the NATs and the middleboxes that only pass UDP. When `addr` is
+    /// `Some`, the port is set only for the given peer address of the association identified by
+    /// the `assoc_id`, else for the association (an `assoc_id` of `0` is used for the future
+    /// associations). A `port` of `0` disables the encapsulation. For receiving the encapsulated
+    /// packets, the local UDP port should be set using the `net.sctp.udp_port` sysctl. Requires
+    /// Linux 5.11 or later, on older kernels an error of kind
+    /// [`Unsupported`][`std::io::ErrorKind::Unsupported`] is returned.
+    pub fn sctp_set_udp_encaps_port(
+        &self,
+        assoc_id: AssociationId,
+        addr: Option<SocketAddr>,
+        port: u16,
+    ) -> std::io::Result<()> {
+        sctp_set_udp_encaps_port_internal(&self.inner, assoc_id, addr, port)
+    }
+
+    /// Get the remote UDP encapsulation port for SCTP over UDP (See RFC 6951).
+    ///
+    /// See [`sctp_set_udp_encaps_port`][`Self::sctp_set_udp_encaps_port`] for details.
+    pub fn sctp_get_udp_encaps_port(
+        &self,
+        assoc_id: AssociationId,
+        addr: Option<SocketAddr>,
+    ) -> std::io::Result<u16> {
+        sctp_get_udp_encaps_port_internal(&self.inner, assoc_id, addr)
+    }
+
+    /// Set the IPv4 Type of Service (`IP_TOS`) used for the packets sent on this socket.
+    ///
+    /// This is used for marking the traffic with a DSCP value (eg. `0xb8` for Expedited
+    /// Forwarding). Returns an `InvalidInput` error for an IPv6 socket, use
+    /// [`set_traffic_class`][`Self::set_traffic_class`] instead.
+    pub fn set_tos(&self, tos: u8) -> std::io::Result<()> {
+        set_tos_internal(&self.inner, tos)
+    }
+
+    /// Get the IPv4 Type of Service (`IP_TOS`) used for the packets sent on this socket.
+    pub fn tos(&self) -> std::io::Result<u8> {
+        get_tos_internal(&self.inner)
+    }
+
+    /// Set the IPv6 Traffic Class (`IPV6_TCLASS`) used for the packets sent on this socket.
+    ///
+    /// Returns an `InvalidInput` error for an IPv4 socket, use [`set_tos`][`Self::set_tos`]
+    /// instead.
+    pub fn set_traffic_class(&self, tc: u8) -> std::io::Result<()> {
+        set_traffic_class_internal(&self.inner, tc)
+    }
+
+    /// Get the IPv6 Traffic Class (`IPV6_TCLASS`) used for the packets sent on this socket.
+    pub fn traffic_class(&self) -> std::io::Result<u8> {
+        get_traffic_class_internal(&self.inner)
+    }
+
+    /// Set the `SO_LINGER` option for the socket. See Section 4.1.6 of RFC 6458.
+    ///
+    /// When set to `Some(Duration::ZERO)`, closing the socket sends an `ABORT` to the peer,
+    /// instead of the graceful `SHUTDOWN` sequence, and any data queued for sending is discarded.
+    /// With a non-zero duration, closing the socket blocks the calling thread until the shutdown
+    /// is complete or the duration expires (sub-second durations are rounded up to a second).
+    /// `None` (the default) restores the default behavior, where the socket is closed
+    /// immediately and the graceful shutdown happens in the background. Note: `Drop` closes the
+    /// socket and hence honors the configured linger.
+    pub fn set_linger(&self, linger: Option<std::time::Duration>) -> std::io::Result<()> {
+        set_linger_internal(&self.inner, linger)
+    }
+
+    /// Get the `SO_LINGER` option for the socket.
+    ///
+    /// See [`set_linger`][`Self::set_linger`] for details.
+    pub fn linger(&self) -> std::io::Result<Option<std::time::Duration>> {
+        get_linger_internal(&self.inner)
+    }
+
+    /// Select the Stream Scheduler for the association.
+    ///
+    /// The Stream Scheduler determines the order in which the data queued on different streams
+    /// of an association is sent. See [`StreamScheduler`] for the supported schedulers.
+    pub fn sctp_set_stream_scheduler(
+        &self,
+        assoc_id: AssociationId,
+        scheduler: StreamScheduler,
+    ) -> std::io::Result<()> {
+        sctp_set_stream_scheduler_internal(&self.inner, assoc_id, scheduler)
+    }
+
+    /// Get the Stream Scheduler used by the association.
+    pub fn sctp_get_stream_scheduler(
+        &self,
+        assoc_id: AssociationId,
+    ) -> std::io::Result<StreamScheduler> {
+        sctp_get_stream_scheduler_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the priority of the stream `sid` of the association.
+    ///
+    /// The priority is used by the [`Prio`][`StreamScheduler::Prio`] scheduler (See
+    /// [`sctp_set_stream_scheduler`][`Self::sctp_set_stream_scheduler`]), a lower value means a
+    /// higher priority. Returns [`SctpError::InvalidStream`] (of the kind
+    /// [`InvalidInput`][`std::io::ErrorKind::InvalidInput`]), if `sid` is not less than the number
+    /// of outbound streams of the association.
+    pub fn sctp_set_stream_priority(
+        &self,
+        assoc_id: AssociationId,
+        sid: u16,
+        value: u16,
+    ) -> std::io::Result<()> {
+        sctp_set_stream_priority_internal(&self.inner, assoc_id, sid, value)
+    }
+
+    /// Get the priority of the stream `sid` of the association.
+    pub fn sctp_get_stream_priority(
+        &self,
+        assoc_id: AssociationId,
+        sid: u16,
+    ) -> std::io::Result<u16> {
+        sctp_get_stream_priority_internal(&self.inner, assoc_id, sid)
+    }
+
     /// Set Default `SendInfo` values for this socket.
     ///
     /// In the [`sctp_send`] API, an optional `SendInfo` is present, which can be used to specify the
@@ -193,6 +939,38 @@ impl ConnectedSocket {
     pub fn sctp_set_default_sendinfo(&self, sendinfo: SendInfo) -> std::io::Result<()> {
         sctp_set_default_sendinfo_internal(&self.inner, sendinfo)
     }
+
+    /// Get Default `SendInfo` values for this socket.
+    ///
+    /// Returns the values set using
+    /// [`sctp_set_default_sendinfo`][`Self::sctp_set_default_sendinfo`] (or the system defaults,
+    /// if those were never set). For a 'connected' socket the `assoc_id` is ignored.
+    pub fn sctp_get_default_sendinfo(&self, assoc_id: AssociationId) -> std::io::Result<SendInfo> {
+        sctp_get_default_sendinfo_internal(&self.inner, assoc_id)
+    }
+}
+
+impl ConnectedSocket {
+    // Returns the oldest of the queued messages (if any).
+    fn pop_pending(&self) -> Option<NotificationOrData> {
+        self.pending.lock().unwrap().pop_front()
+    }
+
+    // Queues the messages received for this socket before it was created (See
+    // `Listener::accept_assoc`).
+    pub(crate) fn queue_pending(&self, received: impl IntoIterator<Item = NotificationOrData>) {
+        self.pending.lock().unwrap().extend(received);
+    }
+
+    // Returns the number of queued Data messages.
+    fn pending_data_count(&self) -> usize {
+        self.pending
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|received| matches!(received, NotificationOrData::Data(_)))
+            .count()
+    }
 }
 
 impl Drop for ConnectedSocket {
@@ -21,20 +21,88 @@ pub(crate) static SCTP_SOCKOPT_CONNECTX3: libc::c_int = 111;
 // To subscribe to SCTP Events
 pub(crate) static SCTP_EVENT: libc::c_int = 127;
 
+// Legacy option to subscribe to SCTP Events (Used on kernels older than 4.16)
+pub(crate) static SCTP_EVENTS: libc::c_int = 11;
+
 //
 pub(crate) static MSG_NOTIFICATION: u32 = 0x8000;
 
 // Notification Types Constants
 pub(crate) const SCTP_ASSOC_CHANGE: u16 = (1 << 15) + 0x0001;
-pub(crate) const SCTP_SHUTDOWN: u16 = (1 << 15) + 0x0005;
+pub(crate) const SCTP_PEER_ADDR_CHANGE: u16 = (1 << 15) + 0x0002;
+pub(crate) const SCTP_SHUTDOWN_EVENT: u16 = (1 << 15) + 0x0005;
+pub(crate) const SCTP_SENDER_DRY_EVENT: u16 = (1 << 15) + 0x0009;
+pub(crate) const SCTP_STREAM_RESET_EVENT: u16 = (1 << 15) + 0x000A;
+pub(crate) const SCTP_SEND_FAILED_EVENT: u16 = (1 << 15) + 0x000D;
 
 // Init Message used for `setsockopt`
 pub(crate) const SCTP_INITMSG: libc::c_int = 2;
 
+// Automatically close idle associations
+pub(crate) const SCTP_AUTOCLOSE: libc::c_int = 4;
+
 // Receving RCVINFO and NXTINFO
 pub(crate) const SCTP_RECVRCVINFO: libc::c_int = 32;
 pub(crate) const SCTP_RECVNXTINFO: libc::c_int = 33;
 pub(crate) const SCTP_DEFAULT_SNDINFO: libc::c_int = 34;
 
+// IPv4 mapped IPv6 addresses
+pub(crate) const SCTP_I_WANT_MAPPED_V4_ADDR: libc::c_int = 12;
+
+// Maximum size of the DATA chunks
+pub(crate) const SCTP_MAXSEG: libc::c_int = 13;
+
+// Delayed SACK timer and frequency
+pub(crate) const SCTP_DELAYED_SACK: libc::c_int = 16;
+
+// Maximum burst of packets sent at once
+pub(crate) const SCTP_MAX_BURST: libc::c_int = 20;
+
+// Associations on a One to Many socket
+pub(crate) const SCTP_GET_ASSOC_NUMBER: libc::c_int = 28;
+pub(crate) const SCTP_GET_ASSOC_ID_LIST: libc::c_int = 29;
+
+// Automatic Address Reconfiguration (ASCONF)
+pub(crate) const SCTP_AUTO_ASCONF: libc::c_int = 30;
+
 // Get SCTP Status
 pub(crate) const SCTP_STATUS: libc::c_int = 14;
+
+// Get Peer Address Information
+pub(crate) const SCTP_GET_PEER_ADDR_INFO: libc::c_int = 15;
+
+// Peer Address Parameters and the flags used
+pub(crate) const SCTP_PEER_ADDR_PARAMS: libc::c_int = 9;
+pub(crate) const SPP_HB_DEMAND: u32 = 1 << 2;
+
+// Default context for the sent messages
+pub(crate) const SCTP_CONTEXT: libc::c_int = 17;
+
+// Partial Reliability Support
+pub(crate) const SCTP_PR_SUPPORTED: libc::c_int = 113;
+pub(crate) const SCTP_DEFAULT_PRINFO: libc::c_int = 114;
+pub(crate) const SCTP_PR_ASSOC_STATUS: libc::c_int = 115;
+
+// Policy used to get the Partial Reliability status for all the policies.
+pub(crate) const SCTP_PR_SCTP_ALL: u16 = 1 << 7;
+
+// Stream Reconfiguration Support
+pub(crate) const SCTP_RECONFIG_SUPPORTED: libc::c_int = 117;
+
+// Stream Schedulers
+pub(crate) const SCTP_STREAM_SCHEDULER: libc::c_int = 123;
+pub(crate) const SCTP_STREAM_SCHEDULER_VALUE: libc::c_int = 124;
+
+// ECN Support
+pub(crate) const SCTP_ECN_SUPPORTED: libc::c_int = 130;
+
+// Remote UDP Encapsulation Port (RFC 6951)
+pub(crate) const SCTP_REMOTE_UDP_ENCAPS_PORT: libc::c_int = 132;
+
+// Flags used in `SendInfo` (`enum sctp_sinfo_flags`)
+pub(crate) const SCTP_UNORDERED: u16 = 1 << 0;
+pub(crate) const SCTP_ADDR_OVER: u16 = 1 << 1;
+pub(crate) const SCTP_ABORT: u16 = 1 << 2;
+pub(crate) const SCTP_SACK_IMMEDIATELY: u16 = 1 << 3;
+pub(crate) const SCTP_SENDALL: u16 = 1 << 6;
+pub(crate) const SCTP_EOF: u16 = libc::MSG_FIN as u16;
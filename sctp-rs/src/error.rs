@@ -0,0 +1,119 @@
+//! Errors returned by the SCTP APIs.
+//!
+//! All the public APIs return [`std::io::Result`]. [`SctpError`] names the SCTP specific failure
+//! cases, an [`std::io::Error`] returned by an API can be converted to [`SctpError`] for matching
+//! on these cases.
+
+/// Error type for SCTP specific failures.
+///
+/// This can be converted to and from [`std::io::Error`]. Converting an `SctpError` to an
+/// [`std::io::Error`] retains the underlying OS error code, so that the callers checking for the
+/// [`raw_os_error`][`std::io::Error::raw_os_error`] are not affected. More variants may be added
+/// in the future, so the matches on it should have a wildcard arm.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SctpError {
+    /// The operation is not supported on the socket (`EOPNOTSUPP`).
+    NotSupported,
+
+    /// The address family of an address is not supported (`EINVAL`).
+    InvalidAddressFamily,
+
+    /// The peer refused the connection (`ECONNREFUSED`).
+    ConnectionRefused,
+
+    /// (Un)subscribing to some of the events failed. Contains the events that failed along with
+    /// the error for each of them.
+    EventSubscription(Vec<(crate::Event, std::io::Error)>),
+
+    /// Only `written` bytes of the message were accepted by the kernel. Since the remaining bytes
+    /// cannot be sent as a part of the same SCTP message, the message is not retried.
+    PartialSend {
+        /// The number of bytes accepted by the kernel.
+        written: usize,
+    },
+
+    /// The stream ID `sid` is not less than the number of the outbound streams (`outstreams`) of
+    /// the association (See
+    /// [`ConnectedSocket::sctp_send_checked`][`crate::ConnectedSocket::sctp_send_checked`]).
+    InvalidStream {
+        /// The stream ID used for sending.
+        sid: u16,
+
+        /// The number of the outbound streams of the association.
+        outstreams: u16,
+    },
+
+    /// Any other I/O error.
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for SctpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotSupported => write!(f, "Operation not supported on the SCTP socket"),
+            Self::InvalidAddressFamily => write!(f, "Unsupported address family"),
+            Self::ConnectionRefused => write!(f, "Connection refused by the SCTP peer"),
+            Self::EventSubscription(failures) => {
+                write!(f, "Failed to (un)subscribe events:")?;
+                for (event, e) in failures {
+                    write!(f, " {:?}: {},", event, e)?;
+                }
+                Ok(())
+            }
+            Self::PartialSend { written } => {
+                write!(f, "Only {} bytes of the message were sent", written)
+            }
+            Self::InvalidStream { sid, outstreams } => write!(
+                f,
+                "Stream ID {} is out of range, the association has {} outbound streams",
+                sid, outstreams
+            ),
+            Self::Io(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SctpError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<SctpError> for std::io::Error {
+    fn from(e: SctpError) -> Self {
+        match e {
+            SctpError::NotSupported => std::io::Error::from_raw_os_error(libc::EOPNOTSUPP),
+            SctpError::InvalidAddressFamily => std::io::Error::from_raw_os_error(libc::EINVAL),
+            SctpError::ConnectionRefused => std::io::Error::from_raw_os_error(libc::ECONNREFUSED),
+            SctpError::Io(e) => e,
+            // There is no single OS error code for this, the error is retained as it is.
+            e @ SctpError::EventSubscription(_) => std::io::Error::other(e),
+            e @ SctpError::PartialSend { .. } => std::io::Error::other(e),
+            // Same kind as `EINVAL`, but with the details of the error.
+            e @ SctpError::InvalidStream { .. } => {
+                std::io::Error::new(std::io::ErrorKind::InvalidInput, e)
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for SctpError {
+    /// Errors with the OS error codes `EOPNOTSUPP` and `ECONNREFUSED` are converted to the
+    /// respective variants. Since `EINVAL` is returned for a number of reasons, it is *not*
+    /// converted to [`InvalidAddressFamily`][`SctpError::InvalidAddressFamily`].
+    fn from(e: std::io::Error) -> Self {
+        if e.get_ref().is_some_and(|inner| inner.is::<SctpError>()) {
+            return *e.into_inner().unwrap().downcast::<SctpError>().unwrap();
+        }
+
+        match e.raw_os_error() {
+            Some(libc::EOPNOTSUPP) => Self::NotSupported,
+            Some(libc::ECONNREFUSED) => Self::ConnectionRefused,
+            _ => Self::Io(e),
+        }
+    }
+}
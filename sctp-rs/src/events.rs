@@ -0,0 +1,87 @@
+//! Separate receivers for the Data and the Notifications of a [`ConnectedSocket`].
+//!
+//! See [`ConnectedSocket::split_events`] for details.
+
+use tokio::sync::mpsc;
+
+use crate::{ConnectedSocket, Notification, NotificationOrData, ReceivedData};
+
+/// Receiver of the Data received on a [`ConnectedSocket`] created by
+/// [`split_events`][`ConnectedSocket::split_events`].
+#[derive(Debug)]
+pub struct DataReceiver {
+    inner: mpsc::Receiver<std::io::Result<ReceivedData>>,
+}
+
+/// Receiver of the Notifications received on a [`ConnectedSocket`] created by
+/// [`split_events`][`ConnectedSocket::split_events`].
+#[derive(Debug)]
+pub struct NotificationReceiver {
+    inner: mpsc::Receiver<Notification>,
+}
+
+pub(crate) fn split_events(
+    socket: ConnectedSocket,
+    data_capacity: usize,
+    notification_capacity: usize,
+) -> (DataReceiver, NotificationReceiver) {
+    let (data_tx, data_rx) = mpsc::channel(data_capacity);
+    let (notification_tx, notification_rx) = mpsc::channel(notification_capacity);
+
+    tokio::spawn(async move {
+        let both_closed = async {
+            data_tx.closed().await;
+            notification_tx.closed().await;
+        };
+        tokio::pin!(both_closed);
+
+        loop {
+            let received = tokio::select! {
+                received = socket.sctp_recv() => received,
+                _ = &mut both_closed => break,
+            };
+
+            // A failed `send` means the receiver is dropped, the message is discarded.
+            match received {
+                Ok(NotificationOrData::Notification(notification)) => {
+                    let _ = notification_tx.send(notification).await;
+                }
+                Ok(NotificationOrData::Data(data)) => {
+                    let _ = data_tx.send(Ok(data)).await;
+                }
+                Ok(NotificationOrData::Eof) => break,
+                Err(e) => {
+                    log::debug!("Error: '{}' while receiving, stopping.", e);
+                    let _ = data_tx.send(Err(e)).await;
+                    break;
+                }
+            }
+        }
+    });
+
+    (
+        DataReceiver { inner: data_rx },
+        NotificationReceiver {
+            inner: notification_rx,
+        },
+    )
+}
+
+impl DataReceiver {
+    /// Receive the next Data received on the socket.
+    ///
+    /// Returns `None` when the association is closed. An error while receiving from the socket is
+    /// returned once, after which `None` is returned.
+    pub async fn recv(&mut self) -> Option<std::io::Result<ReceivedData>> {
+        self.inner.recv().await
+    }
+}
+
+impl NotificationReceiver {
+    /// Receive the next Notification received on the socket.
+    ///
+    /// Returns `None` when the association is closed or receiving from the socket failed.
+    pub async fn recv(&mut self) -> Option<Notification> {
+        self.inner.recv().await
+    }
+}
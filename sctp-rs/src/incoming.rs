@@ -0,0 +1,65 @@
+//! Stream of the incoming connections on a [`Listener`].
+//!
+//! See [`Listener::incoming`] for details.
+
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+
+use crate::{ConnectedSocket, Listener};
+
+/// A [`Stream`] of the connections accepted on a [`Listener`] created by
+/// [`incoming`][`Listener::incoming`].
+#[derive(Debug)]
+pub struct Incoming<'a> {
+    listener: &'a Listener,
+    done: bool,
+}
+
+pub(crate) fn incoming(listener: &Listener) -> Incoming<'_> {
+    Incoming {
+        listener,
+        done: false,
+    }
+}
+
+// Errors after which the `accept` may still succeed, for example, when the connection is aborted
+// before it is accepted or when the process is (temporarily) out of file descriptors or memory.
+fn is_transient(e: &std::io::Error) -> bool {
+    matches!(
+        e.raw_os_error(),
+        Some(
+            libc::ECONNABORTED
+                | libc::EINTR
+                | libc::EPROTO
+                | libc::EMFILE
+                | libc::ENFILE
+                | libc::ENOBUFS
+                | libc::ENOMEM
+        )
+    )
+}
+
+impl Stream for Incoming<'_> {
+    type Item = std::io::Result<(ConnectedSocket, SocketAddr)>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+
+        match self.listener.poll_accept(cx) {
+            Poll::Ready(Err(e)) => {
+                if !is_transient(&e) {
+                    log::debug!("Stopping the incoming stream after error: {}", e);
+                    self.done = true;
+                }
+                Poll::Ready(Some(Err(e)))
+            }
+            Poll::Ready(Ok(accepted)) => Poll::Ready(Some(Ok(accepted))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -3,23 +3,33 @@
 //! Nothing in this module should be public API as this module contains `unsafe` code that uses
 //! `libc` and internal `libc` structs and function calls.
 
-use tokio::io::unix::AsyncFd;
-
 use std::convert::TryInto;
+use std::io::IoSlice;
 use std::net::SocketAddr;
 use std::os::unix::io::{AsRawFd, RawFd};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use os_socketaddr::OsSocketAddr;
 
 use crate::types::internal::{
-    ConnStatusInternal, ConnectxParam, GetAddrs, InitMsg, SubscribeEvent,
+    AssocValue, ConnStatusInternal, ConnectxParam, DefaultPrInfo, EventSubscribe, GetAddrs,
+    PeerAddrInternal, PeerAddrParams, PrStatusInternal, SackInfo, SndRcvInfo, StreamValue,
+    SubscribeEvent, UdpEncaps,
 };
 use crate::{
-    AssocChangeState, AssociationChange, AssociationId, BindxFlags, CmsgType, ConnStatus,
-    ConnectedSocket, Event, Listener, Notification, NotificationOrData, NxtInfo, RcvInfo,
-    ReceivedData, SendData, SendInfo, Shutdown, SubscribeEventAssocId,
+    AssocChangeState, AssociationChange, AssociationId, AuthInfo, BindxFlags, CmsgType, ConnStatus,
+    Event, EventMask, InitParams, Notification, NotificationOrData, NxtInfo, PeerAddress,
+    PeerAddressChange, PeerAddressState, PrInfo, PrPolicy, PrStatus, RcvInfo, ReceivedData,
+    ReceivedInfo, RecvFlags, SctpError, SendData, SendFailed, SendFailedFlags, SendFlags, SendInfo,
+    SenderDry, Shutdown, SocketFlags, SocketToAssociation, StreamResetEvent, StreamResetFlags,
+    StreamScheduler, SubscribeEventAssocId,
 };
 
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+use crate::reactor::{self, RegisteredFd};
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+use crate::{ConnectedSocket, Listener};
+
 #[allow(unused)]
 use super::consts::*;
 
@@ -27,7 +37,7 @@ static SOL_SCTP: libc::c_int = 132;
 
 // Implementation of `sctp_bindx` using `libc::setsockopt`
 pub(crate) fn sctp_bindx_internal(
-    fd: &AsyncFd<RawFd>,
+    fd: &impl AsRawFd,
     addrs: &[SocketAddr],
     flags: BindxFlags,
 ) -> std::io::Result<()> {
@@ -59,7 +69,7 @@ pub(crate) fn sctp_bindx_internal(
     // to raw data is valid.
     unsafe {
         let result = libc::setsockopt(
-            *fd.get_ref(),
+            fd.as_raw_fd(),
             SOL_SCTP,
             flags,
             addrs_u8.as_ptr() as *const _ as *const libc::c_void,
@@ -79,8 +89,9 @@ pub(crate) fn sctp_bindx_internal(
 }
 
 // Implementation of `sctp_peeloff` using `libc::getsockopt`
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 pub(crate) fn sctp_peeloff_internal(
-    fd: &AsyncFd<RawFd>,
+    fd: &RegisteredFd,
     assoc_id: AssociationId,
 ) -> std::io::Result<ConnectedSocket> {
     log::debug!("Peeling off socket for Association ID: {:?}", assoc_id);
@@ -96,7 +107,7 @@ pub(crate) fn sctp_peeloff_internal(
         let peeloff_arg_ptr = std::ptr::addr_of_mut!(peeloff_arg);
         let peeloff_size_ptr = std::ptr::addr_of_mut!(peeloff_size);
         let result = libc::getsockopt(
-            *fd.get_ref(),
+            fd.as_raw_fd(),
             SOL_SCTP,
             SCTP_SOCKOPT_PEELOFF,
             peeloff_arg_ptr as *mut _ as *mut libc::c_void,
@@ -113,6 +124,7 @@ pub(crate) fn sctp_peeloff_internal(
 
             log::debug!("Setting peeled off socket to non-blocking.");
             set_fd_non_blocking(rawfd)?;
+            inherit_fd_cloexec(fd.as_raw_fd(), rawfd)?;
 
             ConnectedSocket::from_rawfd(rawfd)
         }
@@ -126,30 +138,52 @@ pub(crate) fn sctp_peeloff_internal(
 pub(crate) fn sctp_socket_internal(
     domain: libc::c_int,
     assoc: crate::SocketToAssociation,
+    flags: SocketFlags,
+    nonblocking: bool,
 ) -> std::io::Result<RawFd> {
+    // The async sockets are created non-blocking, so that they can be registered for polling right
+    // away. The blocking sockets are left in the blocking mode.
+    let mut type_flags = 0;
+    if nonblocking {
+        type_flags |= libc::SOCK_NONBLOCK;
+    }
+    if flags.contains(SocketFlags::CLOEXEC) {
+        type_flags |= libc::SOCK_CLOEXEC;
+    }
+
     unsafe {
         let rawfd = match assoc {
             crate::SocketToAssociation::OneToOne => {
                 log::debug!("Creating TCP Style Socket.");
-                libc::socket(domain, libc::SOCK_STREAM, libc::IPPROTO_SCTP)
+                libc::socket(domain, libc::SOCK_STREAM | type_flags, libc::IPPROTO_SCTP)
             }
             crate::SocketToAssociation::OneToMany => {
                 log::debug!("Creating UDP Style Socket.");
-                libc::socket(domain, libc::SOCK_SEQPACKET, libc::IPPROTO_SCTP)
+                libc::socket(
+                    domain,
+                    libc::SOCK_SEQPACKET | type_flags,
+                    libc::IPPROTO_SCTP,
+                )
             }
         };
-
-        log::debug!("Setting 'socket' to Non-blocking socket.");
-        set_fd_non_blocking(rawfd)?;
+        if rawfd < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
 
         Ok(rawfd)
     }
 }
 
 // Implementation of `listen` using `libc::listen`
-pub(crate) fn sctp_listen_internal(fd: AsyncFd<RawFd>, backlog: i32) -> std::io::Result<Listener> {
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub(crate) fn sctp_listen_internal(fd: RegisteredFd, backlog: i32) -> std::io::Result<Listener> {
+    sctp_listen_syscall(fd.as_raw_fd(), backlog)?;
+    Listener::from_rawfd(fd.into_inner())
+}
+
+// Actual `listen` call.
+pub(crate) fn sctp_listen_syscall(rawfd: RawFd, backlog: i32) -> std::io::Result<()> {
     unsafe {
-        let rawfd = *fd.get_ref();
         let result = libc::listen(rawfd, backlog);
 
         if result < 0 {
@@ -159,25 +193,25 @@ pub(crate) fn sctp_listen_internal(fd: AsyncFd<RawFd>, backlog: i32) -> std::io:
             );
             Err(std::io::Error::last_os_error())
         } else {
-            Listener::from_rawfd(fd.into_inner())
+            Ok(())
         }
     }
 }
 
 // Implmentation of `sctp_getpaddrs` using `libc::getsockopt`
 pub(crate) fn sctp_getpaddrs_internal(
-    fd: &AsyncFd<RawFd>,
+    fd: &impl AsRawFd,
     assoc_id: AssociationId,
 ) -> std::io::Result<Vec<SocketAddr>> {
-    sctp_getaddrs_internal(*fd.get_ref(), SCTP_GET_PEER_ADDRS, assoc_id)
+    sctp_getaddrs_internal(fd.as_raw_fd(), SCTP_GET_PEER_ADDRS, assoc_id)
 }
 
 // Implmentation of `sctp_getladdrs` using `libc::getsockopt`
 pub(crate) fn sctp_getladdrs_internal(
-    fd: &AsyncFd<RawFd>,
+    fd: &impl AsRawFd,
     assoc_id: AssociationId,
 ) -> std::io::Result<Vec<SocketAddr>> {
-    sctp_getaddrs_internal(*fd.get_ref(), SCTP_GET_LOCAL_ADDRS, assoc_id)
+    sctp_getaddrs_internal(fd.as_raw_fd(), SCTP_GET_LOCAL_ADDRS, assoc_id)
 }
 
 // Actual function performing `sctp_getpaddrs` or `sctp_getladdrs`
@@ -262,7 +296,7 @@ fn sctp_getaddrs_internal(
                     );
                 } else {
                     // Unsupported Family - should never come here.
-                    return Err(std::io::Error::from_raw_os_error(22));
+                    return Err(SctpError::InvalidAddressFamily.into());
                 }
             }
             Ok(peeraddrs)
@@ -271,10 +305,70 @@ fn sctp_getaddrs_internal(
 }
 
 // Implementation of `sctp_connectx` using `getsockopt` and new API using `SCTP_SOCKOPT_CONNECTX3`.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 pub(crate) async fn sctp_connectx_internal(
-    fd: AsyncFd<RawFd>,
+    fd: RegisteredFd,
     addrs: &[SocketAddr],
+    timeout: Option<std::time::Duration>,
 ) -> std::io::Result<(ConnectedSocket, AssociationId)> {
+    let assoc_id = match sctp_connectx_syscall(fd.as_raw_fd(), addrs) {
+        Ok(assoc_id) => assoc_id,
+        Err(e) => {
+            // if we get here, `fd` won't be consumed by a `ConnectedSocket` and thus
+            // won't be closed on drop. Need to manually close here to avoid leaving
+            // sockets behind if the application does not exit.
+            close_internal(&fd);
+            return Err(e);
+        }
+    };
+
+    log::trace!("Waiting to connect...");
+    let writable = match timeout {
+        Some(timeout) => reactor::timeout(timeout, fd.writable())
+            .await
+            .unwrap_or_else(|| {
+                log::error!("Timed out after {:?}, while connecting.", timeout);
+                // Abort the association being setup, rather than shutting it down gracefully.
+                _ = set_linger_internal(&fd, Some(std::time::Duration::ZERO));
+                Err(std::io::Error::from_raw_os_error(libc::ETIMEDOUT))
+            }),
+        None => fd.writable().await,
+    };
+    if let Err(e) = writable {
+        // `fd` won't be consumed by a `ConnectedSocket`, close it (See below).
+        close_internal(&fd);
+        return Err(e);
+    }
+    log::trace!("Connected...");
+
+    let assoc_id = match connected_assoc_id(&fd, assoc_id) {
+        Ok(assoc_id) => assoc_id,
+        Err(e) => {
+            // if we get here, `fd` won't be consumed by a `ConnectedSocket` and thus
+            // won't be closed on drop. Need to manually close here to avoid leaving
+            // sockets behind if the application does not exit.
+            close_internal(&fd);
+            return Err(e);
+        }
+    };
+
+    // We can (and should) now 'consume' the passed `fd` or else 'registration' of next
+    // `ConnectedSocket` (during `RegisteredFd::new` would fail. Consuming the `RegisteredFd` would
+    // de-register.)
+    // Also, since this `fd` is the 'original' created with `socket` call, no need to set it to
+    // non-blocking again.
+    let rawfd = fd.into_inner();
+
+    Ok((ConnectedSocket::from_rawfd(rawfd)?, assoc_id))
+}
+
+// Actual `sctp_connectx` call using `SCTP_SOCKOPT_CONNECTX3`. Returns the association ID
+// reported by the kernel. On a non-blocking socket, the connection is in progress (`EINPROGRESS`
+// is not an error), while on a blocking socket, the call returns after the association is set up.
+pub(crate) fn sctp_connectx_syscall(
+    raw_fd: RawFd,
+    addrs: &[SocketAddr],
+) -> std::io::Result<AssociationId> {
     let mut addrs_u8: Vec<u8> = vec![];
 
     log::debug!("Connecting to {:?} using `getsockopt`", addrs);
@@ -287,12 +381,11 @@ pub(crate) async fn sctp_connectx_internal(
 
     let addrs_len = addrs_u8.len();
 
-    let raw_fd = *fd.get_ref();
     // Safety: The passed vector is valid during the function call and hence the passed reference
     // to raw data is valid.
     unsafe {
         let mut params = ConnectxParam {
-            assoc_id: 0,
+            assoc_id: 0.into(),
             addrs_size: addrs_len.try_into().unwrap(),
             addrs: addrs_u8.as_mut_ptr(),
         };
@@ -312,124 +405,160 @@ pub(crate) async fn sctp_connectx_internal(
             if last_error.raw_os_error() != Some(libc::EINPROGRESS) {
                 log::error!(
                     "Error: '{}' while connecting using `getsockopt`.",
-                    std::io::Error::last_os_error()
+                    last_error
                 );
-                // if we get here, `fd` won't be consumed by a `ConnectedSocket` and thus
-                // won't be closed on drop. Need to manually close here to avoid leaving
-                // sockets behind if the application does not exit.
-                close_internal(&fd);
                 return Err(last_error);
             }
         }
 
-        log::trace!("Waiting to connect...");
-        let _guard = fd.writable().await?;
-        log::trace!("Connected...");
+        Ok(params.assoc_id)
+    }
+}
 
-        let sctp_status = sctp_get_status_internal(&fd, params.assoc_id);
-        if let Err(e) = sctp_status {
-            let err = if e.raw_os_error() != Some(libc::EINVAL) {
+// Returns the association ID of the association set up by `sctp_connectx_syscall`.
+//
+// If the peer aborts the association during the setup (eg. during the cookie exchange), the error
+// is pending on the socket (One to One sockets) or the association no longer exists and getting
+// the status fails with `EINVAL` (One to Many sockets), `ECONNREFUSED` is returned in that case.
+pub(crate) fn connected_assoc_id(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+) -> std::io::Result<AssociationId> {
+    let sctp_status = pending_socket_error(fd)
+        .and_then(|_| sctp_get_status_internal(fd, assoc_id))
+        .map_err(|e| {
+            if !matches!(
+                e.raw_os_error(),
+                Some(libc::EINVAL) | Some(libc::ECONNRESET)
+            ) {
                 e
             } else {
-                log::error!("Received `EINVAL`, while getting status, returning `ECONNREFUSED`.");
-                std::io::Error::from_raw_os_error(libc::ECONNREFUSED)
-            };
-            // if we get here, `fd` won't be consumed by a `ConnectedSocket` and thus
-            // won't be closed on drop. Need to manually close here to avoid leaving
-            // sockets behind if the application does not exit.
-            close_internal(&fd);
-            return Err(err);
-        }
+                log::error!(
+                    "Received `{}`, while connecting, returning `ECONNREFUSED`.",
+                    e
+                );
+                SctpError::ConnectionRefused.into()
+            }
+        })?;
 
-        log::debug!(
-            "Socket State for Assoc ID: {},  {:#?}",
-            params.assoc_id,
-            sctp_status.unwrap().state
-        );
+    // For One to One sockets, the association ID returned by the kernel while connecting is not
+    // always valid (the socket options ignore the association ID for these sockets), hence the
+    // association ID from the status is used.
+    log::debug!(
+        "Socket State for Assoc ID: {},  {:#?}",
+        sctp_status.assoc_id,
+        sctp_status.state
+    );
+    Ok(sctp_status.assoc_id)
+}
 
-        // We can (and should) now 'consume' the passed `fd` or else 'registration' of next
-        // `ConnectedSocket` (during `AsyncFd::new` would fail. Consuming the `AsyncFd` would
-        // de-register.)
-        // Also, since this `fd` is the 'original' created with `socket` call, no need to set it to
-        // non-blocking again.
-        let rawfd = fd.into_inner();
+// Returns the pending error (`SO_ERROR`) on the socket if any. Note: This clears the error.
+fn pending_socket_error(fd: &impl AsRawFd) -> std::io::Result<()> {
+    match getsockopt_int_internal(fd, libc::SOL_SOCKET, libc::SO_ERROR)? {
+        0 => Ok(()),
+        errno => Err(std::io::Error::from_raw_os_error(errno)),
+    }
+}
 
-        Ok((ConnectedSocket::from_rawfd(rawfd)?, params.assoc_id))
+// Poll implementation of `accept` - we just call the `libc::accept` allowing it to fail if the
+// socket type is not the right one (UDP Style `SOCK_SEQPACKET`).
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub(crate) fn poll_accept_internal(
+    fd: &RegisteredFd,
+    cx: &mut std::task::Context<'_>,
+) -> std::task::Poll<std::io::Result<(ConnectedSocket, SocketAddr)>> {
+    use std::task::Poll;
+
+    // This is ugly for the following reasons - On the `SEQPACKET` sockets, we do not get
+    // `readable` ready at all for the `accept`.  (Why not sure? Even when tried after sending
+    // some dummy data to make sure we can recv on it.) Thus we try `accept` first for
+    // `SEQPACKET` sockets, this `accept` would fail with `EINVAL` and for `STREAM` sockets,
+    // this 'may' fail with `EWOULDBLOCK`. If it does, we poll for `readable` event, when ready, in
+    // the next iteration of the `loop`, we won't get `EWOULDBLOCK` and will actually `accept`.
+    loop {
+        match try_accept_internal(fd) {
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                // We got an `EWOULDBLOCK` let's wait.
+                match fd.poll_read_ready(cx) {
+                    Poll::Ready(Ok(())) => {}
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+            result => return Poll::Ready(result),
+        }
     }
 }
 
-// Implementation of `accept` - we just call the `libc::accept` allowing it to fail if the socket
-// type is not the right one (UDP Style `SOCK_SEQPACKET`).
-pub(crate) async fn accept_internal(
-    fd: &AsyncFd<RawFd>,
-) -> std::io::Result<(ConnectedSocket, SocketAddr)> {
+// Calls `accept` once on the socket. Returns an error of kind `WouldBlock` if there is no pending
+// connection.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+fn try_accept_internal(fd: &RegisteredFd) -> std::io::Result<(ConnectedSocket, SocketAddr)> {
+    // The accepted socket is created non-blocking. The read readiness is cleared on `EWOULDBLOCK`.
+    let (rawfd, socketaddr) =
+        fd.try_read_with(|rawfd| sctp_accept_syscall(rawfd, libc::SOCK_NONBLOCK))?;
+
+    Ok((ConnectedSocket::from_rawfd(rawfd)?, socketaddr))
+}
+
+// Actual `accept` call. The accepted socket is created with the `type_flags` (eg.
+// `SOCK_NONBLOCK`) and has the same close-on-exec setting as the listening socket.
+pub(crate) fn sctp_accept_syscall(
+    raw_fd: RawFd,
+    type_flags: libc::c_int,
+) -> std::io::Result<(RawFd, SocketAddr)> {
     // Safety: Both `addrs_buff` and `addrs_len` are in the scope and hence are valid pointers.
     unsafe {
-        let raw_fd = *fd.get_ref();
-
-        // This is ugly for the following reasons - On the `SEQPACKET` sockets, we do not get
-        // `readable` ready at all for the `accept`.  (Why not sure? Even when tried after sending
-        // some dummy data to make sure we can recv on it.) Thus we try `accept` first for
-        // `SEQPACKET` sockets, this `accept` would fail with `EINVAL` and for `STREAM` sockets,
-        // this 'may' fail with `EWOULDBLOCK`. If it does, we wait for `readable` event again, in
-        // the next iteration of the `loop`, we won't get `EWOULDBLOCK` and will actually `accept`.
-        loop {
-            // this should be enough to `accept` a connection normally `sockaddr`s maximum size is
-            // 28 for the `sa_family` we care about.
-            let mut addrs_buff: Vec<u8> = vec![0; 32];
-            let mut addrs_len = addrs_buff.len();
-
-            let result = {
-                let addrs_len_ptr = std::ptr::addr_of_mut!(addrs_len);
-                let addrs_buff_ptr = addrs_buff.as_mut_ptr();
-
-                libc::accept(
-                    raw_fd,
-                    addrs_buff_ptr as *mut _ as *mut libc::sockaddr,
-                    addrs_len_ptr as *mut _ as *mut libc::socklen_t,
-                )
-            };
+        let accept_flags = if fd_is_cloexec(raw_fd)? {
+            type_flags | libc::SOCK_CLOEXEC
+        } else {
+            type_flags
+        };
 
-            if result < 0 {
-                let last_error = std::io::Error::last_os_error();
-                if last_error.raw_os_error() != Some(libc::EWOULDBLOCK) {
-                    log::error!(
-                        "Error: '{}' while `accept`ing on the socket.",
-                        std::io::Error::last_os_error()
-                    );
-                    return Err(last_error);
-                }
+        // this should be enough to `accept` a connection normally `sockaddr`s maximum size is
+        // 28 for the `sa_family` we care about.
+        let mut addrs_buff: Vec<u8> = vec![0; 32];
+        let mut addrs_len = addrs_buff.len();
 
-                // We got an `EWOULDBLOCK` let's wait.
-                fd.readable().await?.clear_ready();
-            } else {
-                let os_socketaddr = OsSocketAddr::copy_from_raw(
-                    addrs_buff.as_ptr() as *const _ as *const libc::sockaddr,
-                    addrs_len.try_into().unwrap(),
-                );
-                log::trace!(
-                    "fd: {}, result: {},  addrs_len: {}, addrs_u8: {:?}",
-                    raw_fd,
-                    result,
-                    addrs_len,
-                    addrs_buff,
-                );
-                let socketaddr = os_socketaddr.into_addr().unwrap();
+        let result = {
+            let addrs_len_ptr = std::ptr::addr_of_mut!(addrs_len);
+            let addrs_buff_ptr = addrs_buff.as_mut_ptr();
 
-                log::debug!("Setting 'accepted' socket to non-blocking.");
-                set_fd_non_blocking(result as RawFd)?;
+            libc::accept4(
+                raw_fd,
+                addrs_buff_ptr as *mut _ as *mut libc::sockaddr,
+                addrs_len_ptr as *mut _ as *mut libc::socklen_t,
+                accept_flags,
+            )
+        };
 
-                return Ok((ConnectedSocket::from_rawfd(result as RawFd)?, socketaddr));
+        if result < 0 {
+            let last_error = std::io::Error::last_os_error();
+            if last_error.raw_os_error() != Some(libc::EWOULDBLOCK) {
+                log::error!("Error: '{}' while `accept`ing on the socket.", last_error);
             }
+            return Err(last_error);
         }
+
+        let os_socketaddr = OsSocketAddr::copy_from_raw(
+            addrs_buff.as_ptr() as *const _ as *const libc::sockaddr,
+            addrs_len.try_into().unwrap(),
+        );
+        log::trace!(
+            "fd: {}, result: {},  addrs_len: {}, addrs_u8: {:?}",
+            raw_fd,
+            result,
+            addrs_len,
+            addrs_buff,
+        );
+        let socketaddr = os_socketaddr.into_addr().unwrap();
+
+        Ok((result as RawFd, socketaddr))
     }
 }
 
 // Shutdown implementation for `Listener` and `ConnectedSocket`.
-pub(crate) fn shutdown_internal(
-    fd: &AsyncFd<RawFd>,
-    how: std::net::Shutdown,
-) -> std::io::Result<()> {
+pub(crate) fn shutdown_internal(fd: &impl AsRawFd, how: std::net::Shutdown) -> std::io::Result<()> {
     use std::net::Shutdown;
 
     log::debug!("Calling 'shutdown' on socket with flags: {:?}", how);
@@ -442,7 +571,7 @@ pub(crate) fn shutdown_internal(
     // Safety: No real undefined behavior as long as fd is a valid fd and if fd is not a valid fd
     // the underlying systemcall will error.
     unsafe {
-        let result = libc::shutdown(*fd.get_ref(), flags);
+        let result = libc::shutdown(fd.as_raw_fd(), flags);
         if result < 0 {
             Err(std::io::Error::last_os_error())
         } else {
@@ -451,141 +580,523 @@ pub(crate) fn shutdown_internal(
     }
 }
 
-// Implementation for the receive side for SCTP.
-// TODO: Handle Control Message Header
+// Retries the `syscall` as long as it fails with `EINTR`. A signal delivered to the process while
+// in the system call is not an error for the caller (same as the `std` and `tokio` I/O types).
+// The readiness of the socket is not affected.
+pub(crate) fn retry_on_interrupt<T>(
+    mut syscall: impl FnMut() -> std::io::Result<T>,
+) -> std::io::Result<T> {
+    loop {
+        match syscall() {
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {
+                log::debug!("System call interrupted, retrying.");
+            }
+            result => return result,
+        }
+    }
+}
+
+// Default size of the buffer used for receiving a message.
+pub(crate) const RECV_BUFFER_SIZE: usize = 4096;
+
+// Implementation for the receive side for SCTP. The message is received in a buffer of `capacity`
+// bytes.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 pub(crate) async fn sctp_recvmsg_internal(
-    fd: &AsyncFd<RawFd>,
+    fd: &RegisteredFd,
+    capacity: usize,
 ) -> std::io::Result<NotificationOrData> {
     log::debug!("Receiving Message on the socket.");
 
-    //
-    // Safety: recvmsg_hdr is valid in the current scope.
+    fd.read_with(|rawfd| retry_on_interrupt(|| sctp_recvmsg_syscall(rawfd, capacity, 0)))
+        .await
+}
+
+// Implementation for the receive side for SCTP, that receives the message into the passed buffer.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub(crate) async fn sctp_recvmsg_into_internal(
+    fd: &RegisteredFd,
+    buffer: &mut Vec<u8>,
+    capacity: usize,
+) -> std::io::Result<ReceivedInfo> {
+    log::debug!("Receiving Message on the socket into the buffer.");
+
+    if buffer.capacity() == 0 {
+        buffer.reserve(capacity);
+    }
+
+    fd.read_with(|rawfd| retry_on_interrupt(|| sctp_recvmsg_into_syscall(rawfd, buffer, 0)))
+        .await
+}
+
+// Non-blocking receive: Returns `EWOULDBLOCK` if the socket is not ready for reading.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub(crate) fn sctp_try_recvmsg_internal(
+    fd: &RegisteredFd,
+    capacity: usize,
+) -> std::io::Result<NotificationOrData> {
+    log::debug!("Trying to receive Message on the socket.");
+
+    fd.try_read_with(|rawfd| retry_on_interrupt(|| sctp_recvmsg_syscall(rawfd, capacity, 0)))
+}
+
+// Peeks at the next message: Same as `sctp_recvmsg_internal`, but the message is received with
+// `MSG_PEEK` and hence is left in the receive queue of the socket.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub(crate) async fn sctp_peekmsg_internal(
+    fd: &RegisteredFd,
+    capacity: usize,
+) -> std::io::Result<NotificationOrData> {
+    log::debug!("Peeking at the Message on the socket.");
+
+    fd.read_with(|rawfd| {
+        retry_on_interrupt(|| sctp_recvmsg_syscall(rawfd, capacity, libc::MSG_PEEK))
+    })
+    .await
+}
+
+// Allocates a buffer of `capacity` bytes for receiving the message and receives the message into
+// it.
+pub(crate) fn sctp_recvmsg_syscall(
+    rawfd: RawFd,
+    capacity: usize,
+    flags: libc::c_int,
+) -> std::io::Result<NotificationOrData> {
+    let mut recv_buffer = Vec::with_capacity(capacity);
+
+    match sctp_recvmsg_into_syscall(rawfd, &mut recv_buffer, flags)? {
+        ReceivedInfo::Notification(notification) => {
+            Ok(NotificationOrData::Notification(notification))
+        }
+        ReceivedInfo::Data {
+            rcv_info,
+            nxt_info,
+            flags,
+            from,
+        } => Ok(NotificationOrData::Data(ReceivedData {
+            payload: recv_buffer,
+            rcv_info,
+            nxt_info,
+            flags,
+            from,
+        })),
+        ReceivedInfo::Eof => Ok(NotificationOrData::Eof),
+    }
+}
+
+// Actual `recvmsg` call. This performs a single `libc::recvmsg` with the given `flags` (eg.
+// `MSG_PEEK`) and returns the error (including `EWOULDBLOCK`) as it is to the caller.
+//
+// The message is received into the spare capacity of the `buffer` and the length of the `buffer`
+// is set to the length of the received message. No other allocation is performed, when a data
+// message is received.
+fn sctp_recvmsg_into_syscall(
+    rawfd: RawFd,
+    buffer: &mut Vec<u8>,
+    flags: libc::c_int,
+) -> std::io::Result<ReceivedInfo> {
+    // Safety: recvmsg_hdr is valid in the current scope. The `buffer` has `capacity` bytes
+    // available, out of which kernel writes at-most `capacity` bytes and we set the length of the
+    // `buffer` to the number of bytes written by the kernel.
     unsafe {
-        let rawfd = *fd.get_ref();
+        buffer.clear();
 
-        let mut recv_buffer = vec![0_u8; 4096];
-        let msg_control_size = libc::CMSG_SPACE(
-            std::mem::size_of::<RcvInfo>() as u32 + std::mem::size_of::<NxtInfo>() as u32,
-        );
-        let mut msg_control = vec![0u8; msg_control_size.try_into().unwrap()];
-        let mut from_buffer = vec![0u8; 256];
+        // Control Buffer on the stack large enough for the `RcvInfo` and the `NxtInfo`. We use
+        // `u64` for correct alignment of the `cmsghdr`.
+        let mut msg_control = [0_u64; 16];
+        let msg_control_size =
+            cmsg_space::<RcvInfo>() + cmsg_space::<NxtInfo>() + cmsg_space::<SndRcvInfo>();
+        debug_assert!(msg_control_size <= std::mem::size_of_val(&msg_control));
 
-        loop {
-            let mut guard = fd.readable().await?;
+        let mut from_buffer = std::mem::MaybeUninit::<libc::sockaddr_storage>::zeroed();
 
-            recv_buffer.fill(0_u8);
-            let mut recv_iov = libc::iovec {
-                iov_base: recv_buffer.as_mut_ptr() as *mut _ as *mut libc::c_void,
-                iov_len: recv_buffer.len(),
-            };
+        let mut recv_iov = libc::iovec {
+            iov_base: buffer.as_mut_ptr() as *mut _ as *mut libc::c_void,
+            iov_len: buffer.capacity(),
+        };
+
+        #[cfg(target_os = "macos")]
+        let msg_controllen = msg_control_size as u32;
+
+        #[cfg(not(target_os = "macos"))]
+        let msg_controllen = msg_control_size;
+
+        let mut recvmsg_header = libc::msghdr {
+            msg_name: from_buffer.as_mut_ptr() as *mut _ as *mut libc::c_void,
+            msg_namelen: std::mem::size_of::<libc::sockaddr_storage>() as u32,
+            msg_iov: &mut recv_iov,
+            msg_iovlen: 1,
+            msg_control: msg_control.as_mut_ptr() as *mut _ as *mut libc::c_void,
+            msg_controllen,
+            msg_flags: 0,
+        };
+
+        let result = libc::recvmsg(rawfd, &mut recvmsg_header as *mut libc::msghdr, flags);
+        if result < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        buffer.set_len(result as usize);
+
+        Ok(received_info_from_msghdr(&recvmsg_header, buffer))
+    }
+}
 
-            msg_control.fill(0);
-            from_buffer.fill(0);
-            #[cfg(target_os = "macos")]
-            let msg_controllen = msg_control_size as u32;
+// Batched receive: Receives up to `max` messages using a single `libc::recvmmsg` call, after the
+// socket is readable.
+#[cfg(target_os = "linux")]
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub(crate) async fn sctp_recvmmsg_internal(
+    fd: &RegisteredFd,
+    max: usize,
+    capacity: usize,
+) -> std::io::Result<Vec<NotificationOrData>> {
+    log::debug!("Receiving up to {} Messages on the socket.", max);
+
+    if max == 0 {
+        return Ok(vec![]);
+    }
 
-            #[cfg(not(target_os = "macos"))]
-            let msg_controllen = msg_control_size as usize;
+    fd.read_with(|rawfd| retry_on_interrupt(|| sctp_recvmmsg_syscall(rawfd, max, capacity)))
+        .await
+}
 
-            let mut recvmsg_header = libc::msghdr {
-                msg_name: from_buffer.as_mut_ptr() as *mut _ as *mut libc::c_void,
-                msg_namelen: from_buffer.len() as u32,
-                msg_iov: &mut recv_iov,
+// Actual `recvmmsg` call. All the buffers (payload, control and name) for each of the `max`
+// messages are set up before the call and the received messages are parsed exactly like in the
+// `recvmsg` case.
+#[cfg(target_os = "linux")]
+fn sctp_recvmmsg_syscall(
+    rawfd: RawFd,
+    max: usize,
+    capacity: usize,
+) -> std::io::Result<Vec<NotificationOrData>> {
+    let msg_control_size =
+        cmsg_space::<RcvInfo>() + cmsg_space::<NxtInfo>() + cmsg_space::<SndRcvInfo>();
+
+    let mut recv_buffers = (0..max)
+        .map(|_| Vec::<u8>::with_capacity(capacity))
+        .collect::<Vec<_>>();
+    // A single allocation for the control buffers of all the messages. Using `u64` for correct
+    // alignment of the `cmsghdr`.
+    let mut msg_controls = vec![0_u64; msg_control_size.div_ceil(8) * max];
+    // Safety: An all zeroes `sockaddr_storage` is valid.
+    let mut from_buffers = vec![unsafe { std::mem::zeroed::<libc::sockaddr_storage>() }; max];
+
+    let mut recv_iovs = recv_buffers
+        .iter_mut()
+        .map(|buffer| libc::iovec {
+            iov_base: buffer.as_mut_ptr() as *mut _ as *mut libc::c_void,
+            iov_len: buffer.capacity(),
+        })
+        .collect::<Vec<_>>();
+
+    let mut mmsg_headers = recv_iovs
+        .iter_mut()
+        .zip(msg_controls.chunks_exact_mut(msg_control_size.div_ceil(8)))
+        .zip(from_buffers.iter_mut())
+        .map(|((recv_iov, msg_control), from_buffer)| libc::mmsghdr {
+            msg_hdr: libc::msghdr {
+                msg_name: from_buffer as *mut _ as *mut libc::c_void,
+                msg_namelen: std::mem::size_of::<libc::sockaddr_storage>() as u32,
+                msg_iov: recv_iov,
                 msg_iovlen: 1,
                 msg_control: msg_control.as_mut_ptr() as *mut _ as *mut libc::c_void,
-                msg_controllen,
+                msg_controllen: msg_control_size,
                 msg_flags: 0,
-            };
+            },
+            msg_len: 0,
+        })
+        .collect::<Vec<_>>();
+
+    // Safety: All the buffers referred to by the `mmsg_headers` are valid in the current scope.
+    // For every received message, kernel writes at-most `capacity` bytes in the corresponding
+    // buffer and we set the length of the buffer to the number of bytes written by the kernel.
+    unsafe {
+        let result = libc::recvmmsg(
+            rawfd,
+            mmsg_headers.as_mut_ptr(),
+            max as libc::c_uint,
+            0,
+            std::ptr::null_mut(),
+        );
+        if result < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
 
-            let flags = 0 as libc::c_int;
-            let result = libc::recvmsg(rawfd, &mut recvmsg_header as *mut libc::msghdr, flags);
-            if result < 0 {
-                let last_error = std::io::Error::last_os_error();
-                if last_error.kind() == std::io::ErrorKind::WouldBlock {
-                    guard.clear_ready();
-                } else {
-                    return Err(last_error);
+        let received = result as usize;
+        log::debug!("Received {} Messages.", received);
+
+        let mut messages = Vec::with_capacity(received);
+        for (mmsg_header, mut buffer) in mmsg_headers.iter().zip(recv_buffers).take(received) {
+            buffer.set_len(mmsg_header.msg_len as usize);
+            let message = match received_info_from_msghdr(&mmsg_header.msg_hdr, &buffer) {
+                ReceivedInfo::Notification(notification) => {
+                    NotificationOrData::Notification(notification)
                 }
-            } else {
-                let received_flags: u32 = recvmsg_header.msg_flags.try_into().unwrap();
-                recv_buffer.truncate(result as usize);
-
-                if received_flags & MSG_NOTIFICATION != 0 {
-                    log::debug!("Received Notification.");
-                    return Ok(NotificationOrData::Notification(notification_from_message(
-                        &recv_buffer,
-                    )));
-                } else {
-                    let mut rcv_info = None;
-                    let mut nxt_info = None;
-                    let mut cmsghdr = libc::CMSG_FIRSTHDR(&mut recvmsg_header as *mut libc::msghdr);
-                    loop {
-                        if cmsghdr.is_null() {
-                            break;
-                        }
-                        if (*cmsghdr).cmsg_level != libc::IPPROTO_SCTP {
-                            log::warn!("cmsg_level is not SCTP");
-                            continue;
-                        }
-
-                        if (*cmsghdr).cmsg_type == CmsgType::RcvInfo as i32 {
-                            let mut recv_info_internal = RcvInfo::default();
-                            let cmsg_data = libc::CMSG_DATA(cmsghdr);
-                            std::ptr::copy(
-                                cmsg_data,
-                                &mut recv_info_internal as *mut _ as *mut u8,
-                                std::mem::size_of::<RcvInfo>(),
-                            );
-                            log::debug!("Received: RcvInfo: {:#?}", recv_info_internal);
-                            rcv_info = Some(recv_info_internal);
-                        }
-
-                        if (*cmsghdr).cmsg_type == CmsgType::NxtInfo as i32 {
-                            let mut nxt_info_internal = NxtInfo::default();
-                            let cmsg_data = libc::CMSG_DATA(cmsghdr);
-                            std::ptr::copy(
-                                cmsg_data,
-                                &mut nxt_info_internal as *mut _ as *mut u8,
-                                std::mem::size_of::<NxtInfo>(),
-                            );
-                            log::debug!("Received: NxtInfo: {:#?}", nxt_info_internal);
-                            nxt_info = Some(nxt_info_internal);
-                        }
-
-                        cmsghdr = libc::CMSG_NXTHDR(
-                            msg_control.as_mut_ptr() as *mut _ as *mut libc::msghdr,
-                            cmsghdr,
-                        );
-                    }
-
-                    log::debug!("Received Data.");
-                    return Ok(NotificationOrData::Data(ReceivedData {
-                        payload: recv_buffer,
-                        rcv_info,
-                        nxt_info,
-                    }));
+                ReceivedInfo::Data {
+                    rcv_info,
+                    nxt_info,
+                    flags,
+                    from,
+                } => NotificationOrData::Data(ReceivedData {
+                    payload: buffer,
+                    rcv_info,
+                    nxt_info,
+                    flags,
+                    from,
+                }),
+                ReceivedInfo::Eof => NotificationOrData::Eof,
+            };
+            messages.push(message);
+        }
+
+        Ok(messages)
+    }
+}
+
+// Parses the received message: A notification is parsed from the `buffer`, for the data the
+// ancillary data (if any) is parsed from the control buffer of the `msghdr`.
+//
+// Safety: The `msghdr` must be the one returned by the kernel in a successful `recvmsg` (or
+// `recvmmsg`) call.
+unsafe fn received_info_from_msghdr(recvmsg_header: &libc::msghdr, buffer: &[u8]) -> ReceivedInfo {
+    let received_flags: u32 = recvmsg_header.msg_flags.try_into().unwrap();
+
+    if received_flags & MSG_NOTIFICATION != 0 {
+        log::debug!("Received Notification.");
+        ReceivedInfo::Notification(notification_from_message(buffer))
+    } else if buffer.is_empty() {
+        log::debug!("Received End of File.");
+        ReceivedInfo::Eof
+    } else {
+        let mut rcv_info = None;
+        let mut nxt_info = None;
+        let mut sndrcv = None;
+        let mut cmsghdr = libc::CMSG_FIRSTHDR(recvmsg_header as *const libc::msghdr);
+        while !cmsghdr.is_null() {
+            if (*cmsghdr).cmsg_level != libc::IPPROTO_SCTP {
+                log::warn!("cmsg_level is not SCTP");
+            } else if (*cmsghdr).cmsg_type == CmsgType::RcvInfo as i32 {
+                let mut recv_info_internal = RcvInfo::default();
+                let cmsg_data = libc::CMSG_DATA(cmsghdr);
+                std::ptr::copy(
+                    cmsg_data,
+                    &mut recv_info_internal as *mut _ as *mut u8,
+                    std::mem::size_of::<RcvInfo>(),
+                );
+                log::debug!("Received: RcvInfo: {:#?}", recv_info_internal);
+                rcv_info = Some(recv_info_internal);
+            } else if (*cmsghdr).cmsg_type == CmsgType::NxtInfo as i32 {
+                let mut nxt_info_internal = NxtInfo::default();
+                let cmsg_data = libc::CMSG_DATA(cmsghdr);
+                std::ptr::copy(
+                    cmsg_data,
+                    &mut nxt_info_internal as *mut _ as *mut u8,
+                    std::mem::size_of::<NxtInfo>(),
+                );
+                log::debug!("Received: NxtInfo: {:#?}", nxt_info_internal);
+                nxt_info = Some(nxt_info_internal);
+            } else if (*cmsghdr).cmsg_type == CmsgType::SndRcv as i32 {
+                // Legacy `SCTP_SNDRCV` delivered when `Event::DataIo` is subscribed. The fields
+                // common with the `RcvInfo` are used, only if the `RcvInfo` is not received.
+                let mut sndrcv_info = SndRcvInfo::default();
+                let cmsg_data = libc::CMSG_DATA(cmsghdr);
+                std::ptr::copy(
+                    cmsg_data,
+                    &mut sndrcv_info as *mut _ as *mut u8,
+                    std::mem::size_of::<SndRcvInfo>(),
+                );
+                log::debug!("Received: SndRcvInfo: {:#?}", sndrcv_info);
+                sndrcv = Some(sndrcv_info);
+            }
+
+            cmsghdr = libc::CMSG_NXTHDR(recvmsg_header as *const libc::msghdr, cmsghdr);
+        }
+        if rcv_info.is_none() {
+            rcv_info = sndrcv.map(RcvInfo::from);
+        }
+
+        let flags = RecvFlags::from_msg_flags(recvmsg_header.msg_flags);
+        if flags.contains(RecvFlags::CTRUNC) {
+            log::warn!("Ancillary data is truncated, the control buffer is too small.");
+        }
+
+        // The address of the peer, the message is received from (filled in by the kernel in the
+        // `msg_name`).
+        let from = if recvmsg_header.msg_name.is_null() || recvmsg_header.msg_namelen == 0 {
+            None
+        } else {
+            OsSocketAddr::copy_from_raw(
+                recvmsg_header.msg_name as *const libc::sockaddr,
+                recvmsg_header.msg_namelen,
+            )
+            .into_addr()
+        };
+
+        log::debug!("Received Data.");
+        ReceivedInfo::Data {
+            rcv_info,
+            nxt_info,
+            flags,
+            from,
+        }
+    }
+}
+
+// Graceful close: Shuts down the sending side and waits for the SCTP shutdown to complete.
+//
+// Any data received while waiting is discarded, and the number of discarded messages is
+// returned. The shutdown is complete when a `Shutdown` or `AssociationChange` notification with
+// `ShutdownComplete` state is received, or when the socket signals an end of file or the
+// association is gone. The association events are subscribed to, since One to Many sockets do
+// not signal an end of file.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub(crate) async fn sctp_graceful_close_internal(fd: &RegisteredFd) -> std::io::Result<usize> {
+    log::debug!("Gracefully closing the socket.");
+
+    // The socket is being closed, so there is no need to restore the subscription later.
+    if let Err(e) =
+        sctp_subscribe_event_internal(fd, Event::Association, SubscribeEventAssocId::All, true)
+    {
+        log::debug!("Failed to subscribe to the association events: {}", e);
+    }
+
+    shutdown_internal(fd, std::net::Shutdown::Write)?;
+
+    let mut discarded = 0;
+    loop {
+        match sctp_recvmsg_internal(fd, RECV_BUFFER_SIZE).await {
+            Ok(NotificationOrData::Notification(Notification::Shutdown(_))) => break,
+            Ok(NotificationOrData::Notification(Notification::AssociationChange(
+                AssociationChange {
+                    state: AssocChangeState::ShutdownComplete,
+                    ..
+                },
+            ))) => break,
+            Ok(NotificationOrData::Eof) => break,
+            Ok(received) => {
+                log::debug!("Discarding {:?} received while closing.", received);
+                if let NotificationOrData::Data(_) = received {
+                    discarded += 1;
                 }
             }
+            Err(e)
+                if matches!(
+                    e.raw_os_error(),
+                    Some(libc::ENOTCONN) | Some(libc::ECONNRESET) | Some(libc::EPIPE)
+                ) =>
+            {
+                break
+            }
+            Err(e) => return Err(e),
         }
     }
+
+    log::debug!("Shutdown complete.");
+    Ok(discarded)
 }
 
 // Implementation of the Send side for SCTP.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 pub(crate) async fn sctp_sendmsg_internal(
-    fd: &AsyncFd<RawFd>,
+    fd: &RegisteredFd,
     to: Option<SocketAddr>,
     data: SendData,
-) -> std::io::Result<()> {
-    // Safety: All the pointers are valid because they are within the current scope.
-    // Also, this is just a wrapper over `libc` call.
-    unsafe {
-        let _ = fd.writable().await?;
+) -> std::io::Result<usize> {
+    fd.write_with(|rawfd| retry_on_interrupt(|| sctp_sendmsg_syscall(rawfd, to, &data)))
+        .await
+}
 
-        let mut send_iov = libc::iovec {
-            iov_base: data.payload.as_ptr() as *mut libc::c_void,
-            iov_len: data.payload.len(),
-        };
+// Non-blocking send: Returns `EWOULDBLOCK` if the socket is not ready for writing.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub(crate) fn sctp_try_sendmsg_internal(
+    fd: &RegisteredFd,
+    to: Option<SocketAddr>,
+    data: SendData,
+) -> std::io::Result<usize> {
+    fd.try_write_with(|rawfd| retry_on_interrupt(|| sctp_sendmsg_syscall(rawfd, to, &data)))
+}
+
+// Vectored send: The `bufs` together form a single SCTP message. The ancillary data is sent using
+// the `snd_info`.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub(crate) async fn sctp_sendmsg_vectored_internal(
+    fd: &RegisteredFd,
+    to: Option<SocketAddr>,
+    bufs: &[IoSlice<'_>],
+    snd_info: Option<SendInfo>,
+) -> std::io::Result<usize> {
+    let data = SendData {
+        snd_info,
+        ..Default::default()
+    };
+
+    fd.write_with(|rawfd| retry_on_interrupt(|| sctp_sendmsg_iov_syscall(rawfd, to, bufs, &data)))
+        .await
+}
+
+// Maximum number of chunks of a `Buf` sent without copying them into a single buffer.
+#[cfg(feature = "bytes")]
+const SEND_BUF_MAX_CHUNKS: usize = 64;
+
+// Send: The `buf` is sent as a single SCTP message. The chunks of the `buf` are sent as they are
+// (See `sctp_sendmsg_vectored_internal`), unless there are more than `SEND_BUF_MAX_CHUNKS` of
+// them, in which case these are copied first.
+#[cfg(feature = "bytes")]
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub(crate) async fn sctp_sendmsg_buf_internal(
+    fd: &RegisteredFd,
+    to: Option<SocketAddr>,
+    mut buf: impl bytes::Buf,
+    snd_info: Option<SendInfo>,
+) -> std::io::Result<usize> {
+    let mut slices = [IoSlice::new(&[]); SEND_BUF_MAX_CHUNKS];
+    if let Some(count) = buf_io_slices(&buf, &mut slices) {
+        return sctp_sendmsg_vectored_internal(fd, to, &slices[..count], snd_info).await;
+    }
+
+    log::debug!("Too many chunks in the buffer, copying them.");
+    let payload = buf.copy_to_bytes(buf.remaining());
+    sctp_sendmsg_vectored_internal(fd, to, &[IoSlice::new(&payload)], snd_info).await
+}
+
+// Fills the `slices` with the chunks of the `buf`. Returns the number of `slices` filled, or
+// `None` if the `slices` are not enough for all the chunks.
+#[cfg(feature = "bytes")]
+fn buf_io_slices<'a>(buf: &'a impl bytes::Buf, slices: &mut [IoSlice<'a>]) -> Option<usize> {
+    let count = buf.chunks_vectored(slices);
+    let filled: usize = slices[..count].iter().map(|slice| slice.len()).sum();
+    if filled == buf.remaining() {
+        Some(count)
+    } else {
+        None
+    }
+}
+
+// Actual `sendmsg` call. This performs a single `libc::sendmsg` and returns the error (including
+// `EWOULDBLOCK`) as it is to the caller.
+pub(crate) fn sctp_sendmsg_syscall(
+    rawfd: RawFd,
+    to: Option<SocketAddr>,
+    data: &SendData,
+) -> std::io::Result<usize> {
+    sctp_sendmsg_iov_syscall(rawfd, to, &[IoSlice::new(&data.payload)], data)
+}
+
+// `sendmsg` call with the payload in the `bufs`, the `payload` of the `data` is not used, only
+// the ancillary data is used.
+fn sctp_sendmsg_iov_syscall(
+    rawfd: RawFd,
+    to: Option<SocketAddr>,
+    bufs: &[IoSlice<'_>],
+    data: &SendData,
+) -> std::io::Result<usize> {
+    check_dst_addrs(rawfd, data)?;
 
+    // Safety: All the pointers are valid because they are within the current scope.
+    // Also, this is just a wrapper over `libc` call. `IoSlice` is ABI compatible with
+    // `libc::iovec` and the `bufs` outlive the `sendmsg` call.
+    unsafe {
         // We have to create this `os_sockaddr` outside the `if let ...`
         // Else it will go out of scope and we'll be using it's raw pointer.
         let os_sockaddr: OsSocketAddr;
@@ -596,22 +1107,16 @@ pub(crate) async fn sctp_sendmsg_internal(
         } else {
             (std::ptr::null::<OsSocketAddr>() as *mut libc::c_void, 0)
         };
-        // TODO: Support copy and other send info as well.
-        let msg_control_size = libc::CMSG_SPACE(std::mem::size_of::<SendInfo>() as u32);
-        let mut msg_control_buffer = vec![0u8; msg_control_size.try_into().unwrap()];
 
-        let (msg_control, msg_control_size) = if data.snd_info.is_some() {
-            // Safety: wrapper over `libc` call. the size of the structures are wellknown.
+        // Control buffer large enough to hold all the requested ancillary data. This buffer
+        // should outlive the `sendmsg` call below, as `msg_control` points into it.
+        let msg_control_size = send_cmsg_space(data);
+        let mut msg_control_buffer = send_cmsg_buffer(msg_control_size);
 
-            (
-                msg_control_buffer.as_mut_ptr() as *mut libc::c_void,
-                msg_control_size as usize,
-            )
+        let msg_control = if msg_control_size > 0 {
+            msg_control_buffer.as_mut_ptr() as *mut libc::c_void
         } else {
-            (
-                std::ptr::null::<libc::cmsghdr>() as *mut libc::c_void,
-                0_usize,
-            )
+            std::ptr::null::<libc::cmsghdr>() as *mut libc::c_void
         };
         #[cfg(target_os = "macos")]
         let msg_controllen = msg_control_size as u32;
@@ -622,64 +1127,362 @@ pub(crate) async fn sctp_sendmsg_internal(
         let mut sendmsg_header = libc::msghdr {
             msg_name: to_buffer,
             msg_namelen: to_buffer_len,
-            msg_iov: &mut send_iov,
-            msg_iovlen: 1,
+            msg_iov: bufs.as_ptr() as *mut libc::iovec,
+            msg_iovlen: bufs.len() as _,
             msg_control,
             msg_controllen,
             msg_flags: 0,
         };
 
-        let cmsg_hdr = libc::CMSG_FIRSTHDR(&sendmsg_header);
-        if !cmsg_hdr.is_null() {
-            (*cmsg_hdr).cmsg_level = libc::IPPROTO_SCTP;
-            (*cmsg_hdr).cmsg_type = CmsgType::SndInfo as i32;
-            (*cmsg_hdr).cmsg_len =
-                libc::CMSG_LEN(std::mem::size_of::<SendInfo>().try_into().unwrap())
-                    .try_into()
-                    .unwrap();
-
-            let snd_info = data.snd_info.unwrap();
-            std::ptr::copy(
-                std::ptr::addr_of!(snd_info) as *const _,
-                libc::CMSG_DATA(cmsg_hdr),
-                std::mem::size_of::<SendInfo>(),
-            );
-        }
-
-        let rawfd = *fd.get_ref();
+        write_send_cmsgs(&sendmsg_header, data);
 
         let flags = 0 as libc::c_int;
 
         let result = libc::sendmsg(rawfd, &mut sendmsg_header as *mut libc::msghdr, flags);
         if result < 0 {
-            Err(std::io::Error::last_os_error())
-        } else {
-            Ok(())
+            return Err(std::io::Error::last_os_error());
         }
+
+        let total = bufs.iter().map(|buf| buf.len()).sum::<usize>();
+        check_sent_len(result as usize, total, data)
     }
 }
 
-pub(crate) fn sctp_set_default_sendinfo_internal(
-    fd: &AsyncFd<RawFd>,
-    sendinfo: SendInfo,
-) -> std::io::Result<()> {
+// SCTP preserves the message boundaries, the message is either sent in full or not at all.
+// Should the kernel ever accept only a part of it, sending the rest would make it a separate
+// message, hence this is reported as an error instead. The kernel returns `0` for the `ABORT`
+// and `EOF` flags, the payload (if any) is the abort reason and is not sent as a message.
+fn check_sent_len(written: usize, total: usize, data: &SendData) -> std::io::Result<usize> {
+    let no_message = data.snd_info.as_ref().is_some_and(|info| {
+        info.flags.contains(SendFlags::ABORT) || info.flags.contains(SendFlags::EOF)
+    });
+    if written < total && !no_message {
+        log::error!(
+            "Only {} of {} bytes of the message were sent.",
+            written,
+            total
+        );
+        Err(SctpError::PartialSend { written }.into())
+    } else {
+        Ok(written)
+    }
+}
+
+// Batched send: Sends the messages using a single `libc::sendmmsg` call, after the socket is
+// writable.
+#[cfg(target_os = "linux")]
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub(crate) async fn sctp_sendmmsg_internal(
+    fd: &RegisteredFd,
+    msgs: Vec<(SocketAddr, SendData)>,
+) -> std::io::Result<usize> {
+    log::debug!("Sending {} Messages on the socket.", msgs.len());
+
+    if msgs.is_empty() {
+        return Ok(0);
+    }
+
+    fd.write_with(|rawfd| retry_on_interrupt(|| sctp_sendmmsg_syscall(rawfd, &msgs)))
+        .await
+}
+
+// Actual `sendmmsg` call. The `msghdr` for each of the messages is built the same way as in the
+// `sendmsg` case. Returns the number of messages sent.
+#[cfg(target_os = "linux")]
+fn sctp_sendmmsg_syscall(rawfd: RawFd, msgs: &[(SocketAddr, SendData)]) -> std::io::Result<usize> {
+    for (_, data) in msgs {
+        check_dst_addrs(rawfd, data)?;
+    }
+
+    // All the buffers below should outlive the `sendmmsg` call, as the `mmsghdr`s point into
+    // them.
+    let os_sockaddrs = msgs
+        .iter()
+        .map(|(to, _)| OsSocketAddr::from(*to))
+        .collect::<Vec<_>>();
+    let mut send_iovs = msgs
+        .iter()
+        .map(|(_, data)| libc::iovec {
+            iov_base: data.payload.as_ptr() as *mut libc::c_void,
+            iov_len: data.payload.len(),
+        })
+        .collect::<Vec<_>>();
+    let mut msg_control_buffers = msgs
+        .iter()
+        .map(|(_, data)| send_cmsg_buffer(send_cmsg_space(data)))
+        .collect::<Vec<_>>();
+
+    let mut mmsg_headers = msgs
+        .iter()
+        .zip(os_sockaddrs.iter())
+        .zip(send_iovs.iter_mut())
+        .zip(msg_control_buffers.iter_mut())
+        .map(
+            |((((_, data), os_sockaddr), send_iov), msg_control_buffer)| {
+                let msg_control_size = send_cmsg_space(data);
+                let msg_control = if msg_control_size > 0 {
+                    msg_control_buffer.as_mut_ptr() as *mut libc::c_void
+                } else {
+                    std::ptr::null::<libc::cmsghdr>() as *mut libc::c_void
+                };
+                let slice: &[u8] = os_sockaddr.as_ref();
+                libc::mmsghdr {
+                    msg_hdr: libc::msghdr {
+                        msg_name: slice.as_ptr() as *mut _,
+                        msg_namelen: os_sockaddr.len(),
+                        msg_iov: send_iov,
+                        msg_iovlen: 1,
+                        msg_control,
+                        msg_controllen: msg_control_size,
+                        msg_flags: 0,
+                    },
+                    msg_len: 0,
+                }
+            },
+        )
+        .collect::<Vec<_>>();
+
+    // Safety: All the pointers are valid because they are within the current scope.
+    // Also, this is just a wrapper over `libc` call.
     unsafe {
-        let result = libc::setsockopt(
-            *fd.get_ref(),
-            SOL_SCTP,
-            SCTP_DEFAULT_SNDINFO,
-            &sendinfo as *const _ as *const libc::c_void,
-            std::mem::size_of::<SendInfo>().try_into().unwrap(),
+        for (mmsg_header, (_, data)) in mmsg_headers.iter().zip(msgs.iter()) {
+            write_send_cmsgs(&mmsg_header.msg_hdr, data);
+        }
+
+        let flags = 0 as libc::c_int;
+
+        let result = libc::sendmmsg(
+            rawfd,
+            mmsg_headers.as_mut_ptr(),
+            mmsg_headers.len() as libc::c_uint,
+            flags,
         );
         if result < 0 {
             Err(std::io::Error::last_os_error())
         } else {
-            Ok(())
+            log::debug!("Sent {} Messages.", result);
+            Ok(result as usize)
+        }
+    }
+}
+
+// IPv6 destination addresses cannot be used on an IPv4 socket.
+fn check_dst_addrs(rawfd: RawFd, data: &SendData) -> std::io::Result<()> {
+    if !data.dst_addrs.iter().any(|addr| addr.is_ipv6()) {
+        return Ok(());
+    }
+
+    // Safety: `addr` and `addr_len` are in the scope and hence are valid pointers.
+    let family = unsafe {
+        let mut addr: libc::sockaddr_storage = std::mem::zeroed();
+        let mut addr_len = std::mem::size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+        let result = libc::getsockname(
+            rawfd,
+            &mut addr as *mut _ as *mut libc::sockaddr,
+            &mut addr_len as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        addr.ss_family as libc::c_int
+    };
+
+    if family == libc::AF_INET {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "IPv6 destination address on an IPv4 socket",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+// Space required in the control buffer for all the ancillary data requested in the `data`.
+fn send_cmsg_space(data: &SendData) -> usize {
+    let mut msg_control_size = 0_usize;
+    if data.snd_info.is_some() {
+        msg_control_size += cmsg_space::<SendInfo>();
+    }
+    if data.pr_info.is_some() {
+        msg_control_size += cmsg_space::<PrInfo>();
+    }
+    if data.auth_info.is_some() {
+        msg_control_size += cmsg_space::<AuthInfo>();
+    }
+    for addr in &data.dst_addrs {
+        msg_control_size += match addr {
+            SocketAddr::V4(_) => cmsg_space::<libc::in_addr>(),
+            SocketAddr::V6(_) => cmsg_space::<libc::in6_addr>(),
+        };
+    }
+    msg_control_size
+}
+
+// A zeroed control buffer of `size` bytes. We use `u64` for correct alignment of the `cmsghdr`.
+fn send_cmsg_buffer(size: usize) -> Vec<u64> {
+    vec![0_u64; size.div_ceil(8)]
+}
+
+// Writes all the ancillary data requested in the `data` to the control buffer of the `msghdr`.
+//
+// Safety: The control buffer of the `msghdr` should be at-least `send_cmsg_space(data)` bytes.
+unsafe fn write_send_cmsgs(msghdr: &libc::msghdr, data: &SendData) {
+    let mut cmsg_hdr = libc::CMSG_FIRSTHDR(msghdr);
+    if let Some(ref snd_info) = data.snd_info {
+        cmsg_hdr = write_cmsg(msghdr, cmsg_hdr, CmsgType::SndInfo, snd_info);
+    }
+    if let Some(ref pr_info) = data.pr_info {
+        cmsg_hdr = write_cmsg(msghdr, cmsg_hdr, CmsgType::PrInfo, pr_info);
+    }
+    if let Some(ref auth_info) = data.auth_info {
+        cmsg_hdr = write_cmsg(msghdr, cmsg_hdr, CmsgType::AuthInfo, auth_info);
+    }
+    for addr in &data.dst_addrs {
+        cmsg_hdr = match addr {
+            SocketAddr::V4(v4) => {
+                let in_addr = libc::in_addr {
+                    s_addr: u32::from_ne_bytes(v4.ip().octets()),
+                };
+                write_cmsg(msghdr, cmsg_hdr, CmsgType::DstAddrV4, &in_addr)
+            }
+            SocketAddr::V6(v6) => {
+                let in6_addr = libc::in6_addr {
+                    s6_addr: v6.ip().octets(),
+                };
+                write_cmsg(msghdr, cmsg_hdr, CmsgType::DstAddrV6, &in6_addr)
+            }
+        };
+    }
+    // All the ancillary data is written, there should be no space left in the buffer.
+    debug_assert!(cmsg_hdr.is_null());
+}
+
+// Space required in the control buffer for an ancillary data item of type `T`.
+fn cmsg_space<T>() -> usize {
+    // Safety: Just a wrapper over the `libc` macro.
+    unsafe { libc::CMSG_SPACE(std::mem::size_of::<T>() as u32) as usize }
+}
+
+// Writes an SCTP ancillary data item of type `cmsg_type` at `cmsg_hdr` and returns the next
+// control message header (which is null when there is no more space in the control buffer.)
+//
+// Safety: `cmsg_hdr` should be a valid header pointer obtained using `CMSG_FIRSTHDR` or
+// `CMSG_NXTHDR` on the `msghdr` and there should be space for `T` in the control buffer.
+unsafe fn write_cmsg<T>(
+    msghdr: &libc::msghdr,
+    cmsg_hdr: *mut libc::cmsghdr,
+    cmsg_type: CmsgType,
+    data: &T,
+) -> *mut libc::cmsghdr {
+    (*cmsg_hdr).cmsg_level = libc::IPPROTO_SCTP;
+    (*cmsg_hdr).cmsg_type = cmsg_type as i32;
+    (*cmsg_hdr).cmsg_len = libc::CMSG_LEN(std::mem::size_of::<T>().try_into().unwrap())
+        .try_into()
+        .unwrap();
+
+    std::ptr::copy_nonoverlapping(
+        data as *const T as *const u8,
+        libc::CMSG_DATA(cmsg_hdr),
+        std::mem::size_of::<T>(),
+    );
+
+    libc::CMSG_NXTHDR(msghdr, cmsg_hdr)
+}
+
+// Abort an association by sending `SCTP_ABORT` flag in the `SendInfo`. The `reason` is sent to
+// the peer as the User Initiated Abort cause.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub(crate) async fn sctp_abort_internal(
+    fd: &RegisteredFd,
+    assoc_id: AssociationId,
+    reason: &[u8],
+) -> std::io::Result<()> {
+    log::debug!("Aborting association: {}", assoc_id);
+
+    let data = SendData {
+        payload: reason.to_vec(),
+        snd_info: Some(SendInfo {
+            flags: SendFlags::ABORT,
+            assoc_id,
+            ..Default::default()
+        }),
+        pr_info: None,
+        auth_info: None,
+        dst_addrs: vec![],
+    };
+
+    sctp_sendmsg_internal(fd, None, data).await?;
+
+    Ok(())
+}
+
+pub(crate) fn sctp_set_default_sendinfo_internal(
+    fd: &impl AsRawFd,
+    sendinfo: SendInfo,
+) -> std::io::Result<()> {
+    unsafe {
+        let result = libc::setsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_DEFAULT_SNDINFO,
+            &sendinfo as *const _ as *const libc::c_void,
+            std::mem::size_of::<SendInfo>().try_into().unwrap(),
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+pub(crate) fn sctp_get_default_sendinfo_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+) -> std::io::Result<SendInfo> {
+    let mut sendinfo = SendInfo {
+        assoc_id,
+        ..Default::default()
+    };
+    let mut sendinfo_size: libc::socklen_t = std::mem::size_of::<SendInfo>() as libc::socklen_t;
+
+    // Safety: `sendinfo` and `sendinfo_size` are valid in the current scope.
+    unsafe {
+        let result = libc::getsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_DEFAULT_SNDINFO,
+            &mut sendinfo as *mut _ as *mut libc::c_void,
+            &mut sendinfo_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(sendinfo)
         }
     }
 }
 
 fn notification_from_message(data: &[u8]) -> Notification {
+    // Every notification starts with the common header (`type`, `flags` and `length`).
+    if data.len() < 8 {
+        log::warn!("Truncated Notification of length {} received.", data.len());
+        return unsupported_notification(data);
+    }
+
+    // The `length` of the notification is never trusted beyond the received data. Any bytes
+    // following the `length` are not a part of the notification.
+    let length = u32::from_ne_bytes(data[4..8].try_into().unwrap()) as usize;
+    if length < 8 || length > data.len() {
+        log::warn!(
+            "Notification length {} is invalid, {} bytes received.",
+            length,
+            data.len()
+        );
+        return unsupported_notification(data);
+    }
+    let data = &data[..length];
+
     let notification_type = u16::from_ne_bytes(data[0..2].try_into().unwrap());
     log::trace!(
         "notification_type: {:x}, SCTP_ASSOC_CHANGE: {:x}",
@@ -689,6 +1492,10 @@ fn notification_from_message(data: &[u8]) -> Notification {
     match notification_type {
         SCTP_ASSOC_CHANGE => {
             log::debug!("SCTP_ASSOC_CHANGE Notification Received.");
+            if data.len() < 20 {
+                log::warn!("Truncated SCTP_ASSOC_CHANGE Notification.");
+                return unsupported_notification(data);
+            }
             let assoc_change = AssociationChange {
                 ev_type: Event::from_u16(u16::from_ne_bytes(data[0..2].try_into().unwrap())),
                 flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
@@ -699,48 +1506,530 @@ fn notification_from_message(data: &[u8]) -> Notification {
                 error: u16::from_ne_bytes(data[10..12].try_into().unwrap()),
                 ob_streams: u16::from_ne_bytes(data[12..14].try_into().unwrap()),
                 ib_streams: u16::from_ne_bytes(data[14..16].try_into().unwrap()),
-                assoc_id: i32::from_ne_bytes(data[16..20].try_into().unwrap()),
+                assoc_id: i32::from_ne_bytes(data[16..20].try_into().unwrap()).into(),
                 info: data[20..].into(),
             };
             Notification::AssociationChange(assoc_change)
         }
-        SCTP_SHUTDOWN => {
-            log::debug!("SCTP_SHUTDOWN Notification Received.");
+        SCTP_PEER_ADDR_CHANGE => {
+            log::debug!("SCTP_PEER_ADDR_CHANGE Notification Received.");
+            // `struct sctp_paddr_change` is packed, the address (`sockaddr_storage`) follows the
+            // header.
+            const ADDR_END: usize = 8 + std::mem::size_of::<libc::sockaddr_storage>();
+            if data.len() < ADDR_END + 12 {
+                log::warn!("Truncated SCTP_PEER_ADDR_CHANGE Notification.");
+                return unsupported_notification(data);
+            }
+            let peer_addr_change = PeerAddressChange {
+                ev_type: Event::from_u16(u16::from_ne_bytes(data[0..2].try_into().unwrap())),
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: u32::from_ne_bytes(data[4..8].try_into().unwrap()),
+                address: socketaddr_from_bytes(&data[8..ADDR_END]).unwrap_or_else(|| {
+                    std::net::SocketAddr::from((std::net::Ipv4Addr::UNSPECIFIED, 0))
+                }),
+                state: PeerAddressState::from_u32(u32::from_ne_bytes(
+                    data[ADDR_END..ADDR_END + 4].try_into().unwrap(),
+                )),
+                error: i32::from_ne_bytes(data[ADDR_END + 4..ADDR_END + 8].try_into().unwrap()),
+                assoc_id: i32::from_ne_bytes(data[ADDR_END + 8..ADDR_END + 12].try_into().unwrap())
+                    .into(),
+            };
+            Notification::PeerAddressChange(peer_addr_change)
+        }
+        SCTP_SHUTDOWN_EVENT => {
+            log::debug!("SCTP_SHUTDOWN_EVENT Notification Received.");
+            if data.len() < 12 {
+                log::warn!("Truncated SCTP_SHUTDOWN_EVENT Notification.");
+                return unsupported_notification(data);
+            }
             let shutdown = Shutdown {
                 ev_type: Event::from_u16(u16::from_ne_bytes(data[0..2].try_into().unwrap())),
                 flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
                 length: u32::from_ne_bytes(data[4..8].try_into().unwrap()),
-                assoc_id: i32::from_ne_bytes(data[8..12].try_into().unwrap()),
+                assoc_id: i32::from_ne_bytes(data[8..12].try_into().unwrap()).into(),
             };
             Notification::Shutdown(shutdown)
         }
+        SCTP_SEND_FAILED_EVENT => {
+            log::debug!("SCTP_SEND_FAILED_EVENT Notification Received.");
+            if data.len() < 32 {
+                log::warn!("Truncated SCTP_SEND_FAILED_EVENT Notification.");
+                return unsupported_notification(data);
+            }
+            let send_failed = SendFailed {
+                ev_type: Event::from_u16(u16::from_ne_bytes(data[0..2].try_into().unwrap())),
+                flags: SendFailedFlags::from_u16(u16::from_ne_bytes(
+                    data[2..4].try_into().unwrap(),
+                )),
+                length: u32::from_ne_bytes(data[4..8].try_into().unwrap()),
+                error: u32::from_ne_bytes(data[8..12].try_into().unwrap()),
+                snd_info: SendInfo {
+                    sid: u16::from_ne_bytes(data[12..14].try_into().unwrap()),
+                    flags: SendFlags::from_u16(u16::from_ne_bytes(
+                        data[14..16].try_into().unwrap(),
+                    )),
+                    ppid: u32::from_ne_bytes(data[16..20].try_into().unwrap()),
+                    context: u32::from_ne_bytes(data[20..24].try_into().unwrap()),
+                    assoc_id: i32::from_ne_bytes(data[24..28].try_into().unwrap()).into(),
+                },
+                assoc_id: i32::from_ne_bytes(data[28..32].try_into().unwrap()).into(),
+                data: data[32..].into(),
+            };
+            Notification::SendFailed(send_failed)
+        }
+        SCTP_SENDER_DRY_EVENT => {
+            log::debug!("SCTP_SENDER_DRY_EVENT Notification Received.");
+            if data.len() < 12 {
+                log::warn!("Truncated SCTP_SENDER_DRY_EVENT Notification.");
+                return unsupported_notification(data);
+            }
+            let sender_dry = SenderDry {
+                ev_type: Event::from_u16(u16::from_ne_bytes(data[0..2].try_into().unwrap())),
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: u32::from_ne_bytes(data[4..8].try_into().unwrap()),
+                assoc_id: i32::from_ne_bytes(data[8..12].try_into().unwrap()).into(),
+            };
+            Notification::SenderDry(sender_dry)
+        }
+        SCTP_STREAM_RESET_EVENT => {
+            log::debug!("SCTP_STREAM_RESET_EVENT Notification Received.");
+            // `struct sctp_stream_reset_event` has a header followed by a variable length array
+            // of stream IDs, filling the rest of the notification.
+            const HEADER_END: usize = 12;
+            if data.len() < HEADER_END {
+                log::warn!("Truncated SCTP_STREAM_RESET_EVENT Notification.");
+                return unsupported_notification(data);
+            }
+            let stream_reset = StreamResetEvent {
+                ev_type: Event::from_u16(u16::from_ne_bytes(data[0..2].try_into().unwrap())),
+                flags: StreamResetFlags::from_u16(u16::from_ne_bytes(
+                    data[2..4].try_into().unwrap(),
+                )),
+                length: u32::from_ne_bytes(data[4..8].try_into().unwrap()),
+                assoc_id: i32::from_ne_bytes(data[8..12].try_into().unwrap()).into(),
+                stream_ids: data[HEADER_END..]
+                    .chunks_exact(2)
+                    .map(|sid| u16::from_ne_bytes(sid.try_into().unwrap()))
+                    .collect(),
+            };
+            Notification::StreamReset(stream_reset)
+        }
         _ => {
-            log::debug!("Unsupported notification received.");
-            Notification::Unsupported
+            log::debug!(
+                "Unsupported notification {:#x} received.",
+                notification_type
+            );
+            unsupported_notification(data)
         }
     }
 }
 
+// Returns the notification as `Notification::Unsupported`, retaining the header fields that are
+// available in the `data` and the raw bytes.
+fn unsupported_notification(data: &[u8]) -> Notification {
+    Notification::Unsupported {
+        ev_type: data
+            .get(0..2)
+            .map_or(0, |b| u16::from_ne_bytes(b.try_into().unwrap())),
+        flags: data
+            .get(2..4)
+            .map_or(0, |b| u16::from_ne_bytes(b.try_into().unwrap())),
+        length: data
+            .get(4..8)
+            .map_or(0, |b| u32::from_ne_bytes(b.try_into().unwrap())),
+        data: data.into(),
+    }
+}
+
+// Decodes a socket address from the bytes of a `sockaddr_storage` (which may not be aligned.)
+fn socketaddr_from_bytes(data: &[u8]) -> Option<SocketAddr> {
+    // Safety: An all zeroes `sockaddr_storage` is valid and we copy at-most the size of the
+    // `sockaddr_storage` into it.
+    unsafe {
+        let mut storage: libc::sockaddr_storage = std::mem::zeroed();
+        let len = data
+            .len()
+            .min(std::mem::size_of::<libc::sockaddr_storage>());
+        std::ptr::copy_nonoverlapping(data.as_ptr(), &mut storage as *mut _ as *mut u8, len);
+
+        let addr_len = match storage.ss_family as libc::c_int {
+            libc::AF_INET => std::mem::size_of::<libc::sockaddr_in>(),
+            libc::AF_INET6 => std::mem::size_of::<libc::sockaddr_in6>(),
+            _ => return None,
+        };
+        OsSocketAddr::copy_from_raw(
+            &storage as *const _ as *const libc::sockaddr,
+            addr_len.try_into().unwrap(),
+        )
+        .into_addr()
+    }
+}
+
+// Set when the kernel does not support the `SCTP_EVENT` socket option. Support for the socket
+// option is a property of the running kernel (not of a socket), so it is detected once, on the
+// first subscription that fails with `ENOPROTOOPT`, and all the sockets in the process use the
+// legacy `SCTP_EVENTS` option after that, without trying `SCTP_EVENT` again every time.
+static USE_LEGACY_EVENTS: AtomicBool = AtomicBool::new(false);
+
 // Implementation of Event Subscription
 pub(crate) fn sctp_subscribe_event_internal(
-    fd: &AsyncFd<RawFd>,
+    fd: &impl AsRawFd,
     event: Event,
     assoc_id: SubscribeEventAssocId,
     on: bool,
 ) -> std::io::Result<()> {
+    if USE_LEGACY_EVENTS.load(Ordering::Relaxed) {
+        return sctp_subscribe_event_legacy_internal(fd, event, assoc_id, on);
+    }
+
     let subscriber = SubscribeEvent {
-        event,
+        event: event.clone(),
         assoc_id: assoc_id.into(),
         on,
     };
 
-    unsafe {
-        let result = libc::setsockopt(
-            *fd.get_ref(),
+    let result = unsafe {
+        libc::setsockopt(
+            fd.as_raw_fd(),
             SOL_SCTP,
             SCTP_EVENT,
             &subscriber as *const _ as *const libc::c_void,
             std::mem::size_of::<SubscribeEvent>().try_into().unwrap(),
+        )
+    };
+    if result < 0 {
+        let e = std::io::Error::last_os_error();
+        if e.raw_os_error() == Some(libc::ENOPROTOOPT) {
+            log::debug!("`SCTP_EVENT` not supported, falling back to `SCTP_EVENTS`.");
+            USE_LEGACY_EVENTS.store(true, Ordering::Relaxed);
+            sctp_subscribe_event_legacy_internal(fd, event, assoc_id, on)
+        } else {
+            Err(e)
+        }
+    } else {
+        Ok(())
+    }
+}
+
+// Get whether the `event` is subscribed to (using `SCTP_EVENT`).
+pub(crate) fn sctp_get_event_subscribed_internal(
+    fd: &impl AsRawFd,
+    event: Event,
+    assoc_id: SubscribeEventAssocId,
+) -> std::io::Result<bool> {
+    let mut subscriber = SubscribeEvent {
+        event,
+        assoc_id: assoc_id.into(),
+        on: false,
+    };
+    let mut subscriber_size = std::mem::size_of::<SubscribeEvent>() as libc::socklen_t;
+
+    unsafe {
+        let result = libc::getsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_EVENT,
+            &mut subscriber as *mut _ as *mut libc::c_void,
+            &mut subscriber_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(subscriber.on)
+        }
+    }
+}
+
+// Waits for the `SCTP_SENDER_DRY_EVENT` on the socket. Anything else received while waiting is
+// added to the `pending` queue, so that it can be returned by the subsequent receive calls.
+//
+// Subscribing to the event, generates the event right away if there is no outstanding data,
+// hence the event is subscribed to, even when it is already subscribed. If it was not subscribed
+// to before, it is unsubscribed once the event is received.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub(crate) async fn sctp_wait_sender_dry_internal(
+    fd: &RegisteredFd,
+    pending: &std::sync::Mutex<std::collections::VecDeque<NotificationOrData>>,
+    capacity: usize,
+) -> std::io::Result<()> {
+    let subscribed =
+        sctp_get_event_subscribed_internal(fd, Event::SenderDry, SubscribeEventAssocId::Future)
+            .unwrap_or(true);
+
+    sctp_subscribe_event_internal(fd, Event::SenderDry, SubscribeEventAssocId::All, true)?;
+
+    loop {
+        match sctp_recvmsg_internal(fd, capacity).await? {
+            NotificationOrData::Notification(Notification::SenderDry(_)) => break,
+            NotificationOrData::Eof => {
+                pending.lock().unwrap().push_back(NotificationOrData::Eof);
+                return Err(std::io::Error::from_raw_os_error(libc::ENOTCONN));
+            }
+            received => {
+                log::debug!(
+                    "Queuing {:?} received while waiting for sender dry.",
+                    received
+                );
+                pending.lock().unwrap().push_back(received);
+            }
+        }
+    }
+
+    if !subscribed {
+        sctp_subscribe_event_internal(fd, Event::SenderDry, SubscribeEventAssocId::All, false)?;
+    }
+
+    Ok(())
+}
+
+// Wait for a new association on a One to Many socket and peel it off.
+//
+// The association events are subscribed to (and left subscribed, so that no `CommUp` is missed
+// between the calls). Messages received while waiting that belong to the new association are
+// moved to the peeled off socket, all the other messages are queued in `pending`.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+pub(crate) async fn sctp_accept_assoc_internal(
+    fd: &RegisteredFd,
+    pending: &std::sync::Mutex<std::collections::VecDeque<NotificationOrData>>,
+    capacity: usize,
+) -> std::io::Result<(ConnectedSocket, AssociationId, SocketAddr)> {
+    sctp_subscribe_event_internal(fd, Event::Association, SubscribeEventAssocId::All, true)?;
+
+    let assoc_id = loop {
+        match sctp_recvmsg_internal(fd, capacity).await? {
+            NotificationOrData::Notification(Notification::AssociationChange(
+                AssociationChange {
+                    state: AssocChangeState::CommUp,
+                    assoc_id,
+                    ..
+                },
+            )) => break assoc_id,
+            received => {
+                log::debug!(
+                    "Queuing {:?} received while waiting for an association.",
+                    received
+                );
+                pending.lock().unwrap().push_back(received);
+            }
+        }
+    };
+
+    let connected = sctp_peeloff_internal(fd, assoc_id)?;
+
+    {
+        let mut pending = pending.lock().unwrap();
+        let (ours, others) = pending
+            .drain(..)
+            .partition(|received| received_assoc_id(received) == Some(assoc_id));
+        *pending = others;
+        connected.queue_pending(ours);
+    }
+
+    let address = connected
+        .sctp_getpaddrs(0.into())?
+        .into_iter()
+        .next()
+        .ok_or_else(|| std::io::Error::from_raw_os_error(libc::ENOTCONN))?;
+
+    Ok((connected, assoc_id, address))
+}
+
+// Returns the Association ID of the received message, if it is known. For the Data, it is known
+// only when receiving `RcvInfo` is requested.
+fn received_assoc_id(received: &NotificationOrData) -> Option<AssociationId> {
+    match received {
+        NotificationOrData::Notification(notification) => match notification {
+            Notification::AssociationChange(n) => Some(n.assoc_id),
+            Notification::PeerAddressChange(n) => Some(n.assoc_id),
+            Notification::Shutdown(n) => Some(n.assoc_id),
+            Notification::SendFailed(n) => Some(n.assoc_id),
+            Notification::SenderDry(n) => Some(n.assoc_id),
+            Notification::StreamReset(n) => Some(n.assoc_id),
+            Notification::Unsupported { .. } => None,
+        },
+        NotificationOrData::Data(data) => data.rcv_info.as_ref().map(|info| info.assoc_id),
+        NotificationOrData::Eof => None,
+    }
+}
+
+// Converts a queued message to the `ReceivedInfo` returned by the `sctp_recv_into` calls, copying
+// the payload (if any) to the `buf`.
+pub(crate) fn pending_into_received_info(
+    received: NotificationOrData,
+    buf: &mut Vec<u8>,
+) -> ReceivedInfo {
+    match received {
+        NotificationOrData::Notification(notification) => ReceivedInfo::Notification(notification),
+        NotificationOrData::Data(ReceivedData {
+            payload,
+            rcv_info,
+            nxt_info,
+            flags,
+            from,
+        }) => {
+            buf.clear();
+            buf.extend_from_slice(&payload);
+            ReceivedInfo::Data {
+                rcv_info,
+                nxt_info,
+                flags,
+                from,
+            }
+        }
+        NotificationOrData::Eof => ReceivedInfo::Eof,
+    }
+}
+
+// Subscribe (or unsubscribe) to each of the `events`, returning the result for every event.
+// Failure to subscribe to an event does not stop the subscription for the remaining events.
+pub(crate) fn sctp_subscribe_events_internal(
+    fd: &impl AsRawFd,
+    events: &[Event],
+    assoc_id: SubscribeEventAssocId,
+    on: bool,
+) -> Vec<(Event, std::io::Result<()>)> {
+    events
+        .iter()
+        .map(|ev| {
+            (
+                ev.clone(),
+                sctp_subscribe_event_internal(fd, ev.clone(), assoc_id, on),
+            )
+        })
+        .collect()
+}
+
+// Convert the per event results of `sctp_subscribe_events_internal` to a single result. The
+// error lists the events that failed along with the error for each of them.
+pub(crate) fn subscribe_events_result(
+    results: Vec<(Event, std::io::Result<()>)>,
+) -> std::io::Result<()> {
+    let failures = results
+        .into_iter()
+        .filter_map(|(ev, result)| result.err().map(|e| (ev, e)))
+        .collect::<Vec<_>>();
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(SctpError::EventSubscription(failures).into())
+    }
+}
+
+// Subscribe to the events in the `mask` and unsubscribe from the rest using the legacy
+// `SCTP_EVENTS` socket option. Falls back to `SCTP_EVENT` for each of the events, if the option
+// is not supported (`ENOPROTOOPT`) or the kernel's `struct sctp_event_subscribe` is smaller than
+// ours, that is, the kernel does not support some of the events (`EINVAL`).
+pub(crate) fn sctp_subscribe_all_events_internal(
+    fd: &impl AsRawFd,
+    mask: EventMask,
+) -> std::io::Result<()> {
+    log::debug!("Subscribing to the events: {:?}.", mask);
+
+    let subscribe = EventSubscribe::from(mask);
+
+    // Safety: `subscribe` is valid in the current scope.
+    let result = unsafe {
+        libc::setsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_EVENTS,
+            &subscribe as *const _ as *const libc::c_void,
+            std::mem::size_of::<EventSubscribe>() as libc::socklen_t,
+        )
+    };
+    if result == 0 {
+        return Ok(());
+    }
+
+    let e = std::io::Error::last_os_error();
+    if !matches!(
+        e.raw_os_error(),
+        Some(libc::ENOPROTOOPT) | Some(libc::EINVAL)
+    ) {
+        return Err(e);
+    }
+
+    log::debug!("`SCTP_EVENTS` failed: {}, falling back to `SCTP_EVENT`.", e);
+    let (on, off): (Vec<Event>, Vec<Event>) = Event::all()
+        .into_iter()
+        .partition(|event| mask.contains(event));
+    let mut results = sctp_subscribe_events_internal(fd, &on, SubscribeEventAssocId::All, true);
+    results.extend(sctp_subscribe_events_internal(
+        fd,
+        &off,
+        SubscribeEventAssocId::All,
+        false,
+    ));
+    subscribe_events_result(results)
+}
+
+// Event Subscription using the legacy `SCTP_EVENTS` socket option. The currently subscribed
+// events are read from the kernel and only the flag for the given `event` is updated, so that
+// subscribing (or unsubscribing) to an event does not affect other events. Note: The legacy
+// option applies to the whole socket and has no association ID. Rather than silently applying
+// a subscription for a particular association to all of them, only the `assoc_id`s that mean all
+// the (future) associations are accepted.
+fn sctp_subscribe_event_legacy_internal(
+    fd: &impl AsRawFd,
+    event: Event,
+    assoc_id: SubscribeEventAssocId,
+    on: bool,
+) -> std::io::Result<()> {
+    if !matches!(
+        assoc_id,
+        SubscribeEventAssocId::Future | SubscribeEventAssocId::All
+    ) {
+        log::debug!(
+            "`SCTP_EVENTS` does not support subscribing for association: {:?}.",
+            assoc_id
+        );
+        return Err(SctpError::NotSupported.into());
+    }
+
+    let mut subscribe = EventSubscribe::default();
+    let mut subscribe_size: libc::socklen_t =
+        std::mem::size_of::<EventSubscribe>() as libc::socklen_t;
+
+    // Safety: `subscribe` and `subscribe_size` are valid in the current scope.
+    unsafe {
+        let result = libc::getsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_EVENTS,
+            &mut subscribe as *mut _ as *mut libc::c_void,
+            &mut subscribe_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+    }
+
+    // The kernel returns the size of its `struct sctp_event_subscribe`, a flag beyond that would
+    // not be set by the `setsockopt` below, though it would succeed.
+    if !EventSubscribe::has_flag(&event, subscribe_size as usize) {
+        log::debug!("Event: {:?} is not supported by `SCTP_EVENTS`.", event);
+        return Err(SctpError::NotSupported.into());
+    }
+
+    match subscribe.flag_mut(&event) {
+        Some(flag) => *flag = u8::from(on),
+        None => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Event: {:?} cannot be subscribed.", event),
+            ))
+        }
+    }
+
+    // Safety: `subscribe` is valid in the current scope.
+    unsafe {
+        let result = libc::setsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_EVENTS,
+            &subscribe as *const _ as *const libc::c_void,
+            subscribe_size,
         );
         if result < 0 {
             Err(std::io::Error::last_os_error())
@@ -752,27 +2041,18 @@ pub(crate) fn sctp_subscribe_event_internal(
 
 // Setup initiation parameters
 pub(crate) fn sctp_setup_init_params_internal(
-    fd: &AsyncFd<RawFd>,
-    ostreams: u16,
-    istreams: u16,
-    retries: u16,
-    timeout: u16,
+    fd: &impl AsRawFd,
+    init_params: InitParams,
 ) -> std::io::Result<()> {
     log::debug!("Setting up `init_params` using `setsockopt`");
-    let init_params = InitMsg {
-        ostreams,
-        istreams,
-        retries,
-        timeout,
-    };
 
     unsafe {
         let result = libc::setsockopt(
-            *fd.get_ref(),
+            fd.as_raw_fd(),
             SOL_SCTP,
             SCTP_INITMSG,
             &init_params as *const _ as *const libc::c_void,
-            std::mem::size_of::<InitMsg>().try_into().unwrap(),
+            std::mem::size_of::<InitParams>().try_into().unwrap(),
         );
         if result < 0 {
             Err(std::io::Error::last_os_error())
@@ -782,8 +2062,32 @@ pub(crate) fn sctp_setup_init_params_internal(
     }
 }
 
+pub(crate) fn sctp_get_init_params_internal(fd: &impl AsRawFd) -> std::io::Result<InitParams> {
+    log::debug!("Getting `init_params` using `getsockopt`");
+
+    let mut init_params = InitParams::default();
+    let mut init_params_size: libc::socklen_t =
+        std::mem::size_of::<InitParams>() as libc::socklen_t;
+
+    // Safety: `init_params` and `init_params_size` are valid in the current scope.
+    unsafe {
+        let result = libc::getsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_INITMSG,
+            &mut init_params as *mut _ as *mut libc::c_void,
+            &mut init_params_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(init_params)
+        }
+    }
+}
+
 // Enable/Disable reception of `RcvInfo` actual call.
-pub(crate) fn request_rcvinfo_internal(fd: &AsyncFd<RawFd>, on: bool) -> std::io::Result<()> {
+pub(crate) fn request_rcvinfo_internal(fd: &impl AsRawFd, on: bool) -> std::io::Result<()> {
     log::debug!("Requesting `rcv_info` along with received data on the socket.");
 
     let enable: libc::socklen_t = u32::from(on);
@@ -791,7 +2095,7 @@ pub(crate) fn request_rcvinfo_internal(fd: &AsyncFd<RawFd>, on: bool) -> std::io
 
     unsafe {
         let result = libc::setsockopt(
-            *fd.get_ref(),
+            fd.as_raw_fd(),
             SOL_SCTP,
             SCTP_RECVRCVINFO,
             &enable as *const _ as *const libc::c_void,
@@ -807,7 +2111,7 @@ pub(crate) fn request_rcvinfo_internal(fd: &AsyncFd<RawFd>, on: bool) -> std::io
 }
 
 // Enable/Disable reception of `NxtInfo` actual call.
-pub(crate) fn request_nxtinfo_internal(fd: &AsyncFd<RawFd>, on: bool) -> std::io::Result<()> {
+pub(crate) fn request_nxtinfo_internal(fd: &impl AsRawFd, on: bool) -> std::io::Result<()> {
     log::debug!("Requesting `nxt_info` along with received data on the socket.");
 
     let enable: libc::socklen_t = u32::from(on);
@@ -815,7 +2119,7 @@ pub(crate) fn request_nxtinfo_internal(fd: &AsyncFd<RawFd>, on: bool) -> std::io
 
     unsafe {
         let result = libc::setsockopt(
-            *fd.get_ref(),
+            fd.as_raw_fd(),
             SOL_SCTP,
             SCTP_RECVNXTINFO,
             &enable as *const _ as *const libc::c_void,
@@ -830,36 +2134,1004 @@ pub(crate) fn request_nxtinfo_internal(fd: &AsyncFd<RawFd>, on: bool) -> std::io
     }
 }
 
+// Get whether the `RcvInfo` is requested with the received data (`SCTP_RECVRCVINFO`).
+pub(crate) fn rcvinfo_requested_internal(fd: &impl AsRawFd) -> std::io::Result<bool> {
+    Ok(getsockopt_int_internal(fd, SOL_SCTP, SCTP_RECVRCVINFO)? != 0)
+}
+
+// Get whether the `NxtInfo` is requested with the received data (`SCTP_RECVNXTINFO`).
+pub(crate) fn nxtinfo_requested_internal(fd: &impl AsRawFd) -> std::io::Result<bool> {
+    Ok(getsockopt_int_internal(fd, SOL_SCTP, SCTP_RECVNXTINFO)? != 0)
+}
+
 // Get the status for the given Assoc ID
 pub(crate) fn sctp_get_status_internal(
-    fd: &AsyncFd<RawFd>,
+    fd: &impl AsRawFd,
     assoc_id: AssociationId,
 ) -> std::io::Result<ConnStatus> {
     log::debug!("Calling `sctp_get_status_internal`.");
 
-    let status_ptr = std::mem::MaybeUninit::<ConnStatusInternal>::zeroed();
-    let mut status_size = std::mem::size_of::<ConnStatusInternal>();
+    sctp_get_status_syscall(fd, assoc_id).map(Into::into)
+}
 
-    unsafe {
-        let mut sctp_status = status_ptr.assume_init();
+// Returns the number of the inbound and the outbound streams of the association from the
+// `SCTP_STATUS`, without converting the rest of the status.
+pub(crate) fn sctp_get_stream_counts_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+) -> std::io::Result<(u16, u16)> {
+    let status = sctp_get_status_syscall(fd, assoc_id)?;
+
+    Ok((status.instreams, status.outstreams))
+}
+
+// Actual `getsockopt` call for the `SCTP_STATUS`.
+fn sctp_get_status_syscall(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+) -> std::io::Result<ConnStatusInternal> {
+    let status_ptr = std::mem::MaybeUninit::<ConnStatusInternal>::zeroed();
+    let mut status_size = std::mem::size_of::<ConnStatusInternal>();
+
+    unsafe {
+        let mut sctp_status = status_ptr.assume_init();
         sctp_status.assoc_id = assoc_id;
 
         let result = libc::getsockopt(
-            *fd.get_ref(),
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_STATUS,
+            &mut sctp_status as *mut _ as *mut libc::c_void,
+            &mut status_size as *mut _ as *mut libc::socklen_t,
+        );
+
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(sctp_status)
+        }
+    }
+}
+
+pub(crate) fn sctp_get_peer_addr_info_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+    addr: SocketAddr,
+) -> std::io::Result<PeerAddress> {
+    log::debug!("Getting Peer Address Info for: {}", addr);
+
+    let os_sockaddr: OsSocketAddr = addr.into();
+    let slice: &[u8] = os_sockaddr.as_ref();
+
+    // Safety: An all zeroes `PeerAddrInternal` is valid. The `address` is large enough for any
+    // socket address and `paddr_info` is valid in the current scope.
+    unsafe {
+        let mut paddr_info = std::mem::MaybeUninit::<PeerAddrInternal>::zeroed().assume_init();
+        paddr_info.assoc_id = assoc_id;
+        let mut address: libc::sockaddr_storage = std::mem::zeroed();
+        std::ptr::copy_nonoverlapping(
+            slice.as_ptr(),
+            &mut address as *mut _ as *mut u8,
+            slice.len(),
+        );
+        paddr_info.address = address;
+
+        let mut paddr_info_size = std::mem::size_of::<PeerAddrInternal>() as libc::socklen_t;
+
+        let result = libc::getsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_GET_PEER_ADDR_INFO,
+            &mut paddr_info as *mut _ as *mut libc::c_void,
+            &mut paddr_info_size as *mut _ as *mut libc::socklen_t,
+        );
+
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            paddr_info.try_into()
+        }
+    }
+}
+
+// Set the value of a socket option from the raw bytes of the `value`.
+pub(crate) fn setsockopt_raw_internal(
+    fd: &impl AsRawFd,
+    level: libc::c_int,
+    optname: libc::c_int,
+    value: &[u8],
+) -> std::io::Result<()> {
+    // Safety: The kernel reads at-most `value.len()` bytes from the `value`.
+    unsafe {
+        let result = libc::setsockopt(
+            fd.as_raw_fd(),
+            level,
+            optname,
+            value.as_ptr() as *const libc::c_void,
+            value.len().try_into().unwrap(),
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Get the value of a socket option as raw bytes in the `buf`. Returns the number of bytes written
+// to the `buf` by the kernel.
+pub(crate) fn getsockopt_raw_internal(
+    fd: &impl AsRawFd,
+    level: libc::c_int,
+    optname: libc::c_int,
+    buf: &mut [u8],
+) -> std::io::Result<usize> {
+    let mut buf_size: libc::socklen_t = buf.len().try_into().unwrap();
+
+    // Safety: The kernel writes at-most `buf.len()` bytes to the `buf`.
+    unsafe {
+        let result = libc::getsockopt(
+            fd.as_raw_fd(),
+            level,
+            optname,
+            buf.as_mut_ptr() as *mut libc::c_void,
+            &mut buf_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(buf_size as usize)
+        }
+    }
+}
+
+// Set the value of a socket option that uses an `int` as the value.
+pub(crate) fn setsockopt_int_internal(
+    fd: &impl AsRawFd,
+    level: libc::c_int,
+    optname: libc::c_int,
+    value: libc::c_int,
+) -> std::io::Result<()> {
+    setsockopt_raw_internal(fd, level, optname, &value.to_ne_bytes())
+}
+
+// Returns the `SocketToAssociation` style of the socket, `None` if it cannot be determined.
+pub(crate) fn socket_style_internal(fd: &impl AsRawFd) -> Option<SocketToAssociation> {
+    match getsockopt_int_internal(fd, libc::SOL_SOCKET, libc::SO_TYPE).ok()? {
+        libc::SOCK_STREAM => Some(SocketToAssociation::OneToOne),
+        libc::SOCK_SEQPACKET => Some(SocketToAssociation::OneToMany),
+        _ => None,
+    }
+}
+
+// Common `Debug` implementation for the socket types: Only the raw fd and the style are printed.
+pub(crate) fn fmt_socket_internal(
+    fd: &impl AsRawFd,
+    name: &str,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    f.debug_struct(name)
+        .field("fd", &fd.as_raw_fd())
+        .field("style", &socket_style_internal(fd))
+        .finish()
+}
+
+// Get the value of a socket option that uses an `int` as the value.
+pub(crate) fn getsockopt_int_internal(
+    fd: &impl AsRawFd,
+    level: libc::c_int,
+    optname: libc::c_int,
+) -> std::io::Result<libc::c_int> {
+    let mut value = [0_u8; std::mem::size_of::<libc::c_int>()];
+    getsockopt_raw_internal(fd, level, optname, &mut value)?;
+    Ok(libc::c_int::from_ne_bytes(value))
+}
+
+// Enable/Disable IPv4 mapped IPv6 addresses for the IPv4 peers on an `AF_INET6` socket.
+pub(crate) fn sctp_set_mapped_v4_addr_internal(fd: &impl AsRawFd, on: bool) -> std::io::Result<()> {
+    log::debug!("Setting `mapped_v4_addr` to {} on the socket.", on);
+    setsockopt_int_internal(
+        fd,
+        SOL_SCTP,
+        SCTP_I_WANT_MAPPED_V4_ADDR,
+        libc::c_int::from(on),
+    )
+}
+
+// Get whether IPv4 mapped IPv6 addresses are used for the IPv4 peers.
+pub(crate) fn sctp_get_mapped_v4_addr_internal(fd: &impl AsRawFd) -> std::io::Result<bool> {
+    Ok(getsockopt_int_internal(fd, SOL_SCTP, SCTP_I_WANT_MAPPED_V4_ADDR)? != 0)
+}
+
+// Set the `SO_LINGER` option for the socket.
+pub(crate) fn set_linger_internal(
+    fd: &impl AsRawFd,
+    linger: Option<std::time::Duration>,
+) -> std::io::Result<()> {
+    log::debug!("Setting `SO_LINGER` to {:?} on the socket.", linger);
+
+    // Round up sub-second durations, so that a non-zero duration never becomes an abortive close.
+    let linger = match linger {
+        Some(duration) => libc::linger {
+            l_onoff: 1,
+            l_linger: (duration.as_secs() + u64::from(duration.subsec_nanos() > 0))
+                .try_into()
+                .unwrap_or(libc::c_int::MAX),
+        },
+        None => libc::linger {
+            l_onoff: 0,
+            l_linger: 0,
+        },
+    };
+
+    unsafe {
+        let result = libc::setsockopt(
+            fd.as_raw_fd(),
+            libc::SOL_SOCKET,
+            libc::SO_LINGER,
+            &linger as *const _ as *const libc::c_void,
+            std::mem::size_of::<libc::linger>().try_into().unwrap(),
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Get the `SO_LINGER` option for the socket.
+pub(crate) fn get_linger_internal(
+    fd: &impl AsRawFd,
+) -> std::io::Result<Option<std::time::Duration>> {
+    let mut linger = libc::linger {
+        l_onoff: 0,
+        l_linger: 0,
+    };
+    let mut linger_size = std::mem::size_of::<libc::linger>() as libc::socklen_t;
+
+    unsafe {
+        let result = libc::getsockopt(
+            fd.as_raw_fd(),
+            libc::SOL_SOCKET,
+            libc::SO_LINGER,
+            &mut linger as *mut _ as *mut libc::c_void,
+            &mut linger_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+    }
+
+    if linger.l_onoff == 0 {
+        Ok(None)
+    } else {
+        Ok(Some(std::time::Duration::from_secs(
+            linger.l_linger.try_into().unwrap_or_default(),
+        )))
+    }
+}
+
+// Get the number of associations on a One to Many socket.
+pub(crate) fn sctp_get_assoc_number_internal(fd: &impl AsRawFd) -> std::io::Result<u32> {
+    Ok(getsockopt_int_internal(fd, SOL_SCTP, SCTP_GET_ASSOC_NUMBER)? as u32)
+}
+
+// Get the Association IDs of all the associations on a One to Many socket.
+//
+// The number of associations is obtained first and then the buffer large enough for those many
+// Association IDs is used (`struct sctp_assoc_ids`). If more associations are set up in between,
+// the kernel returns `EINVAL` and we try again.
+pub(crate) fn sctp_get_assoc_ids_internal(
+    fd: &impl AsRawFd,
+) -> std::io::Result<Vec<AssociationId>> {
+    loop {
+        let count = sctp_get_assoc_number_internal(fd)? as usize;
+
+        // First element is the number of IDs followed by the IDs.
+        let mut assoc_ids = vec![0_i32; count + 1];
+        let mut assoc_ids_size = (std::mem::size_of::<i32>() * assoc_ids.len()) as libc::socklen_t;
+
+        unsafe {
+            let result = libc::getsockopt(
+                fd.as_raw_fd(),
+                SOL_SCTP,
+                SCTP_GET_ASSOC_ID_LIST,
+                assoc_ids.as_mut_ptr() as *mut libc::c_void,
+                &mut assoc_ids_size as *mut _ as *mut libc::socklen_t,
+            );
+            if result < 0 {
+                let e = std::io::Error::last_os_error();
+                if e.raw_os_error() == Some(libc::EINVAL) {
+                    log::debug!("Associations changed while getting the IDs, retrying.");
+                    continue;
+                }
+                return Err(e);
+            }
+        }
+
+        let number_of_ids = (assoc_ids[0] as usize).min(count);
+        return Ok(assoc_ids[1..=number_of_ids]
+            .iter()
+            .map(|&id| AssociationId::from(id))
+            .collect());
+    }
+}
+
+// Bind the socket to a network device (`SO_BINDTODEVICE`). `None` removes the binding.
+pub(crate) fn bind_device_internal(fd: &impl AsRawFd, device: Option<&str>) -> std::io::Result<()> {
+    log::debug!("Binding the socket to device: {:?}", device);
+    let device = device.unwrap_or_default();
+
+    unsafe {
+        let result = libc::setsockopt(
+            fd.as_raw_fd(),
+            libc::SOL_SOCKET,
+            libc::SO_BINDTODEVICE,
+            device.as_ptr() as *const libc::c_void,
+            device.len().try_into().unwrap(),
+        );
+        if result < 0 {
+            let e = std::io::Error::last_os_error();
+            if e.raw_os_error() == Some(libc::EPERM) {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::PermissionDenied,
+                    "SO_BINDTODEVICE requires the CAP_NET_RAW capability",
+                ))
+            } else {
+                Err(e)
+            }
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Get the network device the socket is bound to (`SO_BINDTODEVICE`).
+pub(crate) fn get_device_internal(fd: &impl AsRawFd) -> std::io::Result<Option<String>> {
+    let mut device = [0_u8; libc::IFNAMSIZ];
+    let mut device_size = device.len() as libc::socklen_t;
+
+    unsafe {
+        let result = libc::getsockopt(
+            fd.as_raw_fd(),
+            libc::SOL_SOCKET,
+            libc::SO_BINDTODEVICE,
+            device.as_mut_ptr() as *mut libc::c_void,
+            &mut device_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+    }
+
+    // The returned name may include the trailing NULs.
+    let device = &device[..device_size as usize];
+    let end = device.iter().position(|b| *b == 0).unwrap_or(device.len());
+    if end == 0 {
+        Ok(None)
+    } else {
+        Ok(Some(String::from_utf8_lossy(&device[..end]).into_owned()))
+    }
+}
+
+// Set the IPv4 Type of Service (`IP_TOS`) for the socket.
+pub(crate) fn set_tos_internal(fd: &impl AsRawFd, tos: u8) -> std::io::Result<()> {
+    log::debug!("Setting `IP_TOS` to {:#x} on the socket.", tos);
+    ensure_socket_domain(fd, libc::AF_INET, "IP_TOS", "set_traffic_class")?;
+    setsockopt_int_internal(fd, libc::IPPROTO_IP, libc::IP_TOS, libc::c_int::from(tos))
+}
+
+// Get the IPv4 Type of Service (`IP_TOS`) for the socket.
+pub(crate) fn get_tos_internal(fd: &impl AsRawFd) -> std::io::Result<u8> {
+    ensure_socket_domain(fd, libc::AF_INET, "IP_TOS", "traffic_class")?;
+    Ok(getsockopt_int_internal(fd, libc::IPPROTO_IP, libc::IP_TOS)? as u8)
+}
+
+// Set the IPv6 Traffic Class (`IPV6_TCLASS`) for the socket.
+pub(crate) fn set_traffic_class_internal(fd: &impl AsRawFd, tc: u8) -> std::io::Result<()> {
+    log::debug!("Setting `IPV6_TCLASS` to {:#x} on the socket.", tc);
+    ensure_socket_domain(fd, libc::AF_INET6, "IPV6_TCLASS", "set_tos")?;
+    setsockopt_int_internal(
+        fd,
+        libc::IPPROTO_IPV6,
+        libc::IPV6_TCLASS,
+        libc::c_int::from(tc),
+    )
+}
+
+// Get the IPv6 Traffic Class (`IPV6_TCLASS`) for the socket.
+pub(crate) fn get_traffic_class_internal(fd: &impl AsRawFd) -> std::io::Result<u8> {
+    ensure_socket_domain(fd, libc::AF_INET6, "IPV6_TCLASS", "tos")?;
+    Ok(getsockopt_int_internal(fd, libc::IPPROTO_IPV6, libc::IPV6_TCLASS)? as u8)
+}
+
+// Restrict an IPv6 socket to IPv6 addresses only (`IPV6_V6ONLY`).
+pub(crate) fn set_v6_only_internal(fd: &impl AsRawFd, on: bool) -> std::io::Result<()> {
+    log::debug!("Setting `IPV6_V6ONLY` to {} on the socket.", on);
+    ensure_v6_socket(fd)?;
+    setsockopt_int_internal(
+        fd,
+        libc::IPPROTO_IPV6,
+        libc::IPV6_V6ONLY,
+        libc::c_int::from(on),
+    )
+}
+
+// Get whether an IPv6 socket is restricted to IPv6 addresses only (`IPV6_V6ONLY`).
+pub(crate) fn get_v6_only_internal(fd: &impl AsRawFd) -> std::io::Result<bool> {
+    ensure_v6_socket(fd)?;
+    Ok(getsockopt_int_internal(fd, libc::IPPROTO_IPV6, libc::IPV6_V6ONLY)? != 0)
+}
+
+// Returns `EINVAL` if the socket is not an IPv6 socket.
+fn ensure_v6_socket(fd: &impl AsRawFd) -> std::io::Result<()> {
+    if getsockopt_int_internal(fd, libc::SOL_SOCKET, libc::SO_DOMAIN)? == libc::AF_INET6 {
+        Ok(())
+    } else {
+        Err(std::io::Error::from_raw_os_error(libc::EINVAL))
+    }
+}
+
+// Returns an `InvalidInput` error if the socket is not of the `domain` (address family), naming
+// the API (`instead`) to be used for the other address family.
+fn ensure_socket_domain(
+    fd: &impl AsRawFd,
+    domain: libc::c_int,
+    optname: &str,
+    instead: &str,
+) -> std::io::Result<()> {
+    let socket_domain = getsockopt_int_internal(fd, libc::SOL_SOCKET, libc::SO_DOMAIN)?;
+    if socket_domain == domain {
+        Ok(())
+    } else {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!(
+                "{} is not supported for this address family, use `{}` instead.",
+                optname, instead
+            ),
+        ))
+    }
+}
+
+// Set the number of seconds after which an idle association is closed automatically.
+pub(crate) fn sctp_set_autoclose_internal(fd: &impl AsRawFd, seconds: u32) -> std::io::Result<()> {
+    log::debug!("Setting `autoclose` to {} seconds on the socket.", seconds);
+    setsockopt_int_internal(
+        fd,
+        SOL_SCTP,
+        SCTP_AUTOCLOSE,
+        seconds.try_into().unwrap_or(libc::c_int::MAX),
+    )
+}
+
+// Get the number of seconds after which an idle association is closed automatically.
+pub(crate) fn sctp_get_autoclose_internal(fd: &impl AsRawFd) -> std::io::Result<u32> {
+    Ok(getsockopt_int_internal(fd, SOL_SCTP, SCTP_AUTOCLOSE)? as u32)
+}
+
+// Enable/Disable the automatic ASCONF (adding or removing the local addresses to the associations
+// as the addresses of the host change).
+pub(crate) fn sctp_set_auto_asconf_internal(fd: &impl AsRawFd, on: bool) -> std::io::Result<()> {
+    log::debug!("Setting `auto_asconf` to {} on the socket.", on);
+    setsockopt_int_internal(fd, SOL_SCTP, SCTP_AUTO_ASCONF, libc::c_int::from(on))
+}
+
+// Get whether the automatic ASCONF is enabled on the socket.
+pub(crate) fn sctp_get_auto_asconf_internal(fd: &impl AsRawFd) -> std::io::Result<bool> {
+    Ok(getsockopt_int_internal(fd, SOL_SCTP, SCTP_AUTO_ASCONF)? != 0)
+}
+
+// Set the value of a socket option that uses `struct sctp_assoc_value`.
+pub(crate) fn sctp_set_assoc_value_internal(
+    fd: &impl AsRawFd,
+    optname: libc::c_int,
+    assoc_id: AssociationId,
+    assoc_value: u32,
+) -> std::io::Result<()> {
+    let value = AssocValue {
+        assoc_id,
+        assoc_value,
+    };
+
+    unsafe {
+        let result = libc::setsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            optname,
+            &value as *const _ as *const libc::c_void,
+            std::mem::size_of::<AssocValue>().try_into().unwrap(),
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Get the value of a socket option that uses `struct sctp_assoc_value`.
+pub(crate) fn sctp_get_assoc_value_internal(
+    fd: &impl AsRawFd,
+    optname: libc::c_int,
+    assoc_id: AssociationId,
+) -> std::io::Result<u32> {
+    let mut value = AssocValue {
+        assoc_id,
+        assoc_value: 0,
+    };
+    let mut value_size = std::mem::size_of::<AssocValue>() as libc::socklen_t;
+
+    unsafe {
+        let result = libc::getsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            optname,
+            &mut value as *mut _ as *mut libc::c_void,
+            &mut value_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(value.assoc_value)
+        }
+    }
+}
+
+// Enable/Disable advertising Partial Reliability support.
+pub(crate) fn sctp_set_pr_supported_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+    on: bool,
+) -> std::io::Result<()> {
+    log::debug!("Setting `pr_supported` to {} on the socket.", on);
+    sctp_set_assoc_value_internal(fd, SCTP_PR_SUPPORTED, assoc_id, u32::from(on))
+}
+
+// Get whether Partial Reliability is supported for the association.
+pub(crate) fn sctp_get_pr_supported_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+) -> std::io::Result<bool> {
+    Ok(sctp_get_assoc_value_internal(fd, SCTP_PR_SUPPORTED, assoc_id)? != 0)
+}
+
+// Set the default Partial Reliability policy used for the sent messages of the association.
+pub(crate) fn sctp_set_default_prinfo_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+    pr_info: PrInfo,
+) -> std::io::Result<()> {
+    log::debug!("Setting default `prinfo` to {:?} on the socket.", pr_info);
+    let default_prinfo = DefaultPrInfo {
+        assoc_id,
+        value: pr_info.value,
+        policy: pr_info.policy as u16,
+    };
+
+    unsafe {
+        let result = libc::setsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_DEFAULT_PRINFO,
+            &default_prinfo as *const _ as *const libc::c_void,
+            std::mem::size_of::<DefaultPrInfo>().try_into().unwrap(),
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Get the default Partial Reliability policy used for the sent messages of the association.
+pub(crate) fn sctp_get_default_prinfo_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+) -> std::io::Result<PrInfo> {
+    let mut default_prinfo = DefaultPrInfo {
+        assoc_id,
+        ..Default::default()
+    };
+    let mut default_prinfo_size = std::mem::size_of::<DefaultPrInfo>() as libc::socklen_t;
+
+    unsafe {
+        let result = libc::getsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_DEFAULT_PRINFO,
+            &mut default_prinfo as *mut _ as *mut libc::c_void,
+            &mut default_prinfo_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(PrInfo {
+                policy: PrPolicy::from_u16(default_prinfo.policy),
+                value: default_prinfo.value,
+            })
+        }
+    }
+}
+
+// Get the number of messages abandoned on the association for all the policies.
+pub(crate) fn sctp_get_pr_status_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+) -> std::io::Result<PrStatus> {
+    let mut pr_status = PrStatusInternal {
+        assoc_id,
+        policy: SCTP_PR_SCTP_ALL,
+        ..Default::default()
+    };
+    let mut pr_status_size = std::mem::size_of::<PrStatusInternal>() as libc::socklen_t;
+
+    unsafe {
+        let result = libc::getsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_PR_ASSOC_STATUS,
+            &mut pr_status as *mut _ as *mut libc::c_void,
+            &mut pr_status_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(PrStatus {
+                abandoned_unsent: pr_status.abandoned_unsent,
+                abandoned_sent: pr_status.abandoned_sent,
+            })
+        }
+    }
+}
+
+// Enable or Disable advertising the support for Stream Reconfiguration (RE-CONFIG).
+pub(crate) fn sctp_set_reconfig_supported_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+    on: bool,
+) -> std::io::Result<()> {
+    log::debug!("Setting `reconfig_supported` to {} on the socket.", on);
+    sctp_set_assoc_value_internal(fd, SCTP_RECONFIG_SUPPORTED, assoc_id, u32::from(on))
+}
+
+// Get whether Stream Reconfiguration (RE-CONFIG) is supported for the association.
+pub(crate) fn sctp_get_reconfig_supported_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+) -> std::io::Result<bool> {
+    Ok(sctp_get_assoc_value_internal(fd, SCTP_RECONFIG_SUPPORTED, assoc_id)? != 0)
+}
+
+// Set the maximum size of the DATA chunks (fragmentation point) for the association.
+pub(crate) fn sctp_set_maxseg_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+    maxseg: u32,
+) -> std::io::Result<()> {
+    log::debug!("Setting `maxseg` to {} on the socket.", maxseg);
+    sctp_set_assoc_value_internal(fd, SCTP_MAXSEG, assoc_id, maxseg)
+}
+
+// Get the maximum size of the DATA chunks (fragmentation point) for the association.
+pub(crate) fn sctp_get_maxseg_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+) -> std::io::Result<u32> {
+    sctp_get_assoc_value_internal(fd, SCTP_MAXSEG, assoc_id)
+}
+
+// Set the maximum number of packets sent in a single burst for the association.
+pub(crate) fn sctp_set_max_burst_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+    max_burst: u32,
+) -> std::io::Result<()> {
+    log::debug!("Setting `max_burst` to {} on the socket.", max_burst);
+    sctp_set_assoc_value_internal(fd, SCTP_MAX_BURST, assoc_id, max_burst)
+}
+
+// Get the maximum number of packets sent in a single burst for the association.
+pub(crate) fn sctp_get_max_burst_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+) -> std::io::Result<u32> {
+    sctp_get_assoc_value_internal(fd, SCTP_MAX_BURST, assoc_id)
+}
+
+// Set the delayed SACK timer (in milliseconds) and the frequency for the association. A `delay_ms`
+// of `0` disables the delayed SACK, which the kernel expects as a `freq` of `1`.
+pub(crate) fn sctp_set_delayed_sack_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+    delay_ms: u32,
+    freq: u32,
+) -> std::io::Result<()> {
+    log::debug!(
+        "Setting delayed SACK `delay`: {}ms, `freq`: {} on the socket.",
+        delay_ms,
+        freq
+    );
+    let sack_info = SackInfo {
+        assoc_id,
+        delay: delay_ms,
+        freq: if delay_ms == 0 { 1 } else { freq },
+    };
+
+    unsafe {
+        let result = libc::setsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_DELAYED_SACK,
+            &sack_info as *const _ as *const libc::c_void,
+            std::mem::size_of::<SackInfo>().try_into().unwrap(),
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Get the delayed SACK timer (in milliseconds) and the frequency for the association.
+pub(crate) fn sctp_get_delayed_sack_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+) -> std::io::Result<(u32, u32)> {
+    let mut sack_info = SackInfo {
+        assoc_id,
+        ..Default::default()
+    };
+    let mut sack_info_size = std::mem::size_of::<SackInfo>() as libc::socklen_t;
+
+    unsafe {
+        let result = libc::getsockopt(
+            fd.as_raw_fd(),
             SOL_SCTP,
-            SCTP_STATUS,
-            &mut sctp_status as *mut _ as *mut libc::c_void,
-            &mut status_size as *mut _ as *mut libc::socklen_t,
+            SCTP_DELAYED_SACK,
+            &mut sack_info as *mut _ as *mut libc::c_void,
+            &mut sack_info_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok((sack_info.delay, sack_info.freq))
+        }
+    }
+}
+
+// Set the default context used for the sent messages of the association.
+pub(crate) fn sctp_set_context_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+    context: u32,
+) -> std::io::Result<()> {
+    log::debug!("Setting `context` to {:#x} on the socket.", context);
+    sctp_set_assoc_value_internal(fd, SCTP_CONTEXT, assoc_id, context)
+}
+
+// Get the default context used for the sent messages of the association.
+pub(crate) fn sctp_get_context_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+) -> std::io::Result<u32> {
+    sctp_get_assoc_value_internal(fd, SCTP_CONTEXT, assoc_id)
+}
+
+// Select the Stream Scheduler for the association.
+pub(crate) fn sctp_set_stream_scheduler_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+    scheduler: StreamScheduler,
+) -> std::io::Result<()> {
+    log::debug!("Setting stream scheduler: {:?}", scheduler);
+    sctp_set_assoc_value_internal(fd, SCTP_STREAM_SCHEDULER, assoc_id, scheduler as u32)
+}
+
+// Get the Stream Scheduler used by the association.
+pub(crate) fn sctp_get_stream_scheduler_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+) -> std::io::Result<StreamScheduler> {
+    Ok(StreamScheduler::from_u32(sctp_get_assoc_value_internal(
+        fd,
+        SCTP_STREAM_SCHEDULER,
+        assoc_id,
+    )?))
+}
+
+// Set the Scheduler value (eg. priority) for a stream of the association.
+pub(crate) fn sctp_set_stream_priority_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+    sid: u16,
+    value: u16,
+) -> std::io::Result<()> {
+    log::debug!("Setting priority {} for the stream {}.", value, sid);
+
+    let status = sctp_get_status_internal(fd, assoc_id)?;
+    if sid >= status.outstreams {
+        return Err(SctpError::InvalidStream {
+            sid,
+            outstreams: status.outstreams,
+        }
+        .into());
+    }
+
+    let stream_value = StreamValue {
+        assoc_id,
+        stream_id: sid,
+        stream_value: value,
+    };
+
+    unsafe {
+        let result = libc::setsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_STREAM_SCHEDULER_VALUE,
+            &stream_value as *const _ as *const libc::c_void,
+            std::mem::size_of::<StreamValue>().try_into().unwrap(),
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Get the Scheduler value (eg. priority) for a stream of the association.
+pub(crate) fn sctp_get_stream_priority_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+    sid: u16,
+) -> std::io::Result<u16> {
+    let mut stream_value = StreamValue {
+        assoc_id,
+        stream_id: sid,
+        stream_value: 0,
+    };
+    let mut stream_value_size = std::mem::size_of::<StreamValue>() as libc::socklen_t;
+
+    unsafe {
+        let result = libc::getsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_STREAM_SCHEDULER_VALUE,
+            &mut stream_value as *mut _ as *mut libc::c_void,
+            &mut stream_value_size as *mut _ as *mut libc::socklen_t,
         );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(stream_value.stream_value)
+        }
+    }
+}
+
+// Enable or Disable ECN for the future associations on the socket.
+pub(crate) fn sctp_set_ecn_internal(fd: &impl AsRawFd, on: bool) -> std::io::Result<()> {
+    log::debug!("Setting `ecn_supported` to {} on the socket.", on);
+    sctp_set_assoc_value_internal(fd, SCTP_ECN_SUPPORTED, 0.into(), u32::from(on))
+        .map_err(|e| unsupported_option_error(e, "SCTP_ECN_SUPPORTED"))
+}
+
+// Get whether ECN is advertised for the future associations on the socket.
+pub(crate) fn sctp_get_ecn_internal(fd: &impl AsRawFd) -> std::io::Result<bool> {
+    Ok(
+        sctp_get_assoc_value_internal(fd, SCTP_ECN_SUPPORTED, 0.into())
+            .map_err(|e| unsupported_option_error(e, "SCTP_ECN_SUPPORTED"))?
+            != 0,
+    )
+}
 
+// Request a heartbeat to be sent immediately to the peer address `addr` of the association.
+pub(crate) fn sctp_request_heartbeat_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+    addr: SocketAddr,
+) -> std::io::Result<()> {
+    log::debug!(
+        "Requesting heartbeat to address: {} of association: {}",
+        addr,
+        assoc_id
+    );
+    let params = PeerAddrParams::new(assoc_id, addr, SPP_HB_DEMAND);
+
+    unsafe {
+        let result = libc::setsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_PEER_ADDR_PARAMS,
+            &params as *const _ as *const libc::c_void,
+            std::mem::size_of::<PeerAddrParams>().try_into().unwrap(),
+        );
         if result < 0 {
             Err(std::io::Error::last_os_error())
         } else {
-            Ok(sctp_status.try_into().unwrap())
+            Ok(())
+        }
+    }
+}
+
+// Set the remote UDP encapsulation port (RFC 6951) for the association or the peer address.
+pub(crate) fn sctp_set_udp_encaps_port_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+    addr: Option<SocketAddr>,
+    port: u16,
+) -> std::io::Result<()> {
+    log::debug!(
+        "Setting UDP encapsulation port: {} for association: {}, address: {:?}",
+        port,
+        assoc_id,
+        addr
+    );
+    let encaps = UdpEncaps::new(assoc_id, addr, port);
+
+    unsafe {
+        let result = libc::setsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_REMOTE_UDP_ENCAPS_PORT,
+            &encaps as *const _ as *const libc::c_void,
+            std::mem::size_of::<UdpEncaps>().try_into().unwrap(),
+        );
+        if result < 0 {
+            Err(unsupported_option_error(
+                std::io::Error::last_os_error(),
+                "SCTP_REMOTE_UDP_ENCAPS_PORT",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Get the remote UDP encapsulation port (RFC 6951) for the association or the peer address.
+pub(crate) fn sctp_get_udp_encaps_port_internal(
+    fd: &impl AsRawFd,
+    assoc_id: AssociationId,
+    addr: Option<SocketAddr>,
+) -> std::io::Result<u16> {
+    let mut encaps = UdpEncaps::new(assoc_id, addr, 0);
+    let mut encaps_size = std::mem::size_of::<UdpEncaps>() as libc::socklen_t;
+
+    unsafe {
+        let result = libc::getsockopt(
+            fd.as_raw_fd(),
+            SOL_SCTP,
+            SCTP_REMOTE_UDP_ENCAPS_PORT,
+            &mut encaps as *mut _ as *mut libc::c_void,
+            &mut encaps_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            Err(unsupported_option_error(
+                std::io::Error::last_os_error(),
+                "SCTP_REMOTE_UDP_ENCAPS_PORT",
+            ))
+        } else {
+            Ok(u16::from_be(encaps.port))
         }
     }
 }
 
+// Older kernels do not support some of the socket options and return `ENOPROTOOPT`, map it to an
+// `Unsupported` error.
+fn unsupported_option_error(e: std::io::Error, optname: &str) -> std::io::Error {
+    if e.raw_os_error() == Some(libc::ENOPROTOOPT) {
+        std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            format!("{} is not supported by the kernel", optname),
+        )
+    } else {
+        e
+    }
+}
+
 fn set_fd_non_blocking(fd: RawFd) -> std::io::Result<()> {
     // Set Non Blocking
     unsafe {
@@ -877,10 +3149,573 @@ fn set_fd_non_blocking(fd: RawFd) -> std::io::Result<()> {
     }
 }
 
+// Returns whether the close-on-exec flag is set on the `fd`.
+fn fd_is_cloexec(fd: RawFd) -> std::io::Result<bool> {
+    unsafe {
+        let result = libc::fcntl(fd, libc::F_GETFD);
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(result & libc::FD_CLOEXEC != 0)
+        }
+    }
+}
+
+// Sets the close-on-exec flag of the peeled off socket `fd` same as that of the `from` socket.
+// Unlike `accept4`, `SCTP_SOCKOPT_PEELOFF` does not take the flags for the new socket.
+fn inherit_fd_cloexec(from: RawFd, fd: RawFd) -> std::io::Result<()> {
+    if !fd_is_cloexec(from)? {
+        return Ok(());
+    }
+
+    unsafe {
+        log::debug!("Setting close-on-exec on the socket.");
+        let result = libc::fcntl(fd, libc::F_GETFD);
+        if result < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        let result = libc::fcntl(fd, libc::F_SETFD, result | libc::FD_CLOEXEC);
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Duplicate the socket using `fcntl(F_DUPFD)`. The new fd keeps the close-on-exec setting of the
+// socket (unlike `libc::dup`, which always clears it).
+pub(crate) fn try_clone_internal(fd: &impl AsRawFd) -> std::io::Result<RawFd> {
+    let raw_fd = fd.as_raw_fd();
+    let cmd = if fd_is_cloexec(raw_fd)? {
+        libc::F_DUPFD_CLOEXEC
+    } else {
+        libc::F_DUPFD
+    };
+
+    // Safety: `raw_fd` is a valid fd owned by the `fd`.
+    unsafe {
+        let result = libc::fcntl(raw_fd, cmd, 0);
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(result)
+        }
+    }
+}
+
 // Close the socket
 #[inline(always)]
-pub(crate) fn close_internal(fd: &AsyncFd<RawFd>) {
+pub(crate) fn close_internal(fd: &impl AsRawFd) {
     unsafe {
-        _ = libc::close(*fd.get_ref());
+        _ = libc::close(fd.as_raw_fd());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns the bytes of an `SCTP_ASSOC_CHANGE` notification with the `info`.
+    fn assoc_change_bytes(state: u16, assoc_id: i32, info: &[u8]) -> Vec<u8> {
+        let mut data = vec![];
+        data.extend(SCTP_ASSOC_CHANGE.to_ne_bytes());
+        data.extend(0_u16.to_ne_bytes());
+        data.extend(((20 + info.len()) as u32).to_ne_bytes());
+        data.extend(state.to_ne_bytes());
+        data.extend(0_u16.to_ne_bytes());
+        data.extend(10_u16.to_ne_bytes());
+        data.extend(5_u16.to_ne_bytes());
+        data.extend(assoc_id.to_ne_bytes());
+        data.extend(info);
+        data
+    }
+
+    #[test]
+    fn notification_assoc_change_valid() {
+        let data = assoc_change_bytes(0, 3, &[0x01, 0x05]);
+
+        let notification = notification_from_message(&data);
+        assert_eq!(
+            notification,
+            Notification::AssociationChange(AssociationChange {
+                ev_type: Event::Association,
+                flags: 0,
+                length: 22,
+                state: AssocChangeState::CommUp,
+                error: 0,
+                ob_streams: 10,
+                ib_streams: 5,
+                assoc_id: 3.into(),
+                info: vec![0x01, 0x05],
+            })
+        );
+    }
+
+    #[test]
+    fn notification_assoc_change_truncated() {
+        let data = assoc_change_bytes(0, 3, &[]);
+
+        for len in [0, 4, 8, 19] {
+            let notification = notification_from_message(&data[..len]);
+            assert!(
+                matches!(notification, Notification::Unsupported { ref data, .. } if data.len() == len),
+                "{:#?}",
+                notification
+            );
+        }
+    }
+
+    #[test]
+    fn notification_length_exceeds_received() {
+        let mut data = assoc_change_bytes(0, 3, &[0x01, 0x05]);
+        data[4..8].copy_from_slice(&64_u32.to_ne_bytes());
+
+        let notification = notification_from_message(&data);
+        assert!(
+            matches!(notification, Notification::Unsupported { length: 64, ref data, .. } if data.len() == 22),
+            "{:#?}",
+            notification
+        );
+
+        data[4..8].copy_from_slice(&4_u32.to_ne_bytes());
+        let notification = notification_from_message(&data);
+        assert!(
+            matches!(notification, Notification::Unsupported { length: 4, .. }),
+            "{:#?}",
+            notification
+        );
+    }
+
+    #[test]
+    fn notification_trailing_bytes_ignored() {
+        let mut data = assoc_change_bytes(0, 3, &[0x01, 0x05]);
+        data.extend([0xFF; 4]);
+
+        let notification = notification_from_message(&data);
+        if let Notification::AssociationChange(AssociationChange { length, info, .. }) =
+            notification
+        {
+            assert_eq!(length, 22);
+            assert_eq!(info, vec![0x01, 0x05]);
+        } else {
+            panic!("Should never come here!: {:#?}", notification);
+        }
+    }
+
+    #[test]
+    fn notification_send_failed_flags() {
+        let mut data = vec![];
+        data.extend(SCTP_SEND_FAILED_EVENT.to_ne_bytes());
+        data.extend(SendFailedFlags::SENT.bits().to_ne_bytes());
+        data.extend(36_u32.to_ne_bytes());
+        data.extend([0_u8; 24]);
+        data.extend(b"data");
+
+        let notification = notification_from_message(&data);
+        if let Notification::SendFailed(SendFailed { flags, data, .. }) = notification {
+            assert!(flags.contains(SendFailedFlags::SENT), "{:?}", flags);
+            assert!(!flags.contains(SendFailedFlags::UNSENT), "{:?}", flags);
+            assert_eq!(data, b"data".to_vec());
+        } else {
+            panic!("Should never come here!: {:#?}", notification);
+        }
+    }
+
+    #[test]
+    fn send_failed_flags_known_bits() {
+        assert_eq!(SendFailedFlags::UNSENT.bits(), 0x0001);
+        assert_eq!(SendFailedFlags::SENT.bits(), 0x0002);
+
+        assert_eq!(
+            format!("{:?}", SendFailedFlags::from_u16(0x0003)),
+            "SendFailedFlags(UNSENT | SENT)"
+        );
+        assert_eq!(
+            format!("{:?}", SendFailedFlags::from_u16(0x0011)),
+            "SendFailedFlags(UNSENT | 0x0010)"
+        );
+    }
+
+    #[test]
+    fn stream_reset_flags_known_bits() {
+        assert_eq!(StreamResetFlags::INCOMING.bits(), 0x0001);
+        assert_eq!(StreamResetFlags::OUTGOING.bits(), 0x0002);
+        assert_eq!(StreamResetFlags::DENIED.bits(), 0x0004);
+        assert_eq!(StreamResetFlags::FAILED.bits(), 0x0008);
+
+        let flags = StreamResetFlags::from_u16(0x000F);
+        for flag in [
+            StreamResetFlags::INCOMING,
+            StreamResetFlags::OUTGOING,
+            StreamResetFlags::DENIED,
+            StreamResetFlags::FAILED,
+        ] {
+            assert!(flags.contains(flag), "{:?}", flag);
+        }
+        assert_eq!(
+            format!("{:?}", flags),
+            "StreamResetFlags(INCOMING | OUTGOING | DENIED | FAILED)"
+        );
+        assert_eq!(
+            format!("{:?}", StreamResetFlags::default()),
+            "StreamResetFlags()"
+        );
+    }
+
+    #[test]
+    fn send_cmsgs_all_ancillary_data() {
+        let data = SendData {
+            payload: vec![],
+            snd_info: Some(SendInfo {
+                sid: 3,
+                ppid: 0x42,
+                ..Default::default()
+            }),
+            pr_info: Some(PrInfo {
+                policy: crate::PrPolicy::Rtx,
+                value: 3,
+            }),
+            auth_info: Some(AuthInfo { key_number: 2 }),
+            dst_addrs: vec!["127.0.0.2:0".parse().unwrap(), "[::1]:0".parse().unwrap()],
+        };
+
+        let size = send_cmsg_space(&data);
+        let mut buffer = send_cmsg_buffer(size);
+
+        // Safety: The control buffer is `send_cmsg_space` bytes and outlives the `msghdr`.
+        unsafe {
+            let mut msghdr: libc::msghdr = std::mem::zeroed();
+            msghdr.msg_control = buffer.as_mut_ptr() as *mut libc::c_void;
+            msghdr.msg_controllen = size as _;
+
+            write_send_cmsgs(&msghdr, &data);
+
+            let cmsg_hdr = libc::CMSG_FIRSTHDR(&msghdr);
+            assert_eq!((*cmsg_hdr).cmsg_level, libc::IPPROTO_SCTP);
+            assert_eq!((*cmsg_hdr).cmsg_type, CmsgType::SndInfo as i32);
+            assert_eq!(
+                (*cmsg_hdr).cmsg_len as usize,
+                libc::CMSG_LEN(std::mem::size_of::<SendInfo>() as u32) as usize
+            );
+            let snd_info = std::ptr::read_unaligned(libc::CMSG_DATA(cmsg_hdr) as *const SendInfo);
+            assert_eq!(Some(snd_info), data.snd_info);
+
+            let cmsg_hdr = libc::CMSG_NXTHDR(&msghdr, cmsg_hdr);
+            assert_eq!((*cmsg_hdr).cmsg_level, libc::IPPROTO_SCTP);
+            assert_eq!((*cmsg_hdr).cmsg_type, CmsgType::PrInfo as i32);
+            let pr_info = std::ptr::read_unaligned(libc::CMSG_DATA(cmsg_hdr) as *const PrInfo);
+            assert_eq!(Some(pr_info), data.pr_info);
+
+            let cmsg_hdr = libc::CMSG_NXTHDR(&msghdr, cmsg_hdr);
+            assert_eq!((*cmsg_hdr).cmsg_level, libc::IPPROTO_SCTP);
+            assert_eq!((*cmsg_hdr).cmsg_type, CmsgType::AuthInfo as i32);
+            let auth_info = std::ptr::read_unaligned(libc::CMSG_DATA(cmsg_hdr) as *const AuthInfo);
+            assert_eq!(Some(auth_info), data.auth_info);
+
+            let cmsg_hdr = libc::CMSG_NXTHDR(&msghdr, cmsg_hdr);
+            assert_eq!((*cmsg_hdr).cmsg_type, CmsgType::DstAddrV4 as i32);
+            let in_addr = std::ptr::read_unaligned(libc::CMSG_DATA(cmsg_hdr) as *const [u8; 4]);
+            assert_eq!(in_addr, [127, 0, 0, 2]);
+
+            let cmsg_hdr = libc::CMSG_NXTHDR(&msghdr, cmsg_hdr);
+            assert_eq!((*cmsg_hdr).cmsg_type, CmsgType::DstAddrV6 as i32);
+            let in6_addr = std::ptr::read_unaligned(libc::CMSG_DATA(cmsg_hdr) as *const [u8; 16]);
+            assert_eq!(in6_addr, std::net::Ipv6Addr::LOCALHOST.octets());
+
+            assert!(libc::CMSG_NXTHDR(&msghdr, cmsg_hdr).is_null());
+        }
+    }
+
+    #[test]
+    fn send_data_builder() {
+        let data = SendData::new(b"hello".to_vec());
+        assert_eq!(data.snd_info, None);
+
+        let data = data.stream(3).ppid(0x42).unordered().context(7);
+        assert_eq!(data.payload, b"hello".to_vec());
+        assert_eq!(
+            data.snd_info,
+            Some(SendInfo {
+                sid: 3,
+                flags: SendFlags::UNORDERED,
+                ppid: 0x42,
+                context: 7,
+                assoc_id: 0.into(),
+            })
+        );
+        assert_eq!(data.pr_info, None);
+    }
+
+    #[test]
+    fn send_data_with_lifetime() {
+        let data = SendData::with_lifetime(b"hello".to_vec(), std::time::Duration::from_secs(2));
+        assert_eq!(data.payload, b"hello".to_vec());
+        assert_eq!(data.snd_info, None);
+        assert_eq!(
+            data.pr_info,
+            Some(PrInfo {
+                policy: crate::PrPolicy::Ttl,
+                value: 2000,
+            })
+        );
+
+        // The lifetime is clamped and the other builder methods can still be used.
+        let data = SendData::with_lifetime(vec![], std::time::Duration::MAX).stream(3);
+        assert_eq!(data.pr_info.unwrap().value, u32::MAX);
+        assert_eq!(data.snd_info.unwrap().sid, 3);
+    }
+
+    #[test]
+    fn send_cmsgs_without_snd_info() {
+        // No ancillary data: No control buffer is used.
+        let data = SendData::new(b"hello".to_vec());
+        assert_eq!(send_cmsg_space(&data), 0);
+        assert!(send_cmsg_buffer(0).is_empty());
+
+        // Safety: There is no control buffer and nothing is written.
+        unsafe {
+            let msghdr: libc::msghdr = std::mem::zeroed();
+            write_send_cmsgs(&msghdr, &data);
+        }
+
+        // Only `PrInfo`: It is the first control message.
+        let data = SendData {
+            pr_info: Some(PrInfo {
+                policy: crate::PrPolicy::Ttl,
+                value: 1000,
+            }),
+            ..data
+        };
+        let size = send_cmsg_space(&data);
+        let mut buffer = send_cmsg_buffer(size);
+        assert!(buffer.len() * 8 >= size);
+
+        // Safety: The control buffer is `send_cmsg_space` bytes and outlives the `msghdr`.
+        unsafe {
+            let mut msghdr: libc::msghdr = std::mem::zeroed();
+            msghdr.msg_control = buffer.as_mut_ptr() as *mut libc::c_void;
+            msghdr.msg_controllen = size as _;
+
+            write_send_cmsgs(&msghdr, &data);
+
+            let cmsg_hdr = libc::CMSG_FIRSTHDR(&msghdr);
+            assert_eq!((*cmsg_hdr).cmsg_type, CmsgType::PrInfo as i32);
+            let pr_info = std::ptr::read_unaligned(libc::CMSG_DATA(cmsg_hdr) as *const PrInfo);
+            assert_eq!(Some(pr_info), data.pr_info);
+            assert!(libc::CMSG_NXTHDR(&msghdr, cmsg_hdr).is_null());
+        }
+    }
+
+    #[test]
+    fn recv_flags_from_msg_flags() {
+        let flags = RecvFlags::from_msg_flags(libc::MSG_EOR | libc::MSG_CTRUNC | 0x8000);
+        assert!(flags.contains(RecvFlags::EOR));
+        assert!(flags.contains(RecvFlags::CTRUNC));
+        assert!(!flags.contains(RecvFlags::TRUNC));
+        assert_eq!(format!("{:?}", flags), "RecvFlags(EOR | CTRUNC)");
+    }
+
+    #[test]
+    fn peer_addr_params_layout() {
+        // `struct sctp_paddrparams` is packed and aligned to 4 bytes.
+        assert_eq!(std::mem::size_of::<PeerAddrParams>(), 156);
+
+        let addr: SocketAddr = "127.0.0.1:3868".parse().unwrap();
+        let params = PeerAddrParams::new(5.into(), addr, SPP_HB_DEMAND);
+        let flags = params.flags;
+        assert_eq!(flags, SPP_HB_DEMAND);
+        let assoc_id = params.assoc_id;
+        assert_eq!(assoc_id, 5.into());
+    }
+
+    #[test]
+    fn partial_send_error_round_trip() {
+        let err: std::io::Error = SctpError::PartialSend { written: 42 }.into();
+        assert_eq!(err.kind(), std::io::ErrorKind::Other);
+        assert!(matches!(
+            SctpError::from(err),
+            SctpError::PartialSend { written: 42 }
+        ));
+    }
+
+    #[test]
+    fn check_sent_len_abort_eof_exempt() {
+        let data = SendData::new(b"going away".to_vec());
+        let result = check_sent_len(0, 10, &data);
+        assert!(matches!(
+            SctpError::from(result.err().unwrap()),
+            SctpError::PartialSend { written: 0 }
+        ));
+        assert_eq!(check_sent_len(10, 10, &data).unwrap(), 10);
+
+        for flags in [SendFlags::ABORT, SendFlags::EOF] {
+            let data = SendData {
+                snd_info: Some(SendInfo {
+                    flags,
+                    ..Default::default()
+                }),
+                ..SendData::new(b"going away".to_vec())
+            };
+            assert_eq!(check_sent_len(0, 10, &data).unwrap(), 0);
+        }
+    }
+
+    #[test]
+    fn invalid_stream_error_round_trip() {
+        let err: std::io::Error = SctpError::InvalidStream {
+            sid: 10,
+            outstreams: 10,
+        }
+        .into();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "Stream ID 10 is out of range, the association has 10 outbound streams"
+        );
+        assert!(matches!(
+            SctpError::from(err),
+            SctpError::InvalidStream {
+                sid: 10,
+                outstreams: 10
+            }
+        ));
+    }
+
+    #[test]
+    fn retry_on_interrupt_retries_only_eintr() {
+        let mut calls = 0;
+        let result = retry_on_interrupt(|| {
+            calls += 1;
+            if calls < 3 {
+                Err(std::io::Error::from_raw_os_error(libc::EINTR))
+            } else {
+                Ok(calls)
+            }
+        });
+        assert_eq!(result.unwrap(), 3);
+
+        let mut calls = 0;
+        let result: std::io::Result<()> = retry_on_interrupt(|| {
+            calls += 1;
+            Err(std::io::Error::from_raw_os_error(libc::EWOULDBLOCK))
+        });
+        assert_eq!(result.err().unwrap().kind(), std::io::ErrorKind::WouldBlock);
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn association_id_layout_and_conversions() {
+        // Embedded in the `#[repr(C)]` structures passed to the kernel as `sctp_assoc_t`.
+        assert_eq!(
+            std::mem::size_of::<AssociationId>(),
+            std::mem::size_of::<i32>()
+        );
+        assert_eq!(
+            std::mem::align_of::<AssociationId>(),
+            std::mem::align_of::<i32>()
+        );
+        assert_eq!(std::mem::size_of::<SendInfo>(), 16);
+
+        let assoc_id = AssociationId::from(42);
+        assert_eq!(i32::from(assoc_id), 42);
+        assert_eq!(assoc_id.to_string(), "42");
+        assert_eq!(
+            AssociationId::from(SubscribeEventAssocId::All),
+            AssociationId::from(2)
+        );
+        assert_eq!(
+            AssociationId::from(SubscribeEventAssocId::Value(assoc_id)),
+            assoc_id
+        );
+    }
+
+    #[test]
+    fn sndrcv_info_into_rcv_info() {
+        assert_eq!(std::mem::size_of::<SndRcvInfo>(), 32);
+
+        let sndrcv_info = SndRcvInfo {
+            sid: 1,
+            ssn: 2,
+            flags: 1,
+            ppid: 3,
+            context: 4,
+            timetolive: 5,
+            tsn: 6,
+            cumtsn: 7,
+            assoc_id: 8.into(),
+        };
+        let rcv_info = RcvInfo::from(sndrcv_info);
+        assert_eq!((rcv_info.sid, rcv_info.ssn, rcv_info.ppid), (1, 2, 3));
+        assert_eq!((rcv_info.tsn, rcv_info.cumtsn, rcv_info.context), (6, 7, 4));
+        assert_eq!(rcv_info.assoc_id, 8.into());
+        assert!(rcv_info.flags.contains(crate::RcvFlags::UNORDERED));
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn buf_io_slices_chunks() {
+        use bytes::Buf;
+
+        let buf = bytes::Bytes::from_static(b"header:").chain(&b"hello world!"[..]);
+        let mut slices = [IoSlice::new(&[]); 2];
+        assert_eq!(buf_io_slices(&buf, &mut slices), Some(2));
+        assert_eq!(&*slices[0], b"header:");
+        assert_eq!(&*slices[1], b"hello world!");
+
+        // Not enough `slices` for all the chunks.
+        let mut slices = [IoSlice::new(&[]); 1];
+        assert_eq!(buf_io_slices(&buf, &mut slices), None);
+
+        let mut received = ReceivedData {
+            payload: b"hello world!".to_vec(),
+            rcv_info: None,
+            nxt_info: None,
+            flags: RecvFlags::default(),
+            from: None,
+        };
+        let payload = received.take_payload();
+        assert_eq!(payload.slice(6..), &b"world!"[..]);
+        assert!(received.payload.is_empty());
+    }
+
+    #[test]
+    fn event_subscribe_has_flag() {
+        let size = std::mem::size_of::<EventSubscribe>();
+        assert!(EventSubscribe::has_flag(&Event::DataIo, 1));
+        assert!(EventSubscribe::has_flag(&Event::SendFailureEvent, size));
+        assert!(!EventSubscribe::has_flag(
+            &Event::SendFailureEvent,
+            size - 1
+        ));
+
+        // The structure of the kernels before `sender_dry` was added.
+        assert!(EventSubscribe::has_flag(&Event::Authentication, 9));
+        assert!(!EventSubscribe::has_flag(&Event::SenderDry, 9));
+        assert!(!EventSubscribe::has_flag(&Event::StreamReset, 9));
+        assert!(!EventSubscribe::has_flag(&Event::Unknown, size));
+    }
+
+    #[test]
+    fn event_mask_into_event_subscribe() {
+        assert_eq!(std::mem::size_of::<EventSubscribe>(), 14);
+
+        let subscribe = EventSubscribe::from(EventMask::default());
+        assert_eq!(subscribe.association, 0);
+
+        let mask = EventMask::default()
+            .with(Event::Association, true)
+            .with(Event::SendFailureEvent, true)
+            .with(Event::Unknown, true);
+        let subscribe = EventSubscribe::from(mask);
+        assert_eq!(subscribe.data_io, 0);
+        assert_eq!(subscribe.association, 1);
+        assert_eq!(subscribe.send_failure_event, 1);
+        assert!(!mask.contains(&Event::Unknown));
+        assert_eq!(format!("{:?}", mask), "{Association, SendFailureEvent}");
+
+        let subscribe = EventSubscribe::from(EventMask::all().with(Event::Shutdown, false));
+        assert_eq!((subscribe.data_io, subscribe.shutdown), (1, 0));
+        assert_eq!(subscribe.send_failure_event, 1);
     }
 }
@@ -12,12 +12,33 @@
 //! appropriate types thus making use of the [`std::net::SocketAddr`] structures wherever
 //! appropriate rather than using the [`libc::sockaddr`] structures for example.
 //!
+//! # Features
+//!
+//! - `tokio` (default): The sockets are registered with the `tokio` reactor and must be used
+//!   from within a `tokio` runtime.
+//! - `async-io`: The sockets are registered with the `async-io` reactor instead, so that they can
+//!   be used with `smol` or `async-std`. Use `default-features = false` to enable this. The
+//!   functions that are specific to `tokio` (eg. `ConnectedSocket::split_events`) are not
+//!   available with this feature.
+//! - `serde`: Implements `serde::Serialize` and `serde::Deserialize` for the public types (eg.
+//!   the [`Notification`]s, [`ConnStatus`] or [`PeerAddress`]), which is useful for logging
+//!   them in a structured format like JSON.
+//! - `bytes`: Adds the APIs for using `bytes::Bytes` payloads without copying. The received
+//!   payload can be taken as `Bytes` using `ReceivedData::take_payload` and any `bytes::Buf`
+//!   can be sent using `ConnectedSocket::sctp_send_buf` or `Listener::sctp_send_buf`.
+//! - `blocking`: Provides the blocking sockets in the `blocking` module, that can be used
+//!   without an async runtime. Use `default-features = false` with only this feature, if the
+//!   async sockets are not required.
+//!
 //! # Example
 //!
 //! The examples below will help you to get started using the APIs in your application.
 //!
 //! ```rust,no_run
 //!
+//! # #[cfg(not(any(feature = "tokio", feature = "async-io")))]
+//! # fn main() {}
+//! # #[cfg(any(feature = "tokio", feature = "async-io"))]
 //! # #[tokio::main(flavor="current_thread")]
 //! # async fn main() -> std::io::Result<()> {
 //!
@@ -43,6 +64,10 @@
 //!         sctp_rs::NotificationOrData::Data(data) => {
 //!             // Process Data
 //!         }
+//!         sctp_rs::NotificationOrData::Eof => {
+//!             // Association is closed
+//!             break;
+//!         }
 //!     }
 //! }
 //!
@@ -50,28 +75,70 @@
 //! # }
 //! ```
 
+#[cfg(not(any(feature = "tokio", feature = "async-io", feature = "blocking")))]
+compile_error!("At least one of the `tokio`, `async-io` or `blocking` features must be enabled.");
+
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 mod connected_socket;
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 mod listener;
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 mod socket;
 
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 #[doc(inline)]
 pub use socket::Socket;
 
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 #[doc(inline)]
 pub use listener::Listener;
 
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 #[doc(inline)]
 pub use connected_socket::ConnectedSocket;
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+mod split;
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+#[doc(inline)]
+pub use split::{OwnedReadHalf, OwnedWriteHalf};
+
+#[cfg(feature = "tokio")]
+mod events;
+#[cfg(feature = "tokio")]
+#[doc(inline)]
+pub use events::{DataReceiver, NotificationReceiver};
 
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+mod incoming;
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+#[doc(inline)]
+pub use incoming::Incoming;
+
+mod error;
+#[doc(inline)]
+pub use error::SctpError;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+// The blocking sockets use only a part of the internals, rest is used by the async sockets.
+#[cfg_attr(not(any(feature = "tokio", feature = "async-io")), allow(dead_code))]
 mod internal;
 
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+mod reactor;
+
 mod consts;
 
+#[cfg_attr(not(any(feature = "tokio", feature = "async-io")), allow(dead_code))]
 mod types;
 
 #[doc(inline)]
 pub use types::{
-    AssocChangeState, AssociationChange, AssociationId, BindxFlags, CmsgType, ConnStatus, Event,
-    Notification, NotificationOrData, NxtInfo, RcvInfo, ReceivedData, SendData, SendInfo, Shutdown,
-    SocketToAssociation, SubscribeEventAssocId,
+    AssocChangeState, AssociationChange, AssociationId, AuthInfo, BindxFlags, CmsgType, ConnStatus,
+    Event, EventMask, InitParams, Notification, NotificationOrData, NxtInfo, PeerAddress,
+    PeerAddressChange, PeerAddressState, PeerCapability, PrInfo, PrPolicy, PrStatus, RcvFlags,
+    RcvInfo, ReceivedData, ReceivedInfo, RecvFlags, SendData, SendFailed, SendFailedFlags,
+    SendFlags, SendInfo, SenderDry, Shutdown, SocketFlags, SocketToAssociation, StreamResetEvent,
+    StreamResetFlags, StreamScheduler, SubscribeEventAssocId,
 };
@@ -1,15 +1,20 @@
 //! Listening SCTP Socket
 
+use std::collections::VecDeque;
+use std::io::IoSlice;
 use std::net::SocketAddr;
 use std::os::unix::io::RawFd;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 
-use tokio::io::unix::AsyncFd;
-
+use crate::incoming::incoming;
 #[allow(unused)]
 use crate::internal::*;
+use crate::reactor::RegisteredFd;
 use crate::{
-    types::AssociationId, BindxFlags, ConnStatus, ConnectedSocket, Event, NotificationOrData,
-    SendData, SubscribeEventAssocId,
+    types::AssociationId, BindxFlags, ConnStatus, ConnectedSocket, Event, EventMask, Incoming,
+    InitParams, NotificationOrData, PeerAddress, ReceivedInfo, SendData, SendInfo,
+    SubscribeEventAssocId,
 };
 
 /// A structure representing a socket that is listening for incoming SCTP Connections.
@@ -19,13 +24,89 @@ use crate::{
 /// [`Socket`][crate::Socket] is consumed when this structure is created. See
 /// [`Socket::listen`][crate::Socket::listen] for more details.
 pub struct Listener {
-    inner: AsyncFd<RawFd>,
+    inner: RegisteredFd,
+
+    // Messages received while waiting for a new association (See `accept_assoc`), that are
+    // returned by the subsequent receive calls, before receiving from the socket.
+    pending: Mutex<VecDeque<NotificationOrData>>,
+
+    // Size of the buffer allocated for receiving a message (See `set_recv_capacity`).
+    recv_capacity: AtomicUsize,
 }
 
 impl Listener {
     /// Accept on a given socket (valid only for `OneToOne` type sockets).
     pub async fn accept(&self) -> std::io::Result<(ConnectedSocket, SocketAddr)> {
-        accept_internal(&self.inner).await
+        std::future::poll_fn(|cx| self.poll_accept(cx)).await
+    }
+
+    /// Returns a [`Stream`][`futures_core::Stream`] of the connections accepted on the socket
+    /// (valid only for `OneToOne` type sockets).
+    ///
+    /// The stream repeatedly calls [`accept`][`Self::accept`], yielding the accepted connection
+    /// or the error. After the transient errors (like `ECONNABORTED` or `EMFILE`), the stream
+    /// continues to accept, any other error is the last item yielded by the stream.
+    pub fn incoming(&self) -> Incoming<'_> {
+        incoming(self)
+    }
+
+    /// Poll to accept on a given socket (valid only for `OneToOne` type sockets).
+    ///
+    /// This is the `poll` based variant of [`accept`][`Self::accept`], useful for implementing
+    /// custom [`Future`][`std::future::Future`]s or `Stream`s on top of the [`Listener`]. Returns
+    /// [`Poll::Pending`][`std::task::Poll::Pending`] if there is no pending connection, in which
+    /// case the waker from `cx` is notified when the listener becomes ready. Only the waker
+    /// passed to the most recent call is notified.
+    pub fn poll_accept(
+        &self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<(ConnectedSocket, SocketAddr)>> {
+        poll_accept_internal(&self.inner, cx)
+    }
+
+    /// Wait for the socket to be readable.
+    ///
+    /// Nothing is received from the socket, hence this can be used (for example, in a
+    /// `select!`) without losing a message if the future is dropped before it completes. The
+    /// readiness may be spurious, the subsequent receive may still wait. See
+    /// [`ConnectedSocket::readable`] for the details.
+    pub async fn readable(&self) -> std::io::Result<()> {
+        if !self.pending.lock().unwrap().is_empty() {
+            return Ok(());
+        }
+        self.inner.readable().await
+    }
+
+    /// Wait for the socket to be writable.
+    ///
+    /// The readiness may be spurious as in [`readable`][`Self::readable`].
+    pub async fn writable(&self) -> std::io::Result<()> {
+        self.inner.writable().await
+    }
+
+    /// Poll for the socket to be readable.
+    ///
+    /// This is the `poll` based variant of [`readable`][`Self::readable`]. See
+    /// [`ConnectedSocket::poll_read_ready`] for the details.
+    pub fn poll_read_ready(
+        &self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        if !self.pending.lock().unwrap().is_empty() {
+            return std::task::Poll::Ready(Ok(()));
+        }
+        self.inner.poll_read_ready(cx)
+    }
+
+    /// Poll for the socket to be writable.
+    ///
+    /// This is the `poll` based variant of [`writable`][`Self::writable`]. See
+    /// [`ConnectedSocket::poll_read_ready`] for the details.
+    pub fn poll_write_ready(
+        &self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        self.inner.poll_write_ready(cx)
     }
 
     /// Shutdown on the socket
@@ -49,6 +130,27 @@ impl Listener {
         sctp_peeloff_internal(&self.inner, assoc_id)
     }
 
+    /// Accept a new association on a One to Many socket.
+    ///
+    /// This waits for an [`AssociationChange`][`crate::AssociationChange`] notification with
+    /// [`CommUp`][`crate::AssocChangeState::CommUp`] state and then
+    /// [`peels off`][`Self::sctp_peeloff`] the association. Returns the peeled off socket, the
+    /// Association ID and the (primary) address of the peer.
+    ///
+    /// The [`Event::Association`] is subscribed to (and stays subscribed). Any Data or
+    /// Notifications received while waiting are queued and returned by the subsequent
+    /// [`sctp_recv`][`Self::sctp_recv`] (or other receive) calls, except the ones that belong to
+    /// the accepted association, which are returned by the receive calls on the peeled off socket
+    /// instead. Note: The Data can be attributed to an association only when receiving the
+    /// [`RcvInfo`][`crate::RcvInfo`] is requested (See
+    /// [`sctp_request_rcvinfo`][`Self::sctp_request_rcvinfo`]). The messages not yet received by
+    /// the application are moved to the peeled off socket by the kernel.
+    pub async fn accept_assoc(
+        &self,
+    ) -> std::io::Result<(ConnectedSocket, AssociationId, SocketAddr)> {
+        sctp_accept_assoc_internal(&self.inner, &self.pending, self.recv_capacity()).await
+    }
+
     /// Get Peer Address(es) for the given Association ID. See: Section 9.3 RFC 6458
     ///
     /// This function is supported on the [`Listener`] because in the case of One to Many
@@ -70,17 +172,172 @@ impl Listener {
     /// receive the data is also the API used to receive notifications. This function returns
     /// either the notification (which the user should have subscribed for) or the data.
     pub async fn sctp_recv(&self) -> std::io::Result<NotificationOrData> {
-        sctp_recvmsg_internal(&self.inner).await
+        if let Some(received) = self.pending.lock().unwrap().pop_front() {
+            return Ok(received);
+        }
+        sctp_recvmsg_internal(&self.inner, self.recv_capacity()).await
+    }
+
+    /// Set the size of the buffer allocated for receiving a message.
+    ///
+    /// The buffer of `capacity` bytes is allocated for every message received by the subsequent
+    /// [`sctp_recv`][`Self::sctp_recv`] (or other receive) calls, the default is 4096 bytes. The
+    /// kernel reassembles the fragmented messages before delivering them, a message larger than the
+    /// `capacity` is received in parts (only the last part has the [`EOR`][`crate::RecvFlags::EOR`]
+    /// flag set), so this should be at-least as large as the largest expected message. A smaller
+    /// value avoids over allocation for small messages. A `capacity` of `0` is rejected with
+    /// `InvalidInput`. The setting is not inherited by the sockets accepted or peeled off from this
+    /// socket.
+    pub fn set_recv_capacity(&self, capacity: usize) -> std::io::Result<()> {
+        if capacity == 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "receive capacity must be non-zero",
+            ));
+        }
+        self.recv_capacity.store(capacity, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Get the size of the buffer allocated for receiving a message.
+    ///
+    /// See [`set_recv_capacity`][`Self::set_recv_capacity`] for details.
+    pub fn recv_capacity(&self) -> usize {
+        self.recv_capacity.load(Ordering::Relaxed)
+    }
+
+    /// Receive Data or Notification into the passed buffer.
+    ///
+    /// This is same as [`sctp_recv`][`Self::sctp_recv`], except that the message is received in the
+    /// spare capacity of the `buf` (`buf` is cleared first) and the length of the `buf` is set to
+    /// the length of the received message. If `buf` has no capacity, the receive capacity (See
+    /// [`set_recv_capacity`][`Self::set_recv_capacity`]) is reserved. Reusing the same `buf` across
+    /// the calls avoids allocations on every received message. Messages larger than the capacity of
+    /// the `buf` are received in parts. Note: The messages queued by
+    /// [`accept_assoc`][`Self::accept_assoc`] are always returned in full.
+    pub async fn sctp_recv_into(&self, buf: &mut Vec<u8>) -> std::io::Result<ReceivedInfo> {
+        if let Some(received) = self.pending.lock().unwrap().pop_front() {
+            return Ok(pending_into_received_info(received, buf));
+        }
+        sctp_recvmsg_into_internal(&self.inner, buf, self.recv_capacity()).await
+    }
+
+    /// Receive up to `max` Data or Notification messages in a single system call.
+    ///
+    /// This uses `recvmmsg` to receive the messages and waits only for the socket to be readable,
+    /// so the returned `Vec` contains at-least one and at-most `max` messages. Each message is
+    /// same as the one returned by [`sctp_recv`][`Self::sctp_recv`]. This is useful for reducing
+    /// the system call overhead on busy `OneToMany` sockets.
+    #[cfg(target_os = "linux")]
+    pub async fn sctp_recv_batch(&self, max: usize) -> std::io::Result<Vec<NotificationOrData>> {
+        {
+            let mut pending = self.pending.lock().unwrap();
+            if !pending.is_empty() {
+                let count = max.min(pending.len());
+                return Ok(pending.drain(..count).collect());
+            }
+        }
+        sctp_recvmmsg_internal(&self.inner, max, self.recv_capacity()).await
+    }
+
+    /// Send multiple messages, possibly to different peers, in a single system call.
+    ///
+    /// This uses `sendmmsg` to send the messages and is useful for sending to many associations
+    /// at once on a `OneToMany` socket. Returns the number of messages that were successfully
+    /// queued, which may be less than the number of messages passed, in which case the caller
+    /// may resume sending from the first message that was not queued.
+    #[cfg(target_os = "linux")]
+    pub async fn sctp_send_batch(
+        &self,
+        msgs: Vec<(SocketAddr, SendData)>,
+    ) -> std::io::Result<usize> {
+        sctp_sendmmsg_internal(&self.inner, msgs).await
     }
 
     /// Send Data and Anciliary data if any on the SCTP Socket.
     ///
     /// SCTP supports sending the actual SCTP message together with sending any anciliary data on
-    /// the SCTP association. The anciliary data is optional.
-    pub async fn sctp_send(&self, to: SocketAddr, data: SendData) -> std::io::Result<()> {
+    /// the SCTP association. The anciliary data is optional. Returns the number of bytes of the
+    /// payload accepted by the kernel, which is always the full payload, since an SCTP message is
+    /// sent as a whole. If the kernel accepts only a part of the message, an error wrapping
+    /// [`SctpError::PartialSend`][`crate::SctpError::PartialSend`] is returned.
+    pub async fn sctp_send(&self, to: SocketAddr, data: SendData) -> std::io::Result<usize> {
         sctp_sendmsg_internal(&self.inner, Some(to), data).await
     }
 
+    /// Send a message made up of multiple buffers to the peer `to`.
+    ///
+    /// This is same as [`sctp_send`][`Self::sctp_send`], except that the payload is taken from
+    /// the `bufs` without having to concatenate them first. All the `bufs` together form a
+    /// single SCTP message. The ancillary data is sent using the `snd_info`. Returns the number
+    /// of bytes of the payload accepted by the kernel.
+    pub async fn sctp_send_vectored(
+        &self,
+        to: SocketAddr,
+        bufs: &[IoSlice<'_>],
+        snd_info: Option<SendInfo>,
+    ) -> std::io::Result<usize> {
+        sctp_sendmsg_vectored_internal(&self.inner, Some(to), bufs, snd_info).await
+    }
+
+    /// Send a message taken from a [`bytes::Buf`] to the peer `to`.
+    ///
+    /// This is same as [`sctp_send_vectored`][`Self::sctp_send_vectored`], with the chunks of the
+    /// `buf` (eg. [`bytes::Bytes`] or a chain of them) forming a single SCTP message. This is
+    /// available only with the `bytes` feature.
+    #[cfg(feature = "bytes")]
+    pub async fn sctp_send_buf(
+        &self,
+        to: SocketAddr,
+        buf: impl bytes::Buf,
+        snd_info: Option<SendInfo>,
+    ) -> std::io::Result<usize> {
+        sctp_sendmsg_buf_internal(&self.inner, Some(to), buf, snd_info).await
+    }
+
+    /// Send Data and Anciliary data if any on the association `assoc_id`.
+    ///
+    /// This is same as [`sctp_send`][`Self::sctp_send`], except that the association is
+    /// identified by the `assoc_id` (for example, as received in a notification), instead of the
+    /// peer address. The `assoc_id` of the [`SendInfo`] in the `data` is set to `assoc_id` (a
+    /// default [`SendInfo`] is used, if the `data` does not have one).
+    pub async fn sctp_send_assoc(
+        &self,
+        assoc_id: AssociationId,
+        mut data: SendData,
+    ) -> std::io::Result<usize> {
+        data.snd_info.get_or_insert_with(SendInfo::default).assoc_id = assoc_id;
+        sctp_sendmsg_internal(&self.inner, None, data).await
+    }
+
+    /// Abort the association identified by the `assoc_id` sending the `reason` to the peer.
+    ///
+    /// Unlike [`shutdown`][`Self::shutdown`], which performs a graceful close, an `ABORT` is sent
+    /// to the peer immediately and any data queued for sending on the association is discarded.
+    /// The `reason` is delivered to the peer as a 'User Initiated Abort' error cause. See Section
+    /// 5.3.4 of RFC 6458 (`SCTP_ABORT` flag). This is valid only for One to Many style sockets.
+    pub async fn abort(&self, assoc_id: AssociationId, reason: &[u8]) -> std::io::Result<()> {
+        sctp_abort_internal(&self.inner, assoc_id, reason).await
+    }
+
+    /// Abort the association identified by the `assoc_id` without a reason.
+    ///
+    /// This is same as [`abort`][`Self::abort`] with an empty `reason`. Any data queued for
+    /// sending on the association is discarded and the peer observes an
+    /// [`AssociationChange`][`crate::Notification::AssociationChange`] with the
+    /// [`CommLost`][`crate::AssocChangeState::CommLost`] state.
+    pub async fn abort_association(&self, assoc_id: AssociationId) -> std::io::Result<()> {
+        self.abort(assoc_id, &[]).await
+    }
+
+    /// Get Default `SendInfo` values for the association identified by the `assoc_id`.
+    ///
+    /// For One to Many style sockets, the default `SendInfo` can be set per association, the
+    /// `assoc_id` of `0` returns the default used for the future associations.
+    pub fn sctp_get_default_sendinfo(&self, assoc_id: AssociationId) -> std::io::Result<SendInfo> {
+        sctp_get_default_sendinfo_internal(&self.inner, assoc_id)
+    }
+
     /// Subscribe to a given SCTP Event on the given socket. See section 6.2.1 of RFC6458.
     ///
     /// SCTP allows receiving notifications about the changes to SCTP associations etc from the
@@ -112,27 +369,50 @@ impl Listener {
     /// SCTP allows receiving notifications about the changes to SCTP associations etc from the
     /// user space. For these notification events to be received, this API is used to subsribe for
     /// the events while receiving the data on the SCTP Socket.
+    ///
+    /// On the kernels that do not support the `SCTP_EVENT` socket option, the legacy
+    /// `SCTP_EVENTS` option is used. It applies to all the associations on the socket, hence only
+    /// [`Future`][`SubscribeEventAssocId::Future`] and [`All`][`SubscribeEventAssocId::All`] are
+    /// supported as the `assoc_id` and the others fail with `EOPNOTSUPP`. The events newer than
+    /// the kernel (eg. [`SenderDry`][`Event::SenderDry`]) also fail with `EOPNOTSUPP`.
     pub fn sctp_subscribe_events(
         &self,
         events: &[Event],
         assoc_id: SubscribeEventAssocId,
     ) -> std::io::Result<()> {
-        let mut failures = vec![];
-        for ev in events {
-            let result = sctp_subscribe_event_internal(&self.inner, ev.clone(), assoc_id, true);
-            if result.is_err() {
-                failures.push(result.err().unwrap());
-            }
-        }
+        subscribe_events_result(sctp_subscribe_events_internal(
+            &self.inner,
+            events,
+            assoc_id,
+            true,
+        ))
+    }
 
-        if failures.is_empty() {
-            Ok(())
-        } else {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("{:?}", failures),
-            ))
-        }
+    /// Subscribe to SCTP Events, reporting the result for each of the events.
+    ///
+    /// Unlike [`sctp_subscribe_events`][`Self::sctp_subscribe_events`], which fails if
+    /// subscription to any of the events fails, this returns the result of subscription for every
+    /// event, in the order of the `events`. This is useful when some of the events may not be
+    /// supported by the running kernel and the application can proceed with the remaining events.
+    pub fn sctp_subscribe_events_checked(
+        &self,
+        events: &[Event],
+        assoc_id: SubscribeEventAssocId,
+    ) -> Vec<(Event, std::io::Result<()>)> {
+        sctp_subscribe_events_internal(&self.inner, events, assoc_id, true)
+    }
+
+    /// Subscribe to all the events in the `mask` (and unsubscribe from the rest) in one call.
+    ///
+    /// This uses the legacy `SCTP_EVENTS` socket option, which sets all the events with a single
+    /// `setsockopt` and applies to the socket as a whole (and all its associations). If the
+    /// running kernel does not support the option (or supports fewer events than this crate),
+    /// the events are subscribed to one by one as in
+    /// [`sctp_subscribe_events`][`Self::sctp_subscribe_events`]. Note: `SCTP_EVENTS` is
+    /// deprecated by RFC 6458, `sctp_subscribe_events` is preferred unless the subscription is
+    /// performed often.
+    pub fn sctp_subscribe_all_events(&self, mask: EventMask) -> std::io::Result<()> {
+        sctp_subscribe_all_events_internal(&self.inner, mask)
     }
 
     /// Unsubscribe from a given SCTP Event on the given socket. See section 6.2.1 of RFC6458.
@@ -143,27 +423,18 @@ impl Listener {
         events: &[Event],
         assoc_id: SubscribeEventAssocId,
     ) -> std::io::Result<()> {
-        let mut failures = vec![];
-        for ev in events {
-            let result = sctp_subscribe_event_internal(&self.inner, ev.clone(), assoc_id, false);
-            if result.is_err() {
-                failures.push(result.err().unwrap());
-            }
-        }
-
-        if failures.is_empty() {
-            Ok(())
-        } else {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("{:?}", failures),
-            ))
-        }
+        subscribe_events_result(sctp_subscribe_events_internal(
+            &self.inner,
+            events,
+            assoc_id,
+            false,
+        ))
     }
 
     /// Setup parameters for a new association.
     ///
     /// To specify custom parameters for a new association this API is used.
+    #[deprecated(since = "0.4.0", note = "use sctp_set_init_params instead.")]
     pub fn sctp_setup_init_params(
         &self,
         ostreams: u16,
@@ -171,38 +442,422 @@ impl Listener {
         retries: u16,
         timeout: u16,
     ) -> std::io::Result<()> {
-        sctp_setup_init_params_internal(&self.inner, ostreams, istreams, retries, timeout)
+        self.sctp_set_init_params(InitParams {
+            ostreams,
+            istreams,
+            retries,
+            timeout,
+        })
+    }
+
+    /// Set parameters for a new association. See Section 8.1.3 RFC 6458.
+    ///
+    /// To specify custom parameters for a new association this API is used.
+    pub fn sctp_set_init_params(&self, init_params: InitParams) -> std::io::Result<()> {
+        sctp_setup_init_params_internal(&self.inner, init_params)
+    }
+
+    /// Get parameters used for a new association. See Section 8.1.3 RFC 6458.
+    ///
+    /// Returns the values set using [`sctp_set_init_params`][`Self::sctp_set_init_params`] or
+    /// the system defaults, if those were never set.
+    pub fn sctp_get_init_params(&self) -> std::io::Result<InitParams> {
+        sctp_get_init_params_internal(&self.inner)
     }
 
     /// Request to receive `RcvInfo` ancillary data.
     ///
     /// SCTP allows receiving ancillary data about the curent data received on the given socket.
     /// This API is used to obtain receive side additional info when the data is to be received.
+    ///
+    /// Note: This is a socket level option (See Section 8.1.29 of RFC 6458), on a One to Many
+    /// socket it applies to all the associations, it cannot be set for a single association.
     pub fn sctp_request_rcvinfo(&self, on: bool) -> std::io::Result<()> {
         request_rcvinfo_internal(&self.inner, on)
     }
 
+    /// Get whether receiving `RcvInfo` ancillary data is requested on the socket.
+    ///
+    /// See [`sctp_request_rcvinfo`][`Self::sctp_request_rcvinfo`].
+    pub fn rcvinfo_requested(&self) -> std::io::Result<bool> {
+        rcvinfo_requested_internal(&self.inner)
+    }
+
     /// Request to receive `NxtInfo` ancillary data.
     ///
     /// SCTP allows receiving ancillary data about the curent data received on the given socket.
     /// This API is used to obtain information about the next datagram that will be received.
+    ///
+    /// Note: This is a socket level option (See Section 8.1.30 of RFC 6458), on a One to Many
+    /// socket it applies to all the associations, it cannot be set for a single association.
     pub fn sctp_request_nxtinfo(&self, on: bool) -> std::io::Result<()> {
         request_nxtinfo_internal(&self.inner, on)
     }
 
+    /// Get whether receiving `NxtInfo` ancillary data is requested on the socket.
+    ///
+    /// See [`sctp_request_nxtinfo`][`Self::sctp_request_nxtinfo`].
+    pub fn nxtinfo_requested(&self) -> std::io::Result<bool> {
+        nxtinfo_requested_internal(&self.inner)
+    }
+
     /// Get the status of the connection associated with the association ID.
     pub fn sctp_get_status(&self, assoc_id: AssociationId) -> std::io::Result<ConnStatus> {
         sctp_get_status_internal(&self.inner, assoc_id)
     }
 
+    /// Get the information about a Peer Address of the association. See Section 8.2.2 of RFC
+    /// 6458.
+    ///
+    /// Returns the state, the congestion window, the smoothed RTT, the RTO and the path MTU of
+    /// the path to the given peer address `addr`.
+    pub fn sctp_get_peer_addr_info(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+    ) -> std::io::Result<PeerAddress> {
+        sctp_get_peer_addr_info_internal(&self.inner, assoc_id, addr)
+    }
+
+    /// Send a heartbeat to the peer address `addr` of the association immediately. See Section
+    /// 8.1.12 of RFC 6458 (`SPP_HB_DEMAND`).
+    ///
+    /// This is useful for checking whether a path is alive, without waiting for the periodic
+    /// heartbeat interval. The result of the check is reflected in the state of the peer address
+    /// (See [`sctp_get_peer_addr_info`][`Self::sctp_get_peer_addr_info`]). If the `addr` is not
+    /// one of the peer addresses of the association, an error with the OS error code `EINVAL` is
+    /// returned.
+    pub fn request_heartbeat(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+    ) -> std::io::Result<()> {
+        sctp_request_heartbeat_internal(&self.inner, assoc_id, addr)
+    }
+
+    /// Enable or Disable IPv4 mapped IPv6 addresses. See Section 8.1.15 of RFC 6458.
+    ///
+    /// On an IPv6 family socket, by default the addresses of the IPv4 peers are reported as IPv4
+    /// mapped IPv6 addresses (`::ffff:a.b.c.d`). When this is turned off, the addresses of the
+    /// IPv4 peers are reported as [`SocketAddr::V4`] (for example by `accept` or
+    /// `sctp_getpaddrs`).
+    pub fn sctp_set_mapped_v4_addr(&self, on: bool) -> std::io::Result<()> {
+        sctp_set_mapped_v4_addr_internal(&self.inner, on)
+    }
+
+    /// Get whether IPv4 mapped IPv6 addresses are used for the IPv4 peers.
+    ///
+    /// See [`sctp_set_mapped_v4_addr`][`Self::sctp_set_mapped_v4_addr`] for details.
+    pub fn sctp_get_mapped_v4_addr(&self) -> std::io::Result<bool> {
+        sctp_get_mapped_v4_addr_internal(&self.inner)
+    }
+
+    /// Get the number of associations on the socket. See Section 8.2.5 of RFC 6458.
+    ///
+    /// This is valid only for One to Many style sockets.
+    pub fn association_count(&self) -> std::io::Result<u32> {
+        sctp_get_assoc_number_internal(&self.inner)
+    }
+
+    /// Get the Association IDs of all the associations on the socket. See Section 8.2.6 of RFC
+    /// 6458.
+    ///
+    /// This is valid only for One to Many style sockets. This is useful for example, for
+    /// sending data to all the associations or getting the status of each of the associations.
+    pub fn association_ids(&self) -> std::io::Result<Vec<AssociationId>> {
+        sctp_get_assoc_ids_internal(&self.inner)
+    }
+
+    /// Bind the socket to a network device (eg. `eth1`).
+    ///
+    /// The packets are sent and received only through the given device, irrespective of the
+    /// routing. `None` removes the binding. Binding requires the `CAP_NET_RAW` capability, else
+    /// an error of kind [`PermissionDenied`][`std::io::ErrorKind::PermissionDenied`] is returned.
+    pub fn bind_device(&self, device: Option<&str>) -> std::io::Result<()> {
+        bind_device_internal(&self.inner, device)
+    }
+
+    /// Get the network device the socket is bound to, `None` if the socket is not bound to any
+    /// device.
+    pub fn device(&self) -> std::io::Result<Option<String>> {
+        get_device_internal(&self.inner)
+    }
+
+    /// Set the maximum size of the DATA chunks (fragmentation point). See Section 8.1.16 of RFC
+    /// 6458.
+    ///
+    /// User messages larger than `maxseg` are fragmented by SCTP into multiple DATA chunks. An
+    /// `assoc_id` of `0` is used to set the value for the future associations on the socket. A
+    /// `maxseg` of `0` means the fragmentation point is determined by the path MTU. Note: The
+    /// kernel always fragments based on the smaller of `maxseg` and the value derived from the
+    /// current path MTU, so this can only lower the fragmentation point. The path MTU itself is
+    /// controlled by the path MTU discovery, which can be disabled and the path MTU set per peer
+    /// address using the `SCTP_PEER_ADDR_PARAMS` socket option.
+    pub fn set_maxseg(&self, assoc_id: AssociationId, maxseg: u32) -> std::io::Result<()> {
+        sctp_set_maxseg_internal(&self.inner, assoc_id, maxseg)
+    }
+
+    /// Get the maximum size of the DATA chunks (fragmentation point).
+    ///
+    /// See [`set_maxseg`][`Self::set_maxseg`] for details.
+    pub fn get_maxseg(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        sctp_get_maxseg_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the maximum number of packets that can be sent in a single burst. See Section 8.1.24
+    /// of RFC 6458.
+    ///
+    /// This limits the burst of packets sent by the congestion control at any time. An
+    /// `assoc_id` of `0` is used to set the value for the future associations on the socket. A
+    /// `max_burst` of `0` disables the burst limit.
+    pub fn set_max_burst(&self, assoc_id: AssociationId, max_burst: u32) -> std::io::Result<()> {
+        sctp_set_max_burst_internal(&self.inner, assoc_id, max_burst)
+    }
+
+    /// Get the maximum number of packets that can be sent in a single burst.
+    ///
+    /// See [`set_max_burst`][`Self::set_max_burst`] for details.
+    pub fn get_max_burst(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        sctp_get_max_burst_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the delayed SACK timer and frequency. See Section 8.1.19 of RFC 6458.
+    ///
+    /// The `delay_ms` is the time in milliseconds after which a SACK is sent, if a SACK was not
+    /// already sent because `freq` packets were received. An `assoc_id` of `0` is used to set
+    /// the values for the future associations on the socket. A `delay_ms` of `0` disables the
+    /// delayed SACK (every packet is acknowledged immediately), `freq` is ignored in this case.
+    /// Disabling the delayed SACK reduces the latency for the small request/response messages.
+    pub fn set_delayed_sack(
+        &self,
+        assoc_id: AssociationId,
+        delay_ms: u32,
+        freq: u32,
+    ) -> std::io::Result<()> {
+        sctp_set_delayed_sack_internal(&self.inner, assoc_id, delay_ms, freq)
+    }
+
+    /// Get the delayed SACK timer (in milliseconds) and frequency as `(delay_ms, freq)`.
+    ///
+    /// See [`set_delayed_sack`][`Self::set_delayed_sack`] for details.
+    pub fn get_delayed_sack(&self, assoc_id: AssociationId) -> std::io::Result<(u32, u32)> {
+        sctp_get_delayed_sack_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the default context used for the messages sent on the association. See Section
+    /// 8.1.25 of RFC 6458.
+    ///
+    /// The context is an opaque value that is not sent to the peer, but is returned in the
+    /// [`SendFailed`][`crate::SendFailed`] notification for a message that could not be sent.
+    /// This is used for the messages sent without the [`SendInfo`][`crate::SendInfo`]. An
+    /// `assoc_id` of `0` is used to set the value for the future associations on the socket.
+    pub fn set_context(&self, assoc_id: AssociationId, context: u32) -> std::io::Result<()> {
+        sctp_set_context_internal(&self.inner, assoc_id, context)
+    }
+
+    /// Get the default context used for the messages sent on the association.
+    ///
+    /// See [`set_context`][`Self::set_context`] for details.
+    pub fn context(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        sctp_get_context_internal(&self.inner, assoc_id)
+    }
+
+    /// Set a socket option from the raw bytes of the `value`.
+    ///
+    /// This is an escape hatch for the socket options that are not (yet) supported by this
+    /// crate, the `value` should be the in memory representation of the option as expected by
+    /// the kernel (eg. the bytes of a C `struct`). Note: The crate does not validate the `level`,
+    /// the `optname` or the `value`, an incorrect use can leave the socket in a state, that the
+    /// other APIs of this crate do not expect. Prefer the typed APIs, where available.
+    pub fn setsockopt_raw(&self, level: i32, optname: i32, value: &[u8]) -> std::io::Result<()> {
+        setsockopt_raw_internal(&self.inner, level, optname, value)
+    }
+
+    /// Get a socket option as raw bytes in the `buf`.
+    ///
+    /// Returns the number of bytes of the `buf` written by the kernel. Some options require the
+    /// `buf` to be filled in with the input values (eg. an Association ID) before the call. See
+    /// [`setsockopt_raw`][`Self::setsockopt_raw`] for details.
+    pub fn getsockopt_raw(
+        &self,
+        level: i32,
+        optname: i32,
+        buf: &mut [u8],
+    ) -> std::io::Result<usize> {
+        getsockopt_raw_internal(&self.inner, level, optname, buf)
+    }
+
+    /// Set the remote UDP encapsulation port for SCTP over UDP (See RFC 6951).
+    ///
+    /// SCTP packets to the peer are encapsulated in UDP with the destination `port`, which is
+    /// useful for traversing the NATs and the middleboxes that only pass UDP. When `addr` is
+    /// `Some`, the port is set only for the given peer address of the association identified by
+    /// the `assoc_id`, else for the association (an `assoc_id` of `0` is used for the future
+    /// associations). A `port` of `0` disables the encapsulation. For receiving the encapsulated
+    /// packets, the local UDP port should be set using the `net.sctp.udp_port` sysctl. Requires
+    /// Linux 5.11 or later, on older kernels an error of kind
+    /// [`Unsupported`][`std::io::ErrorKind::Unsupported`] is returned.
+    pub fn sctp_set_udp_encaps_port(
+        &self,
+        assoc_id: AssociationId,
+        addr: Option<SocketAddr>,
+        port: u16,
+    ) -> std::io::Result<()> {
+        sctp_set_udp_encaps_port_internal(&self.inner, assoc_id, addr, port)
+    }
+
+    /// Get the remote UDP encapsulation port for SCTP over UDP (See RFC 6951).
+    ///
+    /// See [`sctp_set_udp_encaps_port`][`Self::sctp_set_udp_encaps_port`] for details.
+    pub fn sctp_get_udp_encaps_port(
+        &self,
+        assoc_id: AssociationId,
+        addr: Option<SocketAddr>,
+    ) -> std::io::Result<u16> {
+        sctp_get_udp_encaps_port_internal(&self.inner, assoc_id, addr)
+    }
+
+    /// Set the number of seconds after which an idle association is closed automatically. See
+    /// Section 8.1.8 of RFC 6458.
+    ///
+    /// The idle association is closed using the graceful shutdown. A value of `0` disables the
+    /// automatic close (which is the default). This is valid only for One to Many style sockets,
+    /// for One to One style sockets the Linux kernel returns `EOPNOTSUPP`.
+    pub fn set_autoclose(&self, seconds: u32) -> std::io::Result<()> {
+        sctp_set_autoclose_internal(&self.inner, seconds)
+    }
+
+    /// Get the number of seconds after which an idle association is closed automatically.
+    ///
+    /// See [`set_autoclose`][`Self::set_autoclose`] for details.
+    pub fn autoclose(&self) -> std::io::Result<u32> {
+        sctp_get_autoclose_internal(&self.inner)
+    }
+
+    /// Set the IPv4 Type of Service (`IP_TOS`) used for the packets sent on this socket.
+    ///
+    /// This is used for marking the traffic with a DSCP value (eg. `0xb8` for Expedited
+    /// Forwarding). Returns an `InvalidInput` error for an IPv6 socket, use
+    /// [`set_traffic_class`][`Self::set_traffic_class`] instead.
+    pub fn set_tos(&self, tos: u8) -> std::io::Result<()> {
+        set_tos_internal(&self.inner, tos)
+    }
+
+    /// Get the IPv4 Type of Service (`IP_TOS`) used for the packets sent on this socket.
+    pub fn tos(&self) -> std::io::Result<u8> {
+        get_tos_internal(&self.inner)
+    }
+
+    /// Set the IPv6 Traffic Class (`IPV6_TCLASS`) used for the packets sent on this socket.
+    ///
+    /// Returns an `InvalidInput` error for an IPv4 socket, use [`set_tos`][`Self::set_tos`]
+    /// instead.
+    pub fn set_traffic_class(&self, tc: u8) -> std::io::Result<()> {
+        set_traffic_class_internal(&self.inner, tc)
+    }
+
+    /// Get the IPv6 Traffic Class (`IPV6_TCLASS`) used for the packets sent on this socket.
+    pub fn traffic_class(&self) -> std::io::Result<u8> {
+        get_traffic_class_internal(&self.inner)
+    }
+
+    /// Set the `SO_LINGER` option for the socket. See Section 4.1.6 of RFC 6458.
+    ///
+    /// When set to `Some(Duration::ZERO)`, closing the socket sends an `ABORT` to the peer,
+    /// instead of the graceful `SHUTDOWN` sequence, and any data queued for sending is discarded.
+    /// With a non-zero duration, closing the socket blocks the calling thread until the shutdown
+    /// is complete or the duration expires (sub-second durations are rounded up to a second).
+    /// `None` (the default) restores the default behavior, where the socket is closed
+    /// immediately and the graceful shutdown happens in the background. Note: `Drop` closes the
+    /// socket and hence honors the configured linger.
+    pub fn set_linger(&self, linger: Option<std::time::Duration>) -> std::io::Result<()> {
+        set_linger_internal(&self.inner, linger)
+    }
+
+    /// Get the `SO_LINGER` option for the socket.
+    ///
+    /// See [`set_linger`][`Self::set_linger`] for details.
+    pub fn linger(&self) -> std::io::Result<Option<std::time::Duration>> {
+        get_linger_internal(&self.inner)
+    }
+
+    /// Enable or Disable advertising the support for Partial Reliability (PR-SCTP).
+    ///
+    /// The value is used for the associations set up after this call. An `assoc_id` of `0` is
+    /// used to set the value for the future associations on the socket. Setting this on an
+    /// already established association has no effect.
+    pub fn set_pr_supported(&self, assoc_id: AssociationId, on: bool) -> std::io::Result<()> {
+        sctp_set_pr_supported_internal(&self.inner, assoc_id, on)
+    }
+
+    /// Get whether Partial Reliability (PR-SCTP) is supported.
+    ///
+    /// For an established association, this returns whether PR-SCTP was negotiated with the
+    /// peer. Partial Reliability parameters (See [`PrInfo`][`crate::PrInfo`]) are honored only
+    /// if this returns `true`.
+    pub fn pr_supported(&self, assoc_id: AssociationId) -> std::io::Result<bool> {
+        sctp_get_pr_supported_internal(&self.inner, assoc_id)
+    }
+
+    /// Enable or Disable advertising the support for Stream Reconfiguration (RE-CONFIG).
+    ///
+    /// The value is used for the associations set up after this call, the Linux kernel applies
+    /// it to the whole socket irrespective of the `assoc_id` (`0` can be used). Setting this on
+    /// an already established association has no effect. See RFC 6525.
+    pub fn set_reconfig_supported(&self, assoc_id: AssociationId, on: bool) -> std::io::Result<()> {
+        sctp_set_reconfig_supported_internal(&self.inner, assoc_id, on)
+    }
+
+    /// Get whether Stream Reconfiguration (RE-CONFIG) is supported.
+    ///
+    /// For an established association, this returns whether RE-CONFIG was negotiated with the
+    /// peer. If this returns `false`, Stream Reset requests on the association fail with
+    /// `ENOPROTOOPT`.
+    pub fn reconfig_supported(&self, assoc_id: AssociationId) -> std::io::Result<bool> {
+        sctp_get_reconfig_supported_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the default Partial Reliability policy for the association. See Section 4.5 RFC 7496
+    ///
+    /// The policy is used for the sent messages, that do not specify a
+    /// [`PrInfo`][`crate::PrInfo`] in the [`SendData`][`crate::SendData`]. An `assoc_id` of `0`
+    /// is used to set the value for the future associations on the socket.
+    pub fn set_default_prinfo(
+        &self,
+        assoc_id: AssociationId,
+        pr_info: crate::PrInfo,
+    ) -> std::io::Result<()> {
+        sctp_set_default_prinfo_internal(&self.inner, assoc_id, pr_info)
+    }
+
+    /// Get the default Partial Reliability policy for the association. See Section 4.5 RFC 7496
+    pub fn get_default_prinfo(&self, assoc_id: AssociationId) -> std::io::Result<crate::PrInfo> {
+        sctp_get_default_prinfo_internal(&self.inner, assoc_id)
+    }
+
+    /// Get the number of messages abandoned on the association. See Section 4.7 RFC 7496
+    ///
+    /// The counters are for all the Partial Reliability policies.
+    pub fn pr_status(&self, assoc_id: AssociationId) -> std::io::Result<crate::PrStatus> {
+        sctp_get_pr_status_internal(&self.inner, assoc_id)
+    }
+
     // functions not part of public APIs
     pub(crate) fn from_rawfd(fd: RawFd) -> std::io::Result<Self> {
         Ok(Self {
-            inner: AsyncFd::new(fd)?,
+            inner: RegisteredFd::new(fd)?,
+            pending: Mutex::new(VecDeque::new()),
+            recv_capacity: AtomicUsize::new(RECV_BUFFER_SIZE),
         })
     }
 }
 
+impl std::fmt::Debug for Listener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_socket_internal(&self.inner, "Listener", f)
+    }
+}
+
 impl Drop for Listener {
     // Drop for `Listener`. We close the `inner` RawFd
     fn drop(&mut self) {
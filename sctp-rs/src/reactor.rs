@@ -0,0 +1,259 @@
+//! Readiness handling of the sockets for the supported async runtimes.
+//!
+//! The sockets are registered with the reactor of the async runtime selected using the features,
+//! `tokio` (the default) or `async-io` (for `smol` and `async-std`). If both are enabled, `tokio`
+//! is used. Rest of the implementation only uses the [`RegisteredFd`] and [`timeout`] below and
+//! hence is independent of the runtime. Both the implementations provide the same API.
+
+#[cfg(feature = "tokio")]
+pub(crate) use self::tokio_impl::*;
+
+#[cfg(all(feature = "async-io", not(feature = "tokio")))]
+pub(crate) use self::async_io_impl::*;
+
+#[cfg(feature = "tokio")]
+mod tokio_impl {
+    use std::os::unix::io::{AsRawFd, RawFd};
+    use std::task::{Context, Poll};
+
+    use tokio::io::unix::AsyncFd;
+    use tokio::io::{Interest, Ready};
+
+    // A non-blocking socket registered with the `tokio` reactor.
+    #[derive(Debug)]
+    pub(crate) struct RegisteredFd {
+        inner: AsyncFd<RawFd>,
+    }
+
+    impl RegisteredFd {
+        // Registers the `fd` for the read and write readiness.
+        pub(crate) fn new(fd: RawFd) -> std::io::Result<Self> {
+            Ok(Self {
+                inner: AsyncFd::new(fd)?,
+            })
+        }
+
+        // De-registers the `fd` and returns it. The `fd` is not closed.
+        pub(crate) fn into_inner(self) -> RawFd {
+            self.inner.into_inner()
+        }
+
+        // Waits for the socket to be readable and calls the `op`, till it does not fail with
+        // `EWOULDBLOCK`.
+        pub(crate) async fn read_with<R>(
+            &self,
+            mut op: impl FnMut(RawFd) -> std::io::Result<R>,
+        ) -> std::io::Result<R> {
+            loop {
+                let mut guard = self.inner.readable().await?;
+                match guard.try_io(|inner| op(*inner.get_ref())) {
+                    Ok(result) => return result,
+                    // We got an `EWOULDBLOCK`, readiness is cleared by `try_io`, let's wait.
+                    Err(_would_block) => continue,
+                }
+            }
+        }
+
+        // Waits for the socket to be writable and calls the `op`, till it does not fail with
+        // `EWOULDBLOCK`.
+        pub(crate) async fn write_with<R>(
+            &self,
+            mut op: impl FnMut(RawFd) -> std::io::Result<R>,
+        ) -> std::io::Result<R> {
+            loop {
+                let mut guard = self.inner.writable().await?;
+                match guard.try_io(|inner| op(*inner.get_ref())) {
+                    Ok(result) => return result,
+                    // We got an `EWOULDBLOCK`, readiness is cleared by `try_io`, let's wait.
+                    Err(_would_block) => continue,
+                }
+            }
+        }
+
+        // Calls the `op` once without waiting. The read readiness is cleared if it fails with
+        // `EWOULDBLOCK`.
+        pub(crate) fn try_read_with<R>(
+            &self,
+            op: impl FnOnce(RawFd) -> std::io::Result<R>,
+        ) -> std::io::Result<R> {
+            self.inner.try_io(Interest::READABLE, |inner| op(*inner))
+        }
+
+        // Calls the `op` once without waiting. The write readiness is cleared if it fails with
+        // `EWOULDBLOCK`.
+        pub(crate) fn try_write_with<R>(
+            &self,
+            op: impl FnOnce(RawFd) -> std::io::Result<R>,
+        ) -> std::io::Result<R> {
+            self.inner.try_io(Interest::WRITABLE, |inner| op(*inner))
+        }
+
+        // Returns ready, if the socket is readable, else registers the waker from `cx` to be
+        // woken up, when the socket is readable. The readiness is not cleared (See
+        // `try_read_with`).
+        pub(crate) fn poll_read_ready(&self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            self.inner.poll_read_ready(cx).map_ok(|_| ())
+        }
+
+        // Same as `poll_read_ready`, for the write readiness.
+        pub(crate) fn poll_write_ready(&self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            self.inner.poll_write_ready(cx).map_ok(|_| ())
+        }
+
+        // Waits for the socket to be readable. The readiness is not cleared.
+        pub(crate) async fn readable(&self) -> std::io::Result<()> {
+            self.inner.readable().await.map(|_| ())
+        }
+
+        // Waits for the socket to be writable. The readiness is not cleared.
+        pub(crate) async fn writable(&self) -> std::io::Result<()> {
+            self.inner.writable().await.map(|_| ())
+        }
+
+        // Waits for any of the readiness in the `interest`.
+        pub(crate) async fn ready(&self, interest: Interest) -> std::io::Result<Ready> {
+            Ok(self.inner.ready(interest).await?.ready())
+        }
+    }
+
+    impl AsRawFd for RegisteredFd {
+        fn as_raw_fd(&self) -> RawFd {
+            *self.inner.get_ref()
+        }
+    }
+
+    // Returns the output of the `future`, or `None` if it does not complete within the `duration`.
+    pub(crate) async fn timeout<F: std::future::Future>(
+        duration: std::time::Duration,
+        future: F,
+    ) -> Option<F::Output> {
+        tokio::time::timeout(duration, future).await.ok()
+    }
+}
+
+#[cfg(all(feature = "async-io", not(feature = "tokio")))]
+mod async_io_impl {
+    use std::future::Future;
+    use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, RawFd};
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use async_io::{Async, Timer};
+
+    // The raw `fd` as required by the `Async`. The `fd` is owned (and closed) by the socket types.
+    #[derive(Debug)]
+    struct Fd(RawFd);
+
+    impl AsFd for Fd {
+        fn as_fd(&self) -> BorrowedFd<'_> {
+            // Safety: The `fd` is open as long as the socket type owning it (and hence this `Fd`)
+            // is alive.
+            unsafe { BorrowedFd::borrow_raw(self.0) }
+        }
+    }
+
+    // A non-blocking socket registered with the `async-io` reactor.
+    #[derive(Debug)]
+    pub(crate) struct RegisteredFd {
+        inner: Async<Fd>,
+    }
+
+    impl RegisteredFd {
+        // Registers the `fd` for the read and write readiness.
+        pub(crate) fn new(fd: RawFd) -> std::io::Result<Self> {
+            Ok(Self {
+                inner: Async::new(Fd(fd))?,
+            })
+        }
+
+        // De-registers the `fd` and returns it. The `fd` is not closed.
+        pub(crate) fn into_inner(self) -> RawFd {
+            let fd = self.inner.get_ref().0;
+            // Fails only if de-registering fails, the `fd` is still valid.
+            if let Err(e) = self.inner.into_inner() {
+                log::warn!("Error: '{}' while de-registering the socket.", e);
+            }
+            fd
+        }
+
+        // Waits for the socket to be readable and calls the `op`, till it does not fail with
+        // `EWOULDBLOCK`.
+        pub(crate) async fn read_with<R>(
+            &self,
+            mut op: impl FnMut(RawFd) -> std::io::Result<R>,
+        ) -> std::io::Result<R> {
+            self.inner.read_with(|inner| op(inner.0)).await
+        }
+
+        // Waits for the socket to be writable and calls the `op`, till it does not fail with
+        // `EWOULDBLOCK`.
+        pub(crate) async fn write_with<R>(
+            &self,
+            mut op: impl FnMut(RawFd) -> std::io::Result<R>,
+        ) -> std::io::Result<R> {
+            self.inner.write_with(|inner| op(inner.0)).await
+        }
+
+        // Calls the `op` once without waiting. (`async-io` does not cache the readiness.)
+        pub(crate) fn try_read_with<R>(
+            &self,
+            op: impl FnOnce(RawFd) -> std::io::Result<R>,
+        ) -> std::io::Result<R> {
+            op(self.inner.get_ref().0)
+        }
+
+        // Calls the `op` once without waiting. (`async-io` does not cache the readiness.)
+        pub(crate) fn try_write_with<R>(
+            &self,
+            op: impl FnOnce(RawFd) -> std::io::Result<R>,
+        ) -> std::io::Result<R> {
+            op(self.inner.get_ref().0)
+        }
+
+        // Returns ready, if the socket has become readable since the last call, else registers
+        // the waker from `cx` to be woken up, when the socket is readable.
+        pub(crate) fn poll_read_ready(&self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            self.inner.poll_readable(cx)
+        }
+
+        // Same as `poll_read_ready`, for the write readiness.
+        pub(crate) fn poll_write_ready(&self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            self.inner.poll_writable(cx)
+        }
+
+        // Waits for the socket to be readable.
+        pub(crate) async fn readable(&self) -> std::io::Result<()> {
+            self.inner.readable().await
+        }
+
+        // Waits for the socket to be writable.
+        pub(crate) async fn writable(&self) -> std::io::Result<()> {
+            self.inner.writable().await
+        }
+    }
+
+    impl AsRawFd for RegisteredFd {
+        fn as_raw_fd(&self) -> RawFd {
+            self.inner.get_ref().0
+        }
+    }
+
+    // Returns the output of the `future`, or `None` if it does not complete within the `duration`.
+    pub(crate) async fn timeout<F: Future>(
+        duration: std::time::Duration,
+        future: F,
+    ) -> Option<F::Output> {
+        let mut future = Box::pin(future);
+        let mut timer = Timer::after(duration);
+        std::future::poll_fn(|cx| {
+            if let Poll::Ready(output) = future.as_mut().poll(cx) {
+                Poll::Ready(Some(output))
+            } else if Pin::new(&mut timer).poll(cx).is_ready() {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
@@ -1,17 +1,15 @@
 //! SCTP Socket: An unconnected SCTP Socket
 
 use std::net::SocketAddr;
-use std::os::unix::io::RawFd;
-
-use tokio::io::unix::AsyncFd;
 
 use crate::{
-    AssociationId, BindxFlags, ConnStatus, ConnectedSocket, Event, Listener, SocketToAssociation,
-    SubscribeEventAssocId,
+    AssociationId, BindxFlags, ConnStatus, ConnectedSocket, Event, EventMask, InitParams, Listener,
+    PeerAddress, SocketFlags, SocketToAssociation, SubscribeEventAssocId,
 };
 
 #[allow(unused)]
 use super::internal::*;
+use super::reactor::RegisteredFd;
 
 /// A structure representing an unconnected SCTP Socket.
 ///
@@ -19,7 +17,7 @@ use super::internal::*;
 /// get a [`ConnectedSocket`] (This is like `TCPStream` but since this can have multiple
 /// associations, we are calling it a 'connected' socket).
 pub struct Socket {
-    inner: AsyncFd<RawFd>,
+    inner: RegisteredFd,
 }
 
 impl Socket {
@@ -30,10 +28,24 @@ impl Socket {
     /// [`OneToMany`][`SocketToAssociation::OneToMany`]. The socket created is set to a
     /// non-blocking socket and is registered for polling for read-write events.
     /// For any potentially blocking I/O operations, whether the socket is 'readable' or
-    /// 'writable' is handled internally.
+    /// 'writable' is handled internally. The socket is created with the close-on-exec flag (See
+    /// [`SocketFlags::CLOEXEC`]).
     pub fn new_v4(assoc: SocketToAssociation) -> std::io::Result<Self> {
+        Self::new_v4_with_flags(assoc, SocketFlags::default())
+    }
+
+    /// Create a New IPv4 family socket with the given `flags`.
+    ///
+    /// This is same as [`new_v4`][`Self::new_v4`], except that the `flags` are used instead of
+    /// the default flags. For example, [`SocketFlags::NONE`] creates a socket that is inherited
+    /// by the child processes. The sockets accepted or peeled off from this socket, have the same
+    /// close-on-exec setting.
+    pub fn new_v4_with_flags(
+        assoc: SocketToAssociation,
+        flags: SocketFlags,
+    ) -> std::io::Result<Self> {
         Ok(Self {
-            inner: AsyncFd::new(sctp_socket_internal(libc::AF_INET, assoc)?)?,
+            inner: RegisteredFd::new(sctp_socket_internal(libc::AF_INET, assoc, flags, true)?)?,
         })
     }
 
@@ -44,9 +56,20 @@ impl Socket {
     /// [`SocketToAssociation::OneToMany`]. The socket created is set to a non-blocking
     /// socket and is registered for polling for read-write events. For any potentially blocking
     /// I/O operations, whether the socket is 'readable' or 'writable' is handled internally.
+    /// The socket is created with the close-on-exec flag (See [`SocketFlags::CLOEXEC`]).
     pub fn new_v6(assoc: SocketToAssociation) -> std::io::Result<Self> {
+        Self::new_v6_with_flags(assoc, SocketFlags::default())
+    }
+
+    /// Create a New IPv6 family socket with the given `flags`.
+    ///
+    /// See [`new_v4_with_flags`][`Self::new_v4_with_flags`] for details.
+    pub fn new_v6_with_flags(
+        assoc: SocketToAssociation,
+        flags: SocketFlags,
+    ) -> std::io::Result<Self> {
         Ok(Self {
-            inner: AsyncFd::new(sctp_socket_internal(libc::AF_INET6, assoc)?)?,
+            inner: RegisteredFd::new(sctp_socket_internal(libc::AF_INET6, assoc, flags, true)?)?,
         })
     }
 
@@ -59,6 +82,22 @@ impl Socket {
         self.sctp_bindx(&[addr], BindxFlags::Add)
     }
 
+    /// Get's the Local Addresses for the association. See: Section 9.4 RFC 6458
+    ///
+    /// For an unconnected socket, an `assoc_id` of `0` returns the addresses the socket is bound
+    /// to. See also [`bound_addrs`][`Self::bound_addrs`].
+    pub fn sctp_getladdrs(&self, assoc_id: AssociationId) -> std::io::Result<Vec<SocketAddr>> {
+        sctp_getladdrs_internal(&self.inner, assoc_id)
+    }
+
+    /// Get the addresses the socket is bound to.
+    ///
+    /// This is useful for finding out the port assigned by the kernel, when bound to the port
+    /// `0`, without having to `listen` on the socket first.
+    pub fn bound_addrs(&self) -> std::io::Result<Vec<SocketAddr>> {
+        self.sctp_getladdrs(0.into())
+    }
+
     /// Listen on a given socket.
     ///
     /// This successful operation  returns [`Listener`] consuming this structure. The `backlog`
@@ -74,7 +113,22 @@ impl Socket {
         self,
         addr: SocketAddr,
     ) -> std::io::Result<(ConnectedSocket, AssociationId)> {
-        sctp_connectx_internal(self.inner, &[addr]).await
+        sctp_connectx_internal(self.inner, &[addr], None).await
+    }
+
+    /// Connect to a (multi-homed) Peer, failing if the connection is not setup within the
+    /// `timeout`.
+    ///
+    /// If the association is not setup within the `timeout`, the association is aborted, the
+    /// socket is closed and an error with the OS error code `ETIMEDOUT` is returned. Note: One to
+    /// Many sockets are ready for sending right away and hence the `timeout` applies only to One
+    /// to One sockets. See [`sctp_connectx`][`Self::sctp_connectx`] for details.
+    pub async fn connect_timeout(
+        self,
+        addrs: &[SocketAddr],
+        timeout: std::time::Duration,
+    ) -> std::io::Result<(ConnectedSocket, AssociationId)> {
+        sctp_connectx_internal(self.inner, addrs, Some(timeout)).await
     }
 
     /// SCTP Specific extension for binding to multiple addresses on a given socket. See Section
@@ -95,12 +149,18 @@ impl Socket {
     /// An Unbound socket when connected to a remote end would return a tuple containing a
     /// [connected socket][`ConnectedSocket`] and an [associaton ID][`AssociationId`]. In
     /// the case of One-to-many sockets, this association ID can be used for subscribing to SCTP
-    /// events and requesting additional anciliary control data on the socket.
+    /// events and requesting additional anciliary control data on the socket. In the case of
+    /// One-to-one sockets, the socket has a single association and the association ID passed to
+    /// the APIs is ignored, the returned ID is the one reported by the kernel for the association
+    /// (See [`sctp_get_status`][`ConnectedSocket::sctp_get_status`]).
+    ///
+    /// If the peer refuses or aborts the association during the setup, an error with the OS error
+    /// code `ECONNREFUSED` is returned.
     pub async fn sctp_connectx(
         self,
         addrs: &[SocketAddr],
     ) -> std::io::Result<(ConnectedSocket, AssociationId)> {
-        sctp_connectx_internal(self.inner, addrs).await
+        sctp_connectx_internal(self.inner, addrs, None).await
     }
 
     /// Subscribe to a given SCTP Event on the given socket. See section 6.2.1 of RFC6458.
@@ -134,27 +194,50 @@ impl Socket {
     /// SCTP allows receiving notifications about the changes to SCTP associations etc from the
     /// user space. For these notification events to be received, this API is used to subsribe for
     /// the events while receiving the data on the SCTP Socket.
+    ///
+    /// On the kernels that do not support the `SCTP_EVENT` socket option, the legacy
+    /// `SCTP_EVENTS` option is used. It applies to all the associations on the socket, hence only
+    /// [`Future`][`SubscribeEventAssocId::Future`] and [`All`][`SubscribeEventAssocId::All`] are
+    /// supported as the `assoc_id` and the others fail with `EOPNOTSUPP`. The events newer than
+    /// the kernel (eg. [`SenderDry`][`Event::SenderDry`]) also fail with `EOPNOTSUPP`.
     pub fn sctp_subscribe_events(
         &self,
         events: &[Event],
         assoc_id: SubscribeEventAssocId,
     ) -> std::io::Result<()> {
-        let mut failures = vec![];
-        for ev in events {
-            let result = sctp_subscribe_event_internal(&self.inner, ev.clone(), assoc_id, true);
-            if result.is_err() {
-                failures.push(result.err().unwrap());
-            }
-        }
-
-        if failures.is_empty() {
-            Ok(())
-        } else {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("{:?}", failures),
-            ))
-        }
+        subscribe_events_result(sctp_subscribe_events_internal(
+            &self.inner,
+            events,
+            assoc_id,
+            true,
+        ))
+    }
+
+    /// Subscribe to SCTP Events, reporting the result for each of the events.
+    ///
+    /// Unlike [`sctp_subscribe_events`][`Self::sctp_subscribe_events`], which fails if
+    /// subscription to any of the events fails, this returns the result of subscription for every
+    /// event, in the order of the `events`. This is useful when some of the events may not be
+    /// supported by the running kernel and the application can proceed with the remaining events.
+    pub fn sctp_subscribe_events_checked(
+        &self,
+        events: &[Event],
+        assoc_id: SubscribeEventAssocId,
+    ) -> Vec<(Event, std::io::Result<()>)> {
+        sctp_subscribe_events_internal(&self.inner, events, assoc_id, true)
+    }
+
+    /// Subscribe to all the events in the `mask` (and unsubscribe from the rest) in one call.
+    ///
+    /// This uses the legacy `SCTP_EVENTS` socket option, which sets all the events with a single
+    /// `setsockopt` and applies to the socket as a whole (and all its associations). If the
+    /// running kernel does not support the option (or supports fewer events than this crate),
+    /// the events are subscribed to one by one as in
+    /// [`sctp_subscribe_events`][`Self::sctp_subscribe_events`]. Note: `SCTP_EVENTS` is
+    /// deprecated by RFC 6458, `sctp_subscribe_events` is preferred unless the subscription is
+    /// performed often.
+    pub fn sctp_subscribe_all_events(&self, mask: EventMask) -> std::io::Result<()> {
+        sctp_subscribe_all_events_internal(&self.inner, mask)
     }
 
     /// Unsubscribe from a given SCTP Event on the given socket. See section 6.2.1 of RFC6458.
@@ -165,27 +248,18 @@ impl Socket {
         events: &[Event],
         assoc_id: SubscribeEventAssocId,
     ) -> std::io::Result<()> {
-        let mut failures = vec![];
-        for ev in events {
-            let result = sctp_subscribe_event_internal(&self.inner, ev.clone(), assoc_id, false);
-            if result.is_err() {
-                failures.push(result.err().unwrap());
-            }
-        }
-
-        if failures.is_empty() {
-            Ok(())
-        } else {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("{:?}", failures),
-            ))
-        }
+        subscribe_events_result(sctp_subscribe_events_internal(
+            &self.inner,
+            events,
+            assoc_id,
+            false,
+        ))
     }
 
     /// Setup parameters for a new association.
     ///
     /// To specify custom parameters for a new association this API is used.
+    #[deprecated(since = "0.4.0", note = "use sctp_set_init_params instead.")]
     pub fn sctp_setup_init_params(
         &self,
         ostreams: u16,
@@ -193,27 +267,418 @@ impl Socket {
         retries: u16,
         timeout: u16,
     ) -> std::io::Result<()> {
-        sctp_setup_init_params_internal(&self.inner, ostreams, istreams, retries, timeout)
+        self.sctp_set_init_params(InitParams {
+            ostreams,
+            istreams,
+            retries,
+            timeout,
+        })
+    }
+
+    /// Set parameters for a new association. See Section 8.1.3 RFC 6458.
+    ///
+    /// To specify custom parameters for a new association this API is used.
+    pub fn sctp_set_init_params(&self, init_params: InitParams) -> std::io::Result<()> {
+        sctp_setup_init_params_internal(&self.inner, init_params)
+    }
+
+    /// Get parameters used for a new association. See Section 8.1.3 RFC 6458.
+    ///
+    /// Returns the values set using [`sctp_set_init_params`][`Self::sctp_set_init_params`] or
+    /// the system defaults, if those were never set.
+    pub fn sctp_get_init_params(&self) -> std::io::Result<InitParams> {
+        sctp_get_init_params_internal(&self.inner)
     }
 
     /// Request to receive `RcvInfo` ancillary data.
     ///
     /// SCTP allows receiving ancillary data about the curent data received on the given socket.
     /// This API is used to obtain receive side additional info when the data is to be received.
+    ///
+    /// Note: This is a socket level option (See Section 8.1.29 of RFC 6458), on a One to Many
+    /// socket it applies to all the associations, it cannot be set for a single association.
     pub fn sctp_request_rcvinfo(&self, on: bool) -> std::io::Result<()> {
         request_rcvinfo_internal(&self.inner, on)
     }
 
+    /// Get whether receiving `RcvInfo` ancillary data is requested on the socket.
+    ///
+    /// See [`sctp_request_rcvinfo`][`Self::sctp_request_rcvinfo`].
+    pub fn rcvinfo_requested(&self) -> std::io::Result<bool> {
+        rcvinfo_requested_internal(&self.inner)
+    }
+
     /// Request to receive `NxtInfo` ancillary data.
     ///
     /// SCTP allows receiving ancillary data about the curent data received on the given socket.
     /// This API is used to obtain information about the next datagram that will be received.
+    ///
+    /// Note: This is a socket level option (See Section 8.1.30 of RFC 6458), on a One to Many
+    /// socket it applies to all the associations, it cannot be set for a single association.
     pub fn sctp_request_nxtinfo(&self, on: bool) -> std::io::Result<()> {
         request_nxtinfo_internal(&self.inner, on)
     }
 
+    /// Get whether receiving `NxtInfo` ancillary data is requested on the socket.
+    ///
+    /// See [`sctp_request_nxtinfo`][`Self::sctp_request_nxtinfo`].
+    pub fn nxtinfo_requested(&self) -> std::io::Result<bool> {
+        nxtinfo_requested_internal(&self.inner)
+    }
+
     /// Get the status of the connection associated with the association ID.
     pub fn sctp_get_status(&self, assoc_id: AssociationId) -> std::io::Result<ConnStatus> {
         sctp_get_status_internal(&self.inner, assoc_id)
     }
+
+    /// Get the information about a Peer Address of the association. See Section 8.2.2 of RFC
+    /// 6458.
+    ///
+    /// Returns the state, the congestion window, the smoothed RTT, the RTO and the path MTU of
+    /// the path to the given peer address `addr`.
+    pub fn sctp_get_peer_addr_info(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+    ) -> std::io::Result<PeerAddress> {
+        sctp_get_peer_addr_info_internal(&self.inner, assoc_id, addr)
+    }
+
+    /// Enable or Disable IPv4 mapped IPv6 addresses. See Section 8.1.15 of RFC 6458.
+    ///
+    /// On an IPv6 family socket, by default the addresses of the IPv4 peers are reported as IPv4
+    /// mapped IPv6 addresses (`::ffff:a.b.c.d`). When this is turned off, the addresses of the
+    /// IPv4 peers are reported as [`SocketAddr::V4`] (for example by `accept` or
+    /// `sctp_getpaddrs`).
+    pub fn sctp_set_mapped_v4_addr(&self, on: bool) -> std::io::Result<()> {
+        sctp_set_mapped_v4_addr_internal(&self.inner, on)
+    }
+
+    /// Get whether IPv4 mapped IPv6 addresses are used for the IPv4 peers.
+    ///
+    /// See [`sctp_set_mapped_v4_addr`][`Self::sctp_set_mapped_v4_addr`] for details.
+    pub fn sctp_get_mapped_v4_addr(&self) -> std::io::Result<bool> {
+        sctp_get_mapped_v4_addr_internal(&self.inner)
+    }
+
+    /// Set the number of seconds after which an idle association is closed automatically. See
+    /// Section 8.1.8 of RFC 6458.
+    ///
+    /// The idle association is closed using the graceful shutdown. A value of `0` disables the
+    /// automatic close (which is the default). This is valid only for One to Many style sockets,
+    /// for One to One style sockets the Linux kernel returns `EOPNOTSUPP`.
+    pub fn set_autoclose(&self, seconds: u32) -> std::io::Result<()> {
+        sctp_set_autoclose_internal(&self.inner, seconds)
+    }
+
+    /// Get the number of seconds after which an idle association is closed automatically.
+    ///
+    /// See [`set_autoclose`][`Self::set_autoclose`] for details.
+    pub fn autoclose(&self) -> std::io::Result<u32> {
+        sctp_get_autoclose_internal(&self.inner)
+    }
+
+    /// Enable or Disable automatic Address Reconfiguration (ASCONF). See RFC 5061.
+    ///
+    /// When enabled, the local addresses added to (or removed from) the host are added to (or
+    /// removed from) the associations on the socket automatically. This is useful on the
+    /// multi-homed hosts with dynamic addresses. The Linux kernel allows enabling this only when
+    /// the socket is bound to the wildcard address, else `EINVAL` is returned. Note: The
+    /// addresses are actually reconfigured only when `net.sctp.addip_enable` is on.
+    pub fn set_auto_asconf(&self, on: bool) -> std::io::Result<()> {
+        sctp_set_auto_asconf_internal(&self.inner, on)
+    }
+
+    /// Get whether automatic Address Reconfiguration (ASCONF) is enabled.
+    ///
+    /// This always returns `false` if the socket is not bound to the wildcard address.
+    pub fn auto_asconf(&self) -> std::io::Result<bool> {
+        sctp_get_auto_asconf_internal(&self.inner)
+    }
+
+    /// Enable or Disable advertising the support for Partial Reliability (PR-SCTP).
+    ///
+    /// The value is used for the associations set up after this call. An `assoc_id` of `0` is
+    /// used to set the value for the future associations on the socket. Setting this on an
+    /// already established association has no effect.
+    pub fn set_pr_supported(&self, assoc_id: AssociationId, on: bool) -> std::io::Result<()> {
+        sctp_set_pr_supported_internal(&self.inner, assoc_id, on)
+    }
+
+    /// Get whether Partial Reliability (PR-SCTP) is supported.
+    ///
+    /// For an established association, this returns whether PR-SCTP was negotiated with the
+    /// peer. Partial Reliability parameters (See [`PrInfo`][`crate::PrInfo`]) are honored only
+    /// if this returns `true`.
+    pub fn pr_supported(&self, assoc_id: AssociationId) -> std::io::Result<bool> {
+        sctp_get_pr_supported_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the default Partial Reliability policy for the socket. See Section 4.5 RFC 7496
+    ///
+    /// The policy is used for the sent messages, that do not specify a
+    /// [`PrInfo`][`crate::PrInfo`] in the [`SendData`][`crate::SendData`]. An `assoc_id` of `0`
+    /// is used to set the value for the future associations on the socket. The policy takes
+    /// effect only if PR-SCTP is negotiated with the peer (See
+    /// [`pr_supported`][`Self::pr_supported`]).
+    pub fn set_default_prinfo(
+        &self,
+        assoc_id: AssociationId,
+        pr_info: crate::PrInfo,
+    ) -> std::io::Result<()> {
+        sctp_set_default_prinfo_internal(&self.inner, assoc_id, pr_info)
+    }
+
+    /// Get the default Partial Reliability policy for the socket. See Section 4.5 RFC 7496
+    pub fn get_default_prinfo(&self, assoc_id: AssociationId) -> std::io::Result<crate::PrInfo> {
+        sctp_get_default_prinfo_internal(&self.inner, assoc_id)
+    }
+
+    /// Enable or Disable Explicit Congestion Notification (ECN) for the new associations.
+    ///
+    /// The value is used for the associations set up after this call. If the kernel does not
+    /// support the `SCTP_ECN_SUPPORTED` socket option (kernels older than 5.8), an error of kind
+    /// [`Unsupported`][`std::io::ErrorKind::Unsupported`] is returned, so that the callers can
+    /// fall back gracefully.
+    pub fn sctp_set_ecn(&self, on: bool) -> std::io::Result<()> {
+        sctp_set_ecn_internal(&self.inner, on)
+    }
+
+    /// Get whether Explicit Congestion Notification (ECN) will be advertised for the new
+    /// associations.
+    ///
+    /// See [`sctp_set_ecn`][`Self::sctp_set_ecn`] for details about the errors.
+    pub fn sctp_get_ecn(&self) -> std::io::Result<bool> {
+        sctp_get_ecn_internal(&self.inner)
+    }
+
+    /// Enable or Disable advertising the support for Stream Reconfiguration (RE-CONFIG).
+    ///
+    /// The value is used for the associations set up after this call and overrides the system
+    /// wide `net.sctp.reconf_enable` setting for this socket. This is useful with peers that do
+    /// not handle the RE-CONFIG extension correctly. The Linux kernel applies it to the whole
+    /// socket irrespective of the `assoc_id` (`0` can be used). See RFC 6525.
+    pub fn set_reconfig_supported(&self, assoc_id: AssociationId, on: bool) -> std::io::Result<()> {
+        sctp_set_reconfig_supported_internal(&self.inner, assoc_id, on)
+    }
+
+    /// Get whether Stream Reconfiguration (RE-CONFIG) will be advertised for the new
+    /// associations.
+    pub fn reconfig_supported(&self, assoc_id: AssociationId) -> std::io::Result<bool> {
+        sctp_get_reconfig_supported_internal(&self.inner, assoc_id)
+    }
+
+    /// Bind the socket to a network device (eg. `eth1`).
+    ///
+    /// The packets are sent and received only through the given device, irrespective of the
+    /// routing. `None` removes the binding. Binding requires the `CAP_NET_RAW` capability, else
+    /// an error of kind [`PermissionDenied`][`std::io::ErrorKind::PermissionDenied`] is returned.
+    pub fn bind_device(&self, device: Option<&str>) -> std::io::Result<()> {
+        bind_device_internal(&self.inner, device)
+    }
+
+    /// Get the network device the socket is bound to, `None` if the socket is not bound to any
+    /// device.
+    pub fn device(&self) -> std::io::Result<Option<String>> {
+        get_device_internal(&self.inner)
+    }
+
+    /// Set the maximum size of the DATA chunks (fragmentation point). See Section 8.1.16 of RFC
+    /// 6458.
+    ///
+    /// User messages larger than `maxseg` are fragmented by SCTP into multiple DATA chunks. An
+    /// `assoc_id` of `0` is used to set the value for the future associations on the socket. A
+    /// `maxseg` of `0` means the fragmentation point is determined by the path MTU. Note: The
+    /// kernel always fragments based on the smaller of `maxseg` and the value derived from the
+    /// current path MTU, so this can only lower the fragmentation point. The path MTU itself is
+    /// controlled by the path MTU discovery, which can be disabled and the path MTU set per peer
+    /// address using the `SCTP_PEER_ADDR_PARAMS` socket option.
+    pub fn set_maxseg(&self, assoc_id: AssociationId, maxseg: u32) -> std::io::Result<()> {
+        sctp_set_maxseg_internal(&self.inner, assoc_id, maxseg)
+    }
+
+    /// Get the maximum size of the DATA chunks (fragmentation point).
+    ///
+    /// See [`set_maxseg`][`Self::set_maxseg`] for details.
+    pub fn get_maxseg(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        sctp_get_maxseg_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the maximum number of packets that can be sent in a single burst. See Section 8.1.24
+    /// of RFC 6458.
+    ///
+    /// This limits the burst of packets sent by the congestion control at any time. An
+    /// `assoc_id` of `0` is used to set the value for the future associations on the socket. A
+    /// `max_burst` of `0` disables the burst limit.
+    pub fn set_max_burst(&self, assoc_id: AssociationId, max_burst: u32) -> std::io::Result<()> {
+        sctp_set_max_burst_internal(&self.inner, assoc_id, max_burst)
+    }
+
+    /// Get the maximum number of packets that can be sent in a single burst.
+    ///
+    /// See [`set_max_burst`][`Self::set_max_burst`] for details.
+    pub fn get_max_burst(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        sctp_get_max_burst_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the delayed SACK timer and frequency. See Section 8.1.19 of RFC 6458.
+    ///
+    /// The `delay_ms` is the time in milliseconds after which a SACK is sent, if a SACK was not
+    /// already sent because `freq` packets were received. An `assoc_id` of `0` is used to set
+    /// the values for the future associations on the socket. A `delay_ms` of `0` disables the
+    /// delayed SACK (every packet is acknowledged immediately), `freq` is ignored in this case.
+    /// Disabling the delayed SACK reduces the latency for the small request/response messages.
+    pub fn set_delayed_sack(
+        &self,
+        assoc_id: AssociationId,
+        delay_ms: u32,
+        freq: u32,
+    ) -> std::io::Result<()> {
+        sctp_set_delayed_sack_internal(&self.inner, assoc_id, delay_ms, freq)
+    }
+
+    /// Get the delayed SACK timer (in milliseconds) and frequency as `(delay_ms, freq)`.
+    ///
+    /// See [`set_delayed_sack`][`Self::set_delayed_sack`] for details.
+    pub fn get_delayed_sack(&self, assoc_id: AssociationId) -> std::io::Result<(u32, u32)> {
+        sctp_get_delayed_sack_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the default context used for the messages sent on the association. See Section
+    /// 8.1.25 of RFC 6458.
+    ///
+    /// The context is an opaque value that is not sent to the peer, but is returned in the
+    /// [`SendFailed`][`crate::SendFailed`] notification for a message that could not be sent.
+    /// This is used for the messages sent without the [`SendInfo`][`crate::SendInfo`]. An
+    /// `assoc_id` of `0` is used to set the value for the future associations on the socket.
+    pub fn set_context(&self, assoc_id: AssociationId, context: u32) -> std::io::Result<()> {
+        sctp_set_context_internal(&self.inner, assoc_id, context)
+    }
+
+    /// Get the default context used for the messages sent on the association.
+    ///
+    /// See [`set_context`][`Self::set_context`] for details.
+    pub fn context(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        sctp_get_context_internal(&self.inner, assoc_id)
+    }
+
+    /// Set a socket option from the raw bytes of the `value`.
+    ///
+    /// This is an escape hatch for the socket options that are not (yet) supported by this
+    /// crate, the `value` should be the in memory representation of the option as expected by
+    /// the kernel (eg. the bytes of a C `struct`). Note: The crate does not validate the `level`,
+    /// the `optname` or the `value`, an incorrect use can leave the socket in a state, that the
+    /// other APIs of this crate do not expect. Prefer the typed APIs, where available.
+    pub fn setsockopt_raw(&self, level: i32, optname: i32, value: &[u8]) -> std::io::Result<()> {
+        setsockopt_raw_internal(&self.inner, level, optname, value)
+    }
+
+    /// Get a socket option as raw bytes in the `buf`.
+    ///
+    /// Returns the number of bytes of the `buf` written by the kernel. Some options require the
+    /// `buf` to be filled in with the input values (eg. an Association ID) before the call. See
+    /// [`setsockopt_raw`][`Self::setsockopt_raw`] for details.
+    pub fn getsockopt_raw(
+        &self,
+        level: i32,
+        optname: i32,
+        buf: &mut [u8],
+    ) -> std::io::Result<usize> {
+        getsockopt_raw_internal(&self.inner, level, optname, buf)
+    }
+
+    /// Set the remote UDP encapsulation port for SCTP over UDP (See RFC 6951).
+    ///
+    /// SCTP packets to the peer are encapsulated in UDP with the destination `port`, which is
+    /// useful for traversing the NATs and the middleboxes that only pass UDP. When `addr` is
+    /// `Some`, the port is set only for the given peer address of the association identified by
+    /// the `assoc_id`, else for the association (an `assoc_id` of `0` is used for the future
+    /// associations). A `port` of `0` disables the encapsulation. For receiving the encapsulated
+    /// packets, the local UDP port should be set using the `net.sctp.udp_port` sysctl. Requires
+    /// Linux 5.11 or later, on older kernels an error of kind
+    /// [`Unsupported`][`std::io::ErrorKind::Unsupported`] is returned.
+    pub fn sctp_set_udp_encaps_port(
+        &self,
+        assoc_id: AssociationId,
+        addr: Option<SocketAddr>,
+        port: u16,
+    ) -> std::io::Result<()> {
+        sctp_set_udp_encaps_port_internal(&self.inner, assoc_id, addr, port)
+    }
+
+    /// Get the remote UDP encapsulation port for SCTP over UDP (See RFC 6951).
+    ///
+    /// See [`sctp_set_udp_encaps_port`][`Self::sctp_set_udp_encaps_port`] for details.
+    pub fn sctp_get_udp_encaps_port(
+        &self,
+        assoc_id: AssociationId,
+        addr: Option<SocketAddr>,
+    ) -> std::io::Result<u16> {
+        sctp_get_udp_encaps_port_internal(&self.inner, assoc_id, addr)
+    }
+
+    /// Set the IPv4 Type of Service (`IP_TOS`) used for the packets sent on this socket.
+    ///
+    /// This is used for marking the traffic with a DSCP value (eg. `0xb8` for Expedited
+    /// Forwarding). Returns an `InvalidInput` error for an IPv6 socket, use
+    /// [`set_traffic_class`][`Self::set_traffic_class`] instead.
+    pub fn set_tos(&self, tos: u8) -> std::io::Result<()> {
+        set_tos_internal(&self.inner, tos)
+    }
+
+    /// Get the IPv4 Type of Service (`IP_TOS`) used for the packets sent on this socket.
+    pub fn tos(&self) -> std::io::Result<u8> {
+        get_tos_internal(&self.inner)
+    }
+
+    /// Set the IPv6 Traffic Class (`IPV6_TCLASS`) used for the packets sent on this socket.
+    ///
+    /// Returns an `InvalidInput` error for an IPv4 socket, use [`set_tos`][`Self::set_tos`]
+    /// instead.
+    pub fn set_traffic_class(&self, tc: u8) -> std::io::Result<()> {
+        set_traffic_class_internal(&self.inner, tc)
+    }
+
+    /// Get the IPv6 Traffic Class (`IPV6_TCLASS`) used for the packets sent on this socket.
+    pub fn traffic_class(&self) -> std::io::Result<u8> {
+        get_traffic_class_internal(&self.inner)
+    }
+
+    /// Set the `SO_LINGER` option for the socket. See Section 4.1.6 of RFC 6458.
+    ///
+    /// The option is retained by the [`ConnectedSocket`] or the [`Listener`] created from this
+    /// socket. See [`ConnectedSocket::set_linger`] for details.
+    pub fn set_linger(&self, linger: Option<std::time::Duration>) -> std::io::Result<()> {
+        set_linger_internal(&self.inner, linger)
+    }
+
+    /// Get the `SO_LINGER` option for the socket.
+    ///
+    /// See [`set_linger`][`Self::set_linger`] for details.
+    pub fn linger(&self) -> std::io::Result<Option<std::time::Duration>> {
+        get_linger_internal(&self.inner)
+    }
+
+    /// Restrict an IPv6 socket to IPv6 addresses only.
+    ///
+    /// By default an IPv6 socket (See [`new_v6`][`Self::new_v6`]) can be used with both IPv4 and
+    /// IPv6 addresses. This is required for example, when separate IPv4 and IPv6 sockets need to
+    /// be bound to the same port. This should be called before the socket is bound. Returns
+    /// `EINVAL` for an IPv4 socket.
+    pub fn set_v6_only(&self, on: bool) -> std::io::Result<()> {
+        set_v6_only_internal(&self.inner, on)
+    }
+
+    /// Get whether an IPv6 socket is restricted to IPv6 addresses only.
+    ///
+    /// Returns `EINVAL` for an IPv4 socket.
+    pub fn v6_only(&self) -> std::io::Result<bool> {
+        get_v6_only_internal(&self.inner)
+    }
+}
+
+impl std::fmt::Debug for Socket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_socket_internal(&self.inner, "Socket", f)
+    }
 }
@@ -0,0 +1,56 @@
+//! Owned Read and Write halves of a [`ConnectedSocket`].
+//!
+//! See [`ConnectedSocket::into_split`] for details.
+
+use std::sync::Arc;
+
+use crate::{ConnectedSocket, NotificationOrData, SendData, SendInfo};
+
+/// Owned Read half of a [`ConnectedSocket`] created by
+/// [`into_split`][`ConnectedSocket::into_split`].
+#[derive(Debug)]
+pub struct OwnedReadHalf {
+    inner: Arc<ConnectedSocket>,
+}
+
+/// Owned Write half of a [`ConnectedSocket`] created by
+/// [`into_split`][`ConnectedSocket::into_split`].
+#[derive(Debug)]
+pub struct OwnedWriteHalf {
+    inner: Arc<ConnectedSocket>,
+}
+
+pub(crate) fn split_owned(socket: ConnectedSocket) -> (OwnedReadHalf, OwnedWriteHalf) {
+    let inner = Arc::new(socket);
+    (
+        OwnedReadHalf {
+            inner: inner.clone(),
+        },
+        OwnedWriteHalf { inner },
+    )
+}
+
+impl OwnedReadHalf {
+    /// Receive Data or Notification from the connected socket.
+    ///
+    /// See [`ConnectedSocket::sctp_recv`] for details.
+    pub async fn sctp_recv(&self) -> std::io::Result<NotificationOrData> {
+        self.inner.sctp_recv().await
+    }
+}
+
+impl OwnedWriteHalf {
+    /// Send Data and Anciliary data if any on the connected socket.
+    ///
+    /// See [`ConnectedSocket::sctp_send`] for details.
+    pub async fn sctp_send(&self, data: SendData) -> std::io::Result<usize> {
+        self.inner.sctp_send(data).await
+    }
+
+    /// Set Default `SendInfo` values for the connected socket.
+    ///
+    /// See [`ConnectedSocket::sctp_set_default_sendinfo`] for details.
+    pub fn sctp_set_default_sendinfo(&self, sendinfo: SendInfo) -> std::io::Result<()> {
+        self.inner.sctp_set_default_sendinfo(sendinfo)
+    }
+}
@@ -1,10 +1,36 @@
 //! Types used by the Public APIs
 
 /// SCTP Association ID Type
-pub type AssociationId = i32;
+///
+/// A wrapper around the `i32` association ID (`sctp_assoc_t`) used by the kernel, so that it is
+/// not confused with other integers like the socket's file descriptor. Numeric values can still be
+/// used through the `From` implementations, eg. `listener.sctp_peeloff(0.into())`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct AssociationId(i32);
+
+impl From<i32> for AssociationId {
+    fn from(value: i32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<AssociationId> for i32 {
+    fn from(value: AssociationId) -> Self {
+        value.0
+    }
+}
+
+impl std::fmt::Display for AssociationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
 
 /// Flags used by `sctp_bindx`.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BindxFlags {
     /// Add the addresses passed (corresponding to `SCTP_BINDX_ADD_ADDR`)
     Add,
@@ -15,6 +41,7 @@ pub enum BindxFlags {
 
 /// SocketToAssociation: One-to-Many or One-to-One style Socket
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SocketToAssociation {
     /// One Association per Socket (TCP Style Socket.)
     OneToOne,
@@ -23,14 +50,61 @@ pub enum SocketToAssociation {
     OneToMany,
 }
 
+/// Flags used while creating a [`Socket`][`crate::Socket`].
+///
+/// See [`Socket::new_v4_with_flags`][`crate::Socket::new_v4_with_flags`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SocketFlags(u16);
+
+impl SocketFlags {
+    /// No flags.
+    pub const NONE: Self = Self(0);
+
+    /// Set the close-on-exec flag on the socket (`SOCK_CLOEXEC`). The socket is not inherited by
+    /// the child processes started using `exec`.
+    pub const CLOEXEC: Self = Self(0x0001);
+
+    /// Returns `true` if all the flags in `other` are set.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the raw value of the flags.
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Default for SocketFlags {
+    /// The sockets are created with the [`CLOEXEC`][`Self::CLOEXEC`] flag by default.
+    fn default() -> Self {
+        Self::CLOEXEC
+    }
+}
+
+impl std::fmt::Debug for SocketFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_flags(f, "SocketFlags", self.0, &[(Self::CLOEXEC.0, "CLOEXEC")])
+    }
+}
+
 /// NotificationOrData: A type returned by a `sctp_recv` call.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NotificationOrData {
     /// SCTP Notification received by an `sctp_recv` call.
     Notification(Notification),
 
     /// SCTP Data Received by an `sctp_recv` call.
     Data(ReceivedData),
+
+    /// End of File: The association is closed and no more data will be received.
+    ///
+    /// This is returned when the underlying `recvmsg` returns `0` bytes, that is not a
+    /// notification. SCTP does not deliver empty user messages, this is only returned on the One
+    /// to One sockets after the association is shutdown.
+    Eof,
 }
 
 /// Structure Representing SCTP Received Data.
@@ -38,6 +112,7 @@ pub enum NotificationOrData {
 /// This structure is returned by the `sctp_recv` API call. This contains in addition to 'received'
 /// data, any ancillary data that is received during the underlying system call.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReceivedData {
     /// Received Message Payload.
     pub payload: Vec<u8>,
@@ -47,29 +122,207 @@ pub struct ReceivedData {
 
     /// Optional ancillary information about the next call to `sctp_recv`.
     pub nxt_info: Option<NxtInfo>,
+
+    /// Flags returned by the underlying `recvmsg` call.
+    pub flags: RecvFlags,
+
+    /// Address of the peer, the message is received from.
+    ///
+    /// This is useful with One to Many style sockets to know the peer that sent the message
+    /// (without having to enable [`RcvInfo`] and map the Association IDs). This is filled in for
+    /// One to One style sockets as well, where it is always the address of the (only) peer. This
+    /// is `None` if the address is not reported by the kernel.
+    pub from: Option<std::net::SocketAddr>,
+}
+
+#[cfg(feature = "bytes")]
+impl ReceivedData {
+    /// Take the received payload as [`bytes::Bytes`], leaving the `payload` empty.
+    ///
+    /// The received buffer is handed over to the `Bytes` without copying, the returned `Bytes`
+    /// can then be cheaply cloned and sliced. This is available only with the `bytes` feature.
+    pub fn take_payload(&mut self) -> bytes::Bytes {
+        std::mem::take(&mut self.payload).into()
+    }
+}
+
+/// Flags returned by the `recvmsg` call for the received Data.
+///
+/// The [`EOR`][`Self::EOR`] flag is set when the complete message (or the last part of the
+/// message) is received. [`CTRUNC`][`Self::CTRUNC`] means that some of the ancillary data
+/// (e.g. [`RcvInfo`] or [`NxtInfo`]) was discarded.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecvFlags(u16);
+
+impl RecvFlags {
+    /// End of the message (`MSG_EOR`).
+    pub const EOR: Self = Self(libc::MSG_EOR as u16);
+
+    /// The payload was truncated (`MSG_TRUNC`).
+    pub const TRUNC: Self = Self(libc::MSG_TRUNC as u16);
+
+    /// The ancillary data was truncated (`MSG_CTRUNC`).
+    pub const CTRUNC: Self = Self(libc::MSG_CTRUNC as u16);
+
+    /// Returns `true` if all the flags in `other` are set.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the raw value of the flags.
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    // Only the flags above are retained from the `msg_flags`.
+    pub(crate) fn from_msg_flags(msg_flags: libc::c_int) -> Self {
+        Self((msg_flags & (libc::MSG_EOR | libc::MSG_TRUNC | libc::MSG_CTRUNC)) as u16)
+    }
+}
+
+impl std::fmt::Debug for RecvFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_flags(
+            f,
+            "RecvFlags",
+            self.0,
+            &[
+                (Self::EOR.0, "EOR"),
+                (Self::TRUNC.0, "TRUNC"),
+                (Self::CTRUNC.0, "CTRUNC"),
+            ],
+        )
+    }
+}
+
+/// ReceivedInfo: A type returned by a `sctp_recv_into` call.
+///
+/// Unlike [`NotificationOrData`], the payload of the received data is not a part of this type,
+/// instead it is received in the buffer passed to the `sctp_recv_into` call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReceivedInfo {
+    /// SCTP Notification received by an `sctp_recv_into` call.
+    Notification(Notification),
+
+    /// SCTP Data received by an `sctp_recv_into` call in the passed buffer.
+    Data {
+        /// Optional ancillary information about the received payload.
+        rcv_info: Option<RcvInfo>,
+
+        /// Optional ancillary information about the next call to `sctp_recv`.
+        nxt_info: Option<NxtInfo>,
+
+        /// Flags returned by the underlying `recvmsg` call. When the message is received in
+        /// parts, [`RecvFlags::EOR`] is set only for the last part.
+        flags: RecvFlags,
+
+        /// Address of the peer, the message is received from. See [`ReceivedData::from`].
+        from: Option<std::net::SocketAddr>,
+    },
+
+    /// End of File: The association is closed. See [`NotificationOrData::Eof`].
+    Eof,
 }
 
 /// Structure Represnting Data to be Sent.
 ///
 /// This structure contains actual paylod and optional ancillary data.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SendData {
     /// Received Message Payload.
     pub payload: Vec<u8>,
 
     /// Optional ancillary information used to send the data.
     pub snd_info: Option<SendInfo>,
+
+    /// Optional Partial Reliability policy used to send the data.
+    pub pr_info: Option<PrInfo>,
+
+    /// Optional shared key used to authenticate the data.
+    pub auth_info: Option<AuthInfo>,
+
+    /// Destination addresses of the peer (See Sections 5.3.9 and 5.3.10 of RFC 6458).
+    ///
+    /// Each of the addresses is sent as `SCTP_DSTADDRV4` or `SCTP_DSTADDRV6` ancillary data (only
+    /// the IP address is used). Note: On Linux, these are used as the additional peer addresses
+    /// when an association is set up implicitly by the send, the primary path is not changed and
+    /// these are ignored for an existing association. IPv6 addresses are rejected with
+    /// `InvalidInput` on an IPv4 socket.
+    pub dst_addrs: Vec<std::net::SocketAddr>,
+}
+
+impl SendData {
+    /// Creates new [`SendData`] with the `payload` and without any ancillary data.
+    ///
+    /// The ancillary [`SendInfo`] can be set using the builder methods below, for example,
+    /// `SendData::new(payload).stream(3).ppid(0x42)`.
+    pub fn new(payload: Vec<u8>) -> Self {
+        Self {
+            payload,
+            ..Default::default()
+        }
+    }
+
+    /// Creates new [`SendData`] with the `payload`, that is abandoned if not delivered within the
+    /// `lifetime`.
+    ///
+    /// This uses the Timed Reliability policy ([`PrPolicy::Ttl`]) of PR-SCTP, the `lifetime` is
+    /// sent in milliseconds and is clamped to `u32::MAX` milliseconds. Note: If PR-SCTP is not
+    /// supported by the peer (See [`pr_supported`][`crate::ConnectedSocket::pr_supported`]), the
+    /// lifetime is ignored and the message is sent reliably.
+    pub fn with_lifetime(payload: Vec<u8>, lifetime: std::time::Duration) -> Self {
+        Self {
+            pr_info: Some(PrInfo {
+                policy: PrPolicy::Ttl,
+                value: lifetime.as_millis().min(u128::from(u32::MAX)) as u32,
+            }),
+            ..Self::new(payload)
+        }
+    }
+
+    /// Sets the Stream ID to send the data on.
+    pub fn stream(mut self, sid: u16) -> Self {
+        self.snd_info_mut().sid = sid;
+        self
+    }
+
+    /// Sets the Payload Protocol Identifier to send the data with.
+    pub fn ppid(mut self, ppid: u32) -> Self {
+        self.snd_info_mut().ppid = ppid;
+        self
+    }
+
+    /// Sends the data unordered (sets the `SCTP_UNORDERED` flag).
+    pub fn unordered(mut self) -> Self {
+        self.snd_info_mut().flags |= SendFlags::UNORDERED;
+        self
+    }
+
+    /// Sets the opaque context to send the data with.
+    pub fn context(mut self, context: u32) -> Self {
+        self.snd_info_mut().context = context;
+        self
+    }
+
+    // Returns the `SendInfo`, creating a default one if not present.
+    fn snd_info_mut(&mut self) -> &mut SendInfo {
+        self.snd_info.get_or_insert_with(SendInfo::default)
+    }
 }
 
 /// Structure representing Ancilliary Send Information (See Section 5.3.4 of RFC 6458)
 #[repr(C)]
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SendInfo {
     /// Stream ID of the stream to send the data on.
     pub sid: u16,
 
     /// Flags to be used while sending the data.
-    pub flags: u16,
+    pub flags: SendFlags,
 
     /// Application Protocol ID to be used while sending the data.
     pub ppid: u32,
@@ -81,9 +334,166 @@ pub struct SendInfo {
     pub assoc_id: AssociationId,
 }
 
+/// Flags used in the [`SendInfo`] (See Section 5.3.4 of RFC 6458).
+///
+/// The flags can be combined using `|`, for example, `SendFlags::UNORDERED | SendFlags::EOF`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SendFlags(u16);
+
+impl SendFlags {
+    /// Send the message unordered (`SCTP_UNORDERED`).
+    pub const UNORDERED: Self = Self(crate::consts::SCTP_UNORDERED);
+
+    /// Send the message to the destination address passed, instead of the primary address
+    /// (`SCTP_ADDR_OVER`).
+    pub const ADDR_OVER: Self = Self(crate::consts::SCTP_ADDR_OVER);
+
+    /// Abort the association, the payload is sent as the reason (`SCTP_ABORT`).
+    pub const ABORT: Self = Self(crate::consts::SCTP_ABORT);
+
+    /// Request the peer to acknowledge the message without any delay
+    /// (`SCTP_SACK_IMMEDIATELY`).
+    pub const SACK_IMMEDIATELY: Self = Self(crate::consts::SCTP_SACK_IMMEDIATELY);
+
+    /// Send the message to all the associations of a One to Many socket (`SCTP_SENDALL`).
+    pub const SENDALL: Self = Self(crate::consts::SCTP_SENDALL);
+
+    /// Gracefully shutdown the association after the message is sent (`SCTP_EOF`).
+    pub const EOF: Self = Self(crate::consts::SCTP_EOF);
+
+    /// Returns `true` if all the flags in `other` are set.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the raw value of the flags.
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    pub(crate) fn from_u16(val: u16) -> Self {
+        Self(val)
+    }
+}
+
+impl std::ops::BitOr for SendFlags {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitOrAssign for SendFlags {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+impl std::fmt::Debug for SendFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_flags(
+            f,
+            "SendFlags",
+            self.0,
+            &[
+                (Self::UNORDERED.0, "UNORDERED"),
+                (Self::ADDR_OVER.0, "ADDR_OVER"),
+                (Self::ABORT.0, "ABORT"),
+                (Self::SACK_IMMEDIATELY.0, "SACK_IMMEDIATELY"),
+                (Self::SENDALL.0, "SENDALL"),
+                (Self::EOF.0, "EOF"),
+            ],
+        )
+    }
+}
+
+/// Partial Reliability Policy used by [`PrInfo`] (See RFC 7496)
+#[repr(u16)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PrPolicy {
+    /// Reliable transmission (No Partial Reliability policy).
+    #[default]
+    None = 0x0000,
+
+    /// Timed Reliability: The message is abandoned if it is not transmitted (or retransmitted)
+    /// within the lifetime specified in miliseconds.
+    Ttl = 0x0010,
+
+    /// Limited Retransmissions: The message is abandoned after specified number of
+    /// retransmissions.
+    Rtx = 0x0020,
+
+    /// Priority: The message with a lower priority is abandoned when a message with a higher
+    /// priority is to be sent and the send buffer is full.
+    Prio = 0x0030,
+}
+
+impl PrPolicy {
+    // The policy is in the lower bits of the flags (`SCTP_PR_SCTP_MASK`).
+    pub(crate) fn from_u16(policy: u16) -> Self {
+        match policy & 0x0030 {
+            0x0010 => Self::Ttl,
+            0x0020 => Self::Rtx,
+            0x0030 => Self::Prio,
+            _ => Self::None,
+        }
+    }
+}
+
+/// Structure representing Ancillary Partial Reliability Information (See Section 5.3.7 of RFC
+/// 6458)
+///
+/// Sending a message with `PrInfo` requires the peer to support PR-SCTP (See
+/// [`pr_supported`][`crate::ConnectedSocket::pr_supported`]). When a message is abandoned, a
+/// [`SendFailed`] notification is delivered, if the application has subscribed to the
+/// [`Event::SendFailureEvent`].
+#[repr(C)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrInfo {
+    /// Partial Reliability Policy to be used while sending the data.
+    pub policy: PrPolicy,
+
+    /// Value for the policy: lifetime in miliseconds for [`PrPolicy::Ttl`], number of
+    /// retransmissions for [`PrPolicy::Rtx`] and the priority for [`PrPolicy::Prio`].
+    pub value: u32,
+}
+
+/// Structure representing Ancillary SCTP AUTH Information (See Section 5.3.8 of RFC 6458)
+///
+/// When the data is sent with this information, the shared key with the `key_number` is used to
+/// authenticate the data (if the data is to be authenticated), instead of the active key. The
+/// send fails, if there is no such key.
+#[repr(C)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthInfo {
+    /// Shared key identifier.
+    pub key_number: u16,
+}
+
+/// Partial Reliability status of an association.
+///
+/// Returned by [`pr_status`][`crate::ConnectedSocket::pr_status`], contains the number of
+/// messages abandoned on the association for all the Partial Reliability policies.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrStatus {
+    /// Number of the abandoned messages that were never sent to the peer.
+    pub abandoned_unsent: u64,
+
+    /// Number of the abandoned messages that were sent to the peer at-least once.
+    pub abandoned_sent: u64,
+}
+
 /// Structure Representing Ancillary Receive Information (See Section 5.3.5 of RFC 6458)
 #[repr(C)]
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RcvInfo {
     /// Stream ID on which the data is received.
     pub sid: u16,
@@ -92,7 +502,7 @@ pub struct RcvInfo {
     pub ssn: u16,
 
     /// Flags for the received data.
-    pub flags: u16,
+    pub flags: RcvFlags,
 
     /// Application Protocol ID used by the sender while sending the data.
     pub ppid: u32,
@@ -110,9 +520,43 @@ pub struct RcvInfo {
     pub assoc_id: AssociationId,
 }
 
+/// Flags received in the [`RcvInfo`] (See Section 5.3.5 of RFC 6458).
+///
+/// Not to be confused with the [`RecvFlags`], which are the flags returned by the `recvmsg`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RcvFlags(u16);
+
+impl RcvFlags {
+    /// The message was sent unordered (`SCTP_UNORDERED`).
+    pub const UNORDERED: Self = Self(crate::consts::SCTP_UNORDERED);
+
+    /// Returns `true` if all the flags in `other` are set.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the raw value of the flags.
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    pub(crate) fn from_u16(val: u16) -> Self {
+        Self(val)
+    }
+}
+
+impl std::fmt::Debug for RcvFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_flags(f, "RcvFlags", self.0, &[(Self::UNORDERED.0, "UNORDERED")])
+    }
+}
+
 /// Structure representing Ancillary next information (See Section 5.3.5)
 #[repr(C)]
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NxtInfo {
     /// Stream ID for the next received data.
     pub sid: u16,
@@ -131,15 +575,49 @@ pub struct NxtInfo {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// An `enum` representing the notifications received on the SCTP Sockets.
+///
+/// New notifications may be added in the future, hence the `match`es on this `enum` must include a
+/// wildcard (`_`) arm.
+#[non_exhaustive]
 pub enum Notification {
     /// Association Change Notification. See Section 6.1.1 of RFC 6458.
     AssociationChange(AssociationChange),
 
+    /// Peer Address Change Notification. See Section 6.1.2 of RFC 6458.
+    PeerAddressChange(PeerAddressChange),
+
     /// Shutdown Notification. See Section 6.1.5 of RFC 6458.
     Shutdown(Shutdown),
-    /// A Catchall Notification type for the Notifications that are not supported
-    Unsupported,
+
+    /// Send Failed Notification. See Section 6.1.11 of RFC 6458.
+    SendFailed(SendFailed),
+
+    /// Sender Dry Notification. See Section 6.1.9 of RFC 6458.
+    SenderDry(SenderDry),
+
+    /// Stream Reset Notification. See Section 6.1.1 of RFC 6525.
+    StreamReset(StreamResetEvent),
+
+    /// A Catchall Notification type for the Notifications that are not supported or that are
+    /// malformed (eg. truncated or with an invalid `length`).
+    ///
+    /// The header fields and the raw bytes of the notification are retained, so that the
+    /// applications can log or decode the notification themselves.
+    Unsupported {
+        /// Type of the Notification (`sn_type`) as received from the kernel.
+        ev_type: u16,
+
+        /// Notification Flags.
+        flags: u16,
+
+        /// Length of the notification data.
+        length: u32,
+
+        /// Raw bytes of the notification, including the header.
+        data: Vec<u8>,
+    },
 }
 
 /// AssociationChange: Structure returned as notification for Association Change.
@@ -148,6 +626,7 @@ pub enum Notification {
 /// the [`Event`] type as [`Event::Association`].
 #[repr(C)]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AssociationChange {
     /// Type of the Notification always `SCTP_ASSOC_CHAGE`
     pub ev_type: Event,
@@ -177,14 +656,200 @@ pub struct AssociationChange {
     pub info: Vec<u8>,
 }
 
+impl AssociationChange {
+    /// Returns the features supported by the peer. See Section 6.1.1 of RFC 6458.
+    ///
+    /// For the [`CommUp`][`AssocChangeState::CommUp`] and the
+    /// [`Restart`][`AssocChangeState::Restart`] states, the `info` contains the features
+    /// supported by the peer for the association. For the other states, the `info` does not
+    /// contain the features and an empty `Vec` is returned.
+    pub fn peer_capabilities(&self) -> Vec<PeerCapability> {
+        match self.state {
+            AssocChangeState::CommUp | AssocChangeState::Restart => self
+                .info
+                .iter()
+                .map(|cap| PeerCapability::from_u8(*cap))
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+/// Features supported by the peer of an association.
+///
+/// See [`peer_capabilities`][`AssociationChange::peer_capabilities`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PeerCapability {
+    /// Partial Reliability Extension (RFC 3758) (`SCTP_ASSOC_SUPPORTS_PR`).
+    PartialReliability,
+
+    /// Authenticated Chunks (RFC 4895) (`SCTP_ASSOC_SUPPORTS_AUTH`).
+    Auth,
+
+    /// Dynamic Address Reconfiguration (RFC 5061) (`SCTP_ASSOC_SUPPORTS_ASCONF`).
+    Asconf,
+
+    /// Multiple buffers for a One to Many socket (`SCTP_ASSOC_SUPPORTS_MULTIBUF`).
+    MultiBuf,
+
+    /// Stream Reconfiguration (RFC 6525) (`SCTP_ASSOC_SUPPORTS_RE_CONFIG`).
+    ReConfig,
+
+    /// User Message Interleaving (RFC 8260) (`SCTP_ASSOC_SUPPORTS_INTERLEAVING`).
+    Interleaving,
+
+    /// Unknown Capability: The value received from the kernel.
+    Unknown(u8),
+}
+
+impl PeerCapability {
+    pub(crate) fn from_u8(val: u8) -> Self {
+        match val {
+            0x01 => PeerCapability::PartialReliability,
+            0x02 => PeerCapability::Auth,
+            0x03 => PeerCapability::Asconf,
+            0x04 => PeerCapability::MultiBuf,
+            0x05 => PeerCapability::ReConfig,
+            0x06 => PeerCapability::Interleaving,
+            _ => PeerCapability::Unknown(val),
+        }
+    }
+}
+
+/// PeerAddressChange: Structure returned as notification for Peer Address Change.
+///
+/// To subscribe to this notification type, An application should call `sctp_subscribe_event` using
+/// the [`Event`] type as [`Event::Address`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeerAddressChange {
+    /// Type of the Notification always `SCTP_PEER_ADDR_CHANGE`
+    pub ev_type: Event,
+
+    /// Notification Flags. Unused currently.
+    pub flags: u16,
+
+    /// Length of the notification data.
+    pub length: u32,
+
+    /// Peer Address affected by the event.
+    pub address: std::net::SocketAddr,
+
+    /// New state of the Peer Address. See also [`PeerAddressState`].
+    pub state: PeerAddressState,
+
+    /// Error when state is an error state and error information is available.
+    pub error: i32,
+
+    /// Association ID for the event.
+    pub assoc_id: AssociationId,
+}
+
+/// Peer Address States (See Section 6.1.2 of RFC 6458)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PeerAddressState {
+    /// Address is now reachable.
+    Available,
+
+    /// Address is unreachable.
+    Unreachable,
+
+    /// Address is no longer part of the association.
+    Removed,
+
+    /// Address is now part of the association.
+    Added,
+
+    /// Address is now the primary destination address.
+    MadePrimary,
+
+    /// Address is now confirmed as a valid address.
+    Confirmed,
+
+    /// Address is potentially failed (See RFC 7829).
+    PotentiallyFailed,
+
+    /// Unknown State: The value received from the kernel.
+    Unknown(u32),
+}
+
+impl PeerAddressState {
+    pub(crate) fn from_u32(val: u32) -> Self {
+        match val {
+            0 => PeerAddressState::Available,
+            1 => PeerAddressState::Unreachable,
+            2 => PeerAddressState::Removed,
+            3 => PeerAddressState::Added,
+            4 => PeerAddressState::MadePrimary,
+            5 => PeerAddressState::Confirmed,
+            6 => PeerAddressState::PotentiallyFailed,
+            _ => PeerAddressState::Unknown(val),
+        }
+    }
+}
+
 /// Shutdown: Structure rreturned as notification for Shutdown Event.
 ///
 ///To subscribe to this notification type, An application should call `sctp_subscribe_event` using
 ///the [`Event`] ty[e as [`Event::Shutdown`]
 #[repr(C)]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shutdown {
-    /// Type of the Notification always `SCTP_SHUTDOWN`
+    /// Type of the Notification always `SCTP_SHUTDOWN_EVENT`
+    pub ev_type: Event,
+
+    /// Notification Flags. Unused currently.
+    pub flags: u16,
+
+    /// Length of the notification data.
+    pub length: u32,
+
+    /// Association ID for the event.
+    pub assoc_id: AssociationId,
+}
+
+/// SendFailed: Structure returned as notification for Send Failure Event.
+///
+/// To subscribe to this notification type, An application should call `sctp_subscribe_events`
+/// using the [`Event`] type as [`Event::SendFailureEvent`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SendFailed {
+    /// Type of the Notification always `SCTP_SEND_FAILED_EVENT`
+    pub ev_type: Event,
+
+    /// Notification Flags. Whether the data was sent on the wire or not. See
+    /// [`SendFailedFlags`].
+    pub flags: SendFailedFlags,
+
+    /// Length of the notification data.
+    pub length: u32,
+
+    /// Error code for the failure.
+    pub error: u32,
+
+    /// Ancillary information used while sending the data.
+    pub snd_info: SendInfo,
+
+    /// Association ID for the event.
+    pub assoc_id: AssociationId,
+
+    /// The data that could not be sent.
+    pub data: Vec<u8>,
+}
+
+/// SenderDry: Structure returned as notification for Sender Dry Event.
+///
+/// This notification is received when there is no user data outstanding (unsent or
+/// unacknowledged) for the association. To subscribe to this notification type, An application
+/// should call `sctp_subscribe_events` using the [`Event`] type as [`Event::SenderDry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SenderDry {
+    /// Type of the Notification always `SCTP_SENDER_DRY_EVENT`
     pub ev_type: Event,
 
     /// Notification Flags. Unused currently.
@@ -197,11 +862,150 @@ pub struct Shutdown {
     pub assoc_id: AssociationId,
 }
 
+/// StreamResetEvent: Structure returned as notification for Stream Reset Event.
+///
+/// To subscribe to this notification type, An application should call `sctp_subscribe_events`
+/// using the [`Event`] type as [`Event::StreamReset`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StreamResetEvent {
+    /// Type of the Notification always `SCTP_STREAM_RESET_EVENT`
+    pub ev_type: Event,
+
+    /// Notification Flags. See [`StreamResetFlags`].
+    pub flags: StreamResetFlags,
+
+    /// Length of the notification data.
+    pub length: u32,
+
+    /// Association ID for the event.
+    pub assoc_id: AssociationId,
+
+    /// Stream IDs of the streams that were reset. Empty if all the streams were reset.
+    pub stream_ids: Vec<u16>,
+}
+
+/// Flags received in the [`SendFailed`] notification. See Section 6.1.11 of RFC 6458.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SendFailedFlags(u16);
+
+impl SendFailedFlags {
+    /// The data was never put on the wire (`SCTP_DATA_UNSENT`).
+    pub const UNSENT: Self = Self(0x0001);
+
+    /// The data was put on the wire at least once (`SCTP_DATA_SENT`).
+    pub const SENT: Self = Self(0x0002);
+
+    /// Returns `true` if all the flags in `other` are set.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the raw value of the flags.
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    pub(crate) fn from_u16(val: u16) -> Self {
+        Self(val)
+    }
+}
+
+impl std::fmt::Debug for SendFailedFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_flags(
+            f,
+            "SendFailedFlags",
+            self.0,
+            &[(Self::UNSENT.0, "UNSENT"), (Self::SENT.0, "SENT")],
+        )
+    }
+}
+
+/// Flags received in the [`StreamResetEvent`] notification. See Section 6.1.1 of RFC 6525.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StreamResetFlags(u16);
+
+impl StreamResetFlags {
+    /// Incoming streams were reset.
+    pub const INCOMING: Self = Self(0x0001);
+
+    /// Outgoing streams were reset.
+    pub const OUTGOING: Self = Self(0x0002);
+
+    /// The stream reset request was denied by the peer.
+    pub const DENIED: Self = Self(0x0004);
+
+    /// The stream reset request failed.
+    pub const FAILED: Self = Self(0x0008);
+
+    /// Returns `true` if all the flags in `other` are set.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the raw value of the flags.
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    pub(crate) fn from_u16(val: u16) -> Self {
+        Self(val)
+    }
+}
+
+impl std::fmt::Debug for StreamResetFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_flags(
+            f,
+            "StreamResetFlags",
+            self.0,
+            &[
+                (Self::INCOMING.0, "INCOMING"),
+                (Self::OUTGOING.0, "OUTGOING"),
+                (Self::DENIED.0, "DENIED"),
+                (Self::FAILED.0, "FAILED"),
+            ],
+        )
+    }
+}
+
+// Formats the flags as `Name(FLAG_A | FLAG_B | 0x..)`, naming the `known` flags and showing the
+// remaining bits (if any) as a hex value.
+fn fmt_flags(
+    f: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    bits: u16,
+    known: &[(u16, &str)],
+) -> std::fmt::Result {
+    write!(f, "{}(", name)?;
+    let mut remaining = bits;
+    let mut separator = "";
+    for (flag, flag_name) in known {
+        if bits & flag == *flag {
+            write!(f, "{}{}", separator, flag_name)?;
+            remaining &= !flag;
+            separator = " | ";
+        }
+    }
+    if remaining != 0 {
+        write!(f, "{}{:#06x}", separator, remaining)?;
+    }
+    write!(f, ")")
+}
+
 /// Event: Used for Subscribing for SCTP Events
 ///
 /// See [`sctp_subscribe_events`][`crate::Listener::sctp_subscribe_event`] for the usage.
+///
+/// New events may be added in the future, hence the `match`es on this `enum` must include a
+/// wildcard (`_`) arm.
 #[repr(u16)]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Event {
     /// Event to receive ancillary information with every `sctp_recv`.
     DataIo = (1 << 15),
@@ -251,6 +1055,26 @@ pub enum Event {
 }
 
 impl Event {
+    /// Returns all the events that can be subscribed to (ie. all except [`Event::Unknown`]).
+    pub fn all() -> Vec<Event> {
+        vec![
+            Event::DataIo,
+            Event::Association,
+            Event::Address,
+            Event::SendFailure,
+            Event::PeerError,
+            Event::Shutdown,
+            Event::PartialDelivery,
+            Event::AdaptationLayer,
+            Event::Authentication,
+            Event::SenderDry,
+            Event::StreamReset,
+            Event::AssociationReset,
+            Event::StreamChange,
+            Event::SendFailureEvent,
+        ]
+    }
+
     pub(crate) fn from_u16(val: u16) -> Self {
         match val {
             0x8000 => Event::DataIo,
@@ -272,11 +1096,73 @@ impl Event {
     }
 }
 
+/// A set of [`Event`]s subscribed to in a single call.
+///
+/// See [`sctp_subscribe_all_events`][`crate::Listener::sctp_subscribe_all_events`] for the
+/// usage.
+///
+/// ```
+/// use sctp_rs::{Event, EventMask};
+///
+/// let mask = EventMask::default()
+///     .with(Event::Association, true)
+///     .with(Event::Shutdown, true);
+/// assert!(mask.contains(&Event::Association));
+/// assert!(!EventMask::all().with(Event::DataIo, false).contains(&Event::DataIo));
+/// ```
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventMask(u16);
+
+impl EventMask {
+    /// A mask with all the events that can be subscribed to (See [`Event::all`]).
+    pub fn all() -> Self {
+        Event::all()
+            .into_iter()
+            .fold(Self::default(), |mask, event| mask.with(event, true))
+    }
+
+    /// Returns the mask with the `event` added (`on` is `true`) or removed.
+    ///
+    /// [`Event::Unknown`] cannot be subscribed to and is ignored.
+    pub fn with(self, event: Event, on: bool) -> Self {
+        let bit = Self::bit(&event);
+        if on {
+            Self(self.0 | bit)
+        } else {
+            Self(self.0 & !bit)
+        }
+    }
+
+    /// Returns whether the `event` is in the mask.
+    pub fn contains(&self, event: &Event) -> bool {
+        let bit = Self::bit(event);
+        bit != 0 && self.0 & bit == bit
+    }
+
+    // Every event is a bit in the mask, in the order of the `Event` values.
+    fn bit(event: &Event) -> u16 {
+        match event {
+            Event::Unknown => 0,
+            _ => 1 << (event.clone() as u16 - Event::DataIo as u16),
+        }
+    }
+}
+
+impl std::fmt::Debug for EventMask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set()
+            .entries(Event::all().iter().filter(|event| self.contains(event)))
+            .finish()
+    }
+}
+
 /// SubscribeEventAssocId: AssociationID Used for Event Subscription
 ///
 /// Note: repr should be same as `AssociationId` (ie. `i32`)
 #[repr(i32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SubscribeEventAssocId {
     /// Subscribe to Future Association IDs
     Future,
@@ -294,17 +1180,22 @@ pub enum SubscribeEventAssocId {
 impl From<SubscribeEventAssocId> for AssociationId {
     fn from(value: SubscribeEventAssocId) -> Self {
         match value {
-            SubscribeEventAssocId::Future => 0 as Self,
-            SubscribeEventAssocId::Current => 1 as Self,
-            SubscribeEventAssocId::All => 2 as Self,
+            SubscribeEventAssocId::Future => Self(0),
+            SubscribeEventAssocId::Current => Self(1),
+            SubscribeEventAssocId::All => Self(2),
             SubscribeEventAssocId::Value(v) => v,
         }
     }
 }
 
 /// Association Change States
+///
+/// New states may be added in the future, hence the `match`es on this `enum` must include a
+/// wildcard (`_`) arm.
 #[repr(u16)]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum AssocChangeState {
     /// SCTP communication up.
     CommUp = 0,
@@ -338,9 +1229,51 @@ impl AssocChangeState {
     }
 }
 
+/// Stream Schedulers used for scheduling the outgoing data on the streams of an association.
+///
+/// See RFC 8260 for the details of the schedulers.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StreamScheduler {
+    /// First Come First Served scheduler (Default).
+    Fcfs = 0,
+
+    /// Priority based scheduler. The priority of a stream is set using
+    /// [`sctp_set_stream_priority`][`crate::ConnectedSocket::sctp_set_stream_priority`]. Lower
+    /// value means higher priority.
+    Prio,
+
+    /// Round Robin scheduler.
+    Rr,
+
+    /// Fair Capacity scheduler.
+    Fc,
+
+    /// Weighted Fair Queueing scheduler.
+    Wfq,
+
+    /// Unknown Scheduler: This value indicates an error
+    Unknown,
+}
+
+impl StreamScheduler {
+    pub(crate) fn from_u32(val: u32) -> Self {
+        match val {
+            0 => StreamScheduler::Fcfs,
+            1 => StreamScheduler::Prio,
+            2 => StreamScheduler::Rr,
+            3 => StreamScheduler::Fc,
+            4 => StreamScheduler::Wfq,
+            _ => StreamScheduler::Unknown,
+        }
+    }
+}
+
 /// Constants related to `enum sctp_cmsg_type`
 #[repr(i32)]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CmsgType {
     Init = 0,
     SndRcv,
@@ -354,8 +1287,13 @@ pub enum CmsgType {
 }
 
 /// Constants related to `enum sctp_sstat_state`
+///
+/// New states may be added in the future, hence the `match`es on this `enum` must include a
+/// wildcard (`_`) arm.
 #[repr(i32)]
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum ConnState {
     #[default]
     Empty = 0,
@@ -390,6 +1328,7 @@ impl ConnState {
 
 /// PeerAddress: Structure representing SCTP Peer Address.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PeerAddress {
     pub assoc_id: AssociationId,
     pub address: std::net::SocketAddr,
@@ -400,8 +1339,30 @@ pub struct PeerAddress {
     pub mtu: u32,
 }
 
+/// InitParams: Parameters used for setting up a new association. (See Section 8.1.3 RFC 6458)
+///
+/// A value of `0` for any of the fields means the system default is used for that field.
+#[repr(C)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitParams {
+    /// Number of outbound streams requested (`sinit_num_ostreams`).
+    pub ostreams: u16,
+
+    /// Maximum number of inbound streams supported (`sinit_max_instreams`).
+    pub istreams: u16,
+
+    /// Maximum number of retransmissions of the `INIT` message (`sinit_max_attempts`).
+    pub retries: u16,
+
+    /// Maximum Retransmission Timeout (in miliseconds) for the `INIT` message
+    /// (`sinit_max_init_timeo`).
+    pub timeout: u16,
+}
+
 /// ConnStatus: Status of an SCTP Connection
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConnStatus {
     pub assoc_id: AssociationId,
     pub state: ConnState,
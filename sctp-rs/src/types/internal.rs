@@ -4,7 +4,7 @@
 //! performing certain SCTP related functionality using `libc::getsockopt` or `libc::setsockopt`.
 //! Structures below are used by the implementation details and are not part of the public API.
 
-use crate::{AssociationId, Event};
+use crate::{AssociationId, Event, EventMask, RcvFlags, RcvInfo};
 
 // Structure used by `sctp_peeloff` (Section 9.2)
 #[repr(C)]
@@ -45,14 +45,118 @@ pub(crate) struct SubscribeEvent {
     pub(crate) on: bool,
 }
 
-// SCTP Initiation Structure (See Section 5.3.1 of RFC 6458)
+// Structure used for Subscribing to SCTP Events using the legacy `SCTP_EVENTS` socket option
+// (`struct sctp_event_subscribe`). Used only on the kernels that do not support `SCTP_EVENT`.
 #[repr(C)]
-#[derive(Debug)]
-pub(crate) struct InitMsg {
-    pub(crate) ostreams: u16,
-    pub(crate) istreams: u16,
-    pub(crate) retries: u16,
-    pub(crate) timeout: u16, // in miliseconds
+#[derive(Debug, Default)]
+pub(crate) struct EventSubscribe {
+    pub(crate) data_io: u8,
+    pub(crate) association: u8,
+    pub(crate) address: u8,
+    pub(crate) send_failure: u8,
+    pub(crate) peer_error: u8,
+    pub(crate) shutdown: u8,
+    pub(crate) partial_delivery: u8,
+    pub(crate) adaptation_layer: u8,
+    pub(crate) authentication: u8,
+    pub(crate) sender_dry: u8,
+    pub(crate) stream_reset: u8,
+    pub(crate) assoc_reset: u8,
+    pub(crate) stream_change: u8,
+    pub(crate) send_failure_event: u8,
+}
+
+impl EventSubscribe {
+    // Returns the flag in the structure corresponding to the `event`.
+    pub(crate) fn flag_mut(&mut self, event: &Event) -> Option<&mut u8> {
+        match event {
+            Event::DataIo => Some(&mut self.data_io),
+            Event::Association => Some(&mut self.association),
+            Event::Address => Some(&mut self.address),
+            Event::SendFailure => Some(&mut self.send_failure),
+            Event::PeerError => Some(&mut self.peer_error),
+            Event::Shutdown => Some(&mut self.shutdown),
+            Event::PartialDelivery => Some(&mut self.partial_delivery),
+            Event::AdaptationLayer => Some(&mut self.adaptation_layer),
+            Event::Authentication => Some(&mut self.authentication),
+            Event::SenderDry => Some(&mut self.sender_dry),
+            Event::StreamReset => Some(&mut self.stream_reset),
+            Event::AssociationReset => Some(&mut self.assoc_reset),
+            Event::StreamChange => Some(&mut self.stream_change),
+            Event::SendFailureEvent => Some(&mut self.send_failure_event),
+            Event::Unknown => None,
+        }
+    }
+
+    // Returns whether the flag corresponding to the `event` is within the first `size` bytes of
+    // the structure. An older kernel's `struct sctp_event_subscribe` is smaller than ours and
+    // does not have the flags for the newer events.
+    pub(crate) fn has_flag(event: &Event, size: usize) -> bool {
+        let mut subscribe = Self::default();
+        let base = &subscribe as *const Self as usize;
+        subscribe
+            .flag_mut(event)
+            .is_some_and(|flag| (flag as *mut u8 as usize) - base < size)
+    }
+}
+
+impl From<EventMask> for EventSubscribe {
+    fn from(mask: EventMask) -> Self {
+        let mut subscribe = Self::default();
+        for event in Event::all() {
+            if let Some(flag) = subscribe.flag_mut(&event) {
+                *flag = u8::from(mask.contains(&event));
+            }
+        }
+        subscribe
+    }
+}
+
+// Structure used by socket options taking an association ID and a value (`struct
+// sctp_assoc_value`).
+#[repr(C)]
+#[derive(Debug, Default)]
+pub(crate) struct AssocValue {
+    pub(crate) assoc_id: AssociationId,
+    pub(crate) assoc_value: u32,
+}
+
+// Structure used by `SCTP_DELAYED_SACK` socket option (`struct sctp_sack_info`).
+#[repr(C)]
+#[derive(Debug, Default)]
+pub(crate) struct SackInfo {
+    pub(crate) assoc_id: AssociationId,
+    pub(crate) delay: u32,
+    pub(crate) freq: u32,
+}
+
+// Structure used by `SCTP_DEFAULT_PRINFO` socket option (`struct sctp_default_prinfo`).
+#[repr(C)]
+#[derive(Debug, Default)]
+pub(crate) struct DefaultPrInfo {
+    pub(crate) assoc_id: AssociationId,
+    pub(crate) value: u32,
+    pub(crate) policy: u16,
+}
+
+// Structure used by `SCTP_PR_ASSOC_STATUS` socket option (`struct sctp_prstatus`).
+#[repr(C)]
+#[derive(Debug, Default)]
+pub(crate) struct PrStatusInternal {
+    pub(crate) assoc_id: AssociationId,
+    pub(crate) sid: u16,
+    pub(crate) policy: u16,
+    pub(crate) abandoned_unsent: u64,
+    pub(crate) abandoned_sent: u64,
+}
+
+// Structure used by `SCTP_STREAM_SCHEDULER_VALUE` socket option (`struct sctp_stream_value`).
+#[repr(C)]
+#[derive(Debug, Default)]
+pub(crate) struct StreamValue {
+    pub(crate) assoc_id: AssociationId,
+    pub(crate) stream_id: u16,
+    pub(crate) stream_value: u16,
 }
 
 // Structure used by connectx (using SCTP_SOCKOPT_CONNECTX3). This is required to get the
@@ -123,7 +227,7 @@ impl TryFrom<PeerAddrInternal> for PeerAddress {
                 );
                 os_socketaddr.into_addr().unwrap()
             } else {
-                return Err(std::io::Error::from_raw_os_error(22));
+                return Err(crate::SctpError::InvalidAddressFamily.into());
             }
         };
         Ok(Self {
@@ -138,11 +242,9 @@ impl TryFrom<PeerAddrInternal> for PeerAddress {
     }
 }
 
-impl TryFrom<ConnStatusInternal> for ConnStatus {
-    type Error = std::convert::Infallible;
-
-    fn try_from(val: ConnStatusInternal) -> Result<Self, Self::Error> {
-        Ok(Self {
+impl From<ConnStatusInternal> for ConnStatus {
+    fn from(val: ConnStatusInternal) -> Self {
+        Self {
             assoc_id: val.assoc_id,
             state: ConnState::from_i32(val.state),
             rwnd: val.rwnd,
@@ -152,6 +254,111 @@ impl TryFrom<ConnStatusInternal> for ConnStatus {
             outstreams: val.outstreams,
             fragmentation_pt: val.fragmentation_pt,
             peer_primary: val.peer_primary.try_into().unwrap(),
-        })
+        }
+    }
+}
+
+// Structure used for the remote UDP encapsulation port (RFC 6951) (`struct sctp_udpencaps`).
+#[repr(C)]
+pub(crate) struct UdpEncaps {
+    pub(crate) assoc_id: AssociationId,
+    pub(crate) address: libc::sockaddr_storage,
+    pub(crate) port: u16, // Network Byte Order
+}
+
+impl UdpEncaps {
+    pub(crate) fn new(
+        assoc_id: AssociationId,
+        addr: Option<std::net::SocketAddr>,
+        port: u16,
+    ) -> Self {
+        Self {
+            assoc_id,
+            address: sockaddr_storage_from(addr),
+            port: port.to_be(),
+        }
+    }
+}
+
+// Structure used for the peer address parameters (`struct sctp_paddrparams`). The C structure is
+// packed and aligned to 4 bytes, hence the explicit padding at the end.
+#[repr(C, packed)]
+pub(crate) struct PeerAddrParams {
+    pub(crate) assoc_id: AssociationId,
+    pub(crate) address: libc::sockaddr_storage,
+    pub(crate) hbinterval: u32,
+    pub(crate) pathmaxrxt: u16,
+    pub(crate) pathmtu: u32,
+    pub(crate) sackdelay: u32,
+    pub(crate) flags: u32,
+    pub(crate) ipv6_flowlabel: u32,
+    pub(crate) dscp: u8,
+    _pad: u8,
+}
+
+impl PeerAddrParams {
+    pub(crate) fn new(assoc_id: AssociationId, addr: std::net::SocketAddr, flags: u32) -> Self {
+        Self {
+            assoc_id,
+            address: sockaddr_storage_from(Some(addr)),
+            hbinterval: 0,
+            pathmaxrxt: 0,
+            pathmtu: 0,
+            sackdelay: 0,
+            flags,
+            ipv6_flowlabel: 0,
+            dscp: 0,
+            _pad: 0,
+        }
+    }
+}
+
+// Legacy Ancillary data for the received messages (`struct sctp_sndrcvinfo`), delivered in the
+// `SCTP_SNDRCV` cmsg, when the `Event::DataIo` is subscribed. (See Section 5.2.2 of RFC 6458)
+#[repr(C)]
+#[derive(Debug, Default)]
+pub(crate) struct SndRcvInfo {
+    pub(crate) sid: u16,
+    pub(crate) ssn: u16,
+    pub(crate) flags: u16,
+    pub(crate) ppid: u32,
+    pub(crate) context: u32,
+    pub(crate) timetolive: u32,
+    pub(crate) tsn: u32,
+    pub(crate) cumtsn: u32,
+    pub(crate) assoc_id: AssociationId,
+}
+
+impl From<SndRcvInfo> for RcvInfo {
+    fn from(val: SndRcvInfo) -> Self {
+        Self {
+            sid: val.sid,
+            ssn: val.ssn,
+            flags: RcvFlags::from_u16(val.flags),
+            ppid: val.ppid,
+            tsn: val.tsn,
+            cumtsn: val.cumtsn,
+            context: val.context,
+            assoc_id: val.assoc_id,
+        }
+    }
+}
+
+// Returns the `sockaddr_storage` with the `addr` copied in, all zeroes if `addr` is `None`.
+fn sockaddr_storage_from(addr: Option<std::net::SocketAddr>) -> libc::sockaddr_storage {
+    // Safety: An all zeroes `sockaddr_storage` is valid.
+    let mut address: libc::sockaddr_storage = unsafe { std::mem::zeroed() };
+    if let Some(addr) = addr {
+        let os_sockaddr: os_socketaddr::OsSocketAddr = addr.into();
+        let slice: &[u8] = os_sockaddr.as_ref();
+        // Safety: A `sockaddr_storage` is large enough for any of the socket addresses.
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                slice.as_ptr(),
+                &mut address as *mut _ as *mut u8,
+                slice.len(),
+            );
+        }
     }
+    address
 }
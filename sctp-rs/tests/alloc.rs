@@ -0,0 +1,88 @@
+//! Tests for the allocations made by the receive APIs.
+//!
+//! These are in a separate test binary, since the global allocator is replaced for counting the
+//! allocations.
+
+#![cfg(any(feature = "tokio", feature = "async-io"))]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use sctp_rs::{ConnectedSocket, NotificationOrData, ReceivedInfo};
+
+// Counts the allocations made by the current thread, so that the tests running in parallel do not
+// affect each other.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|count| count.get())
+}
+
+#[tokio::test]
+async fn recv_allocations() {
+    // The receive path does not depend on the protocol, a `SOCK_SEQPACKET` Unix socket pair is
+    // used since SCTP may not be available.
+    let mut fds = [0; 2];
+    // Safety: `fds` is valid for two file descriptors.
+    let result =
+        unsafe { libc::socketpair(libc::AF_UNIX, libc::SOCK_SEQPACKET, 0, fds.as_mut_ptr()) };
+    assert_eq!(result, 0, "{}", std::io::Error::last_os_error());
+    let send = |payload: &[u8]| {
+        // Safety: `payload` is valid for `payload.len()` bytes.
+        let sent = unsafe { libc::send(fds[1], payload.as_ptr() as *const _, payload.len(), 0) };
+        assert_eq!(sent, payload.len() as isize);
+    };
+
+    // Safety: `fds[0]` is a valid socket, it is set to non-blocking as expected by the
+    // `ConnectedSocket`.
+    let result = unsafe { libc::fcntl(fds[0], libc::F_SETFL, libc::O_NONBLOCK) };
+    assert_eq!(result, 0, "{}", std::io::Error::last_os_error());
+    let connected = ConnectedSocket::from_rawfd(fds[0]);
+    assert!(connected.is_ok(), "{:#?}", connected.err().unwrap());
+    let connected = connected.unwrap();
+
+    // Only the payload is allocated. The socket is waited on before counting, so that the
+    // allocations made by the runtime (if any) are not counted.
+    send(b"hello");
+    let result = connected.readable().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let before = allocations();
+    let received = connected.try_sctp_recv();
+    assert_eq!(allocations() - before, 1);
+    match received.unwrap() {
+        NotificationOrData::Data(data) => assert_eq!(data.payload, b"hello"),
+        received => panic!("Should never come here!: {:#?}", received),
+    }
+
+    // Nothing is allocated, when receiving into a buffer with enough capacity.
+    let mut buffer = Vec::with_capacity(4096);
+    send(b"world");
+    let result = connected.readable().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let before = allocations();
+    let received = connected.sctp_recv_into(&mut buffer).await;
+    assert_eq!(allocations() - before, 0);
+    assert!(matches!(received.unwrap(), ReceivedInfo::Data { .. }));
+    assert_eq!(buffer, b"world");
+
+    // Safety: `fds[1]` is not used after this. `fds[0]` is closed by the `connected`.
+    unsafe { libc::close(fds[1]) };
+}
@@ -0,0 +1,93 @@
+use sctp_rs::blocking::Socket;
+use sctp_rs::*;
+
+use std::net::SocketAddr;
+use std::sync::atomic::Ordering;
+
+use crate::TEST_PORT_NO;
+
+fn blocking_bind_and_listen(association: SocketToAssociation) -> (blocking::Listener, SocketAddr) {
+    let socket = Socket::new_v4(association);
+    assert!(socket.is_ok(), "{:#?}", socket.err().unwrap());
+    let socket = socket.unwrap();
+
+    let port = TEST_PORT_NO.fetch_add(1, Ordering::SeqCst);
+    let bindaddr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+
+    let result = socket.bind(bindaddr);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let listener = socket.listen(10);
+    assert!(listener.is_ok(), "{:#?}", listener.err().unwrap());
+
+    (listener.unwrap(), bindaddr)
+}
+
+#[test]
+fn blocking_one2one_connect_accept_send_recv() {
+    let (listener, bindaddr) = blocking_bind_and_listen(SocketToAssociation::OneToOne);
+
+    let server = std::thread::spawn(move || {
+        let accepted = listener.accept();
+        assert!(accepted.is_ok(), "{:#?}", accepted.err().unwrap());
+        let (accepted, _) = accepted.unwrap();
+
+        let received = accepted.sctp_recv();
+        assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+        let received = received.unwrap();
+        if let NotificationOrData::Data(data) = received {
+            let result = accepted.sctp_send(SendData::new(data.payload));
+            assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        } else {
+            panic!("Should never come here!: {:#?}", received);
+        }
+    });
+
+    let client = Socket::new_v4(SocketToAssociation::OneToOne);
+    assert!(client.is_ok(), "{:#?}", client.err().unwrap());
+    let result = client.unwrap().sctp_connectx(&[bindaddr]);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, assoc_id) = result.unwrap();
+
+    let status = connected.sctp_get_status(assoc_id);
+    assert!(status.is_ok(), "{:#?}", status.err().unwrap());
+
+    let result = connected.sctp_send(SendData::new(b"hello blocking".to_vec()));
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let received = connected.sctp_recv();
+    assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+    let received = received.unwrap();
+    if let NotificationOrData::Data(data) = received {
+        assert_eq!(data.payload, b"hello blocking".to_vec());
+        assert!(data.flags.contains(RecvFlags::EOR), "{:?}", data.flags);
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    }
+
+    server.join().unwrap();
+}
+
+#[test]
+fn blocking_connect_refused() {
+    let port = TEST_PORT_NO.fetch_add(1, Ordering::SeqCst);
+    let addr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+
+    let client = Socket::new_v4(SocketToAssociation::OneToOne);
+    assert!(client.is_ok(), "{:#?}", client.err().unwrap());
+    let result = client.unwrap().sctp_connectx(&[addr]);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+}
+
+#[test]
+fn blocking_socket_created_without_nonblock() {
+    use std::os::unix::io::AsRawFd;
+
+    let socket = Socket::new_v4(SocketToAssociation::OneToOne);
+    assert!(socket.is_ok(), "{:#?}", socket.err().unwrap());
+    let socket = socket.unwrap();
+
+    let flags = unsafe { libc::fcntl(socket.as_raw_fd(), libc::F_GETFL, 0) };
+    assert!(flags >= 0, "{:#?}", std::io::Error::last_os_error());
+    assert_eq!(flags & libc::O_NONBLOCK, 0);
+}
@@ -1,6 +1,9 @@
 use sctp_rs::*;
 
-use crate::{create_client_socket, create_socket_bind_and_listen};
+use crate::{
+    assert_peer_comm_lost, create_client_socket, create_socket_bind_and_listen, info_has_reason,
+    one2many_listener_associated,
+};
 
 #[tokio::test]
 async fn bindx_not_supported() {
@@ -33,18 +36,15 @@ async fn connected_default_sendinfo_success() {
     let sendinfo = SendInfo {
         sid,
         ppid,
-        flags: 0,
-        assoc_id: 0,
+        flags: SendFlags::default(),
+        assoc_id: 0.into(),
         context: 0,
     };
 
     let result = accepted.sctp_set_default_sendinfo(sendinfo);
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
 
-    let senddata = SendData {
-        payload: b"hello world!".to_vec(),
-        snd_info: None,
-    };
+    let senddata = SendData::new(b"hello world!".to_vec());
     let result = accepted.sctp_send(senddata.clone()).await;
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
 
@@ -61,8 +61,12 @@ async fn connected_default_sendinfo_success() {
         payload,
         rcv_info,
         nxt_info,
+        flags,
+        from,
     }) = data
     {
+        assert!(flags.contains(RecvFlags::EOR), "{:?}", flags);
+        assert!(from.is_some());
         assert!(
             payload == b"hello world!".to_vec(),
             "received_payload: {:?}",
@@ -87,8 +91,35 @@ async fn connected_default_sendinfo_success() {
         );
         assert!(nxt_info.is_none(), "{:#?}", nxt_info.unwrap());
     } else {
-        assert!(false, "Should never come here!: {:#?}", data);
+        panic!("Should never come here!: {:#?}", data);
+    };
+}
+
+#[tokio::test]
+async fn connected_get_default_sendinfo_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let sendinfo = SendInfo {
+        sid: 3,
+        ppid: 0x1234,
+        flags: SendFlags::default(),
+        assoc_id: 0.into(),
+        context: 0x5678,
     };
+    let result = connected.sctp_set_default_sendinfo(sendinfo.clone());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.sctp_get_default_sendinfo(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), sendinfo);
 }
 
 #[tokio::test]
@@ -114,14 +145,17 @@ async fn connected_send_some_sendinfo_success() {
     let snd_info = SendInfo {
         sid,
         ppid,
-        flags: 1,
-        assoc_id: 0,
+        flags: SendFlags::UNORDERED,
+        assoc_id: 0.into(),
         context: 0,
     };
 
     let senddata = SendData {
         payload: b"hello world!".to_vec(),
         snd_info: Some(snd_info),
+        pr_info: None,
+        auth_info: None,
+        dst_addrs: vec![],
     };
     let result = accepted.sctp_send(senddata).await;
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
@@ -139,8 +173,12 @@ async fn connected_send_some_sendinfo_success() {
         payload,
         rcv_info,
         nxt_info,
+        flags,
+        from,
     }) = data
     {
+        assert!(flags.contains(RecvFlags::EOR), "{:?}", flags);
+        assert!(from.is_some());
         assert!(
             payload == b"hello world!".to_vec(),
             "received_payload: {:?}",
@@ -163,13 +201,18 @@ async fn connected_send_some_sendinfo_success() {
             "rcv_info.ppid: {:x}, ppid: {:x}",
             rcv_info.ppid, ppid
         );
+        assert!(
+            rcv_info.flags.contains(RcvFlags::UNORDERED),
+            "{:#?}",
+            rcv_info
+        );
         assert!(nxt_info.is_none(), "{:#?}", nxt_info.unwrap());
     } else {
-        assert!(false, "Should never come here!: {:#?}", data);
+        panic!("Should never come here!: {:#?}", data);
     };
 }
 #[tokio::test]
-async fn test_shutdown_event() {
+async fn connected_send_pr_info_success() {
     let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
 
     let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
@@ -178,44 +221,60 @@ async fn test_shutdown_event() {
 
     let result = client_socket.sctp_connectx(&[bindaddr]).await;
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
-    let (connected, client_assoc_id) = result.unwrap();
-    let result = connected.sctp_subscribe_events(&[Event::Shutdown], SubscribeEventAssocId::All);
-    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
 
     let accept = listener.accept().await;
     assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
     let (accepted, _) = accept.unwrap();
 
-    // drop the connected socket, so that should generate shutdown event.
-    drop(accepted);
+    let result = accepted.pr_supported(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(result.unwrap());
+
+    let sid = 2;
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        snd_info: Some(SendInfo {
+            sid,
+            ..Default::default()
+        }),
+        pr_info: Some(PrInfo {
+            policy: PrPolicy::Ttl,
+            value: 1000,
+        }),
+        auth_info: None,
+        dst_addrs: vec![],
+    };
+    let result = accepted.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
 
     let result = connected.sctp_recv().await;
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
     let data = result.unwrap();
-    assert!(
-        matches!(
-            data,
-            NotificationOrData::Notification(Notification::Shutdown(Shutdown { .. }))
-        ),
-        "{:#?}",
-        data
-    );
 
-    if let NotificationOrData::Notification(Notification::Shutdown(Shutdown { assoc_id, .. })) =
-        data
+    if let NotificationOrData::Data(ReceivedData {
+        payload, rcv_info, ..
+    }) = data
     {
+        assert!(
+            payload == b"hello world!".to_vec(),
+            "received_payload: {:?}",
+            payload,
+        );
+        assert!(rcv_info.is_some());
+        let rcv_info = rcv_info.unwrap();
         assert_eq!(
-            client_assoc_id, assoc_id,
-            "client_assoc_id: {}, Event assoc_id: {}",
-            client_assoc_id, assoc_id
+            rcv_info.sid, sid,
+            "rcv_info.sid: {}, sid: {}",
+            rcv_info.sid, sid
         );
     } else {
-        assert!(false, "Should never come here!: {:#?}", data);
-    }
+        panic!("Should never come here!: {:#?}", data);
+    };
 }
 
 #[tokio::test]
-async fn test_get_status() {
+async fn connected_send_snd_info_and_pr_info_success() {
     let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
 
     let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
@@ -224,27 +283,1476 @@ async fn test_get_status() {
 
     let result = client_socket.sctp_connectx(&[bindaddr]).await;
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
-    let (connected, client_assoc_id) = result.unwrap();
+    let (connected, assoc_id) = result.unwrap();
 
     let accept = listener.accept().await;
     assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
-    let (accepted, client_addr) = accept.unwrap();
+    let (accepted, _) = accept.unwrap();
 
-    let result = connected.sctp_get_status(0);
+    let sid = 3;
+    let ppid = 0x42;
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        snd_info: Some(SendInfo {
+            sid,
+            ppid,
+            ..Default::default()
+        }),
+        pr_info: Some(PrInfo {
+            policy: PrPolicy::Rtx,
+            value: 3,
+        }),
+        auth_info: None,
+        dst_addrs: vec![],
+    };
+    let payload_len = senddata.payload.len();
+    let result = accepted.sctp_send(senddata).await;
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
-    let status = result.unwrap();
-    assert_eq!(
-        status.assoc_id, client_assoc_id,
-        "client assoc ID: {}, status assoc ID: {}",
-        client_assoc_id, status.assoc_id
-    );
+    assert_eq!(result.unwrap(), payload_len);
 
-    let result = accepted.sctp_get_status(0);
+    let result = connected.sctp_recv().await;
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
-    let status = result.unwrap();
-    assert_eq!(
-        client_addr, status.peer_primary.address,
-        "Client Addres: {}, Peer Primary Address: {}",
-        client_addr, status.peer_primary.address
-    );
+    let data = result.unwrap();
+
+    if let NotificationOrData::Data(ReceivedData {
+        payload, rcv_info, ..
+    }) = data
+    {
+        assert!(
+            payload == b"hello world!".to_vec(),
+            "received_payload: {:?}",
+            payload,
+        );
+        assert!(rcv_info.is_some());
+        let rcv_info = rcv_info.unwrap();
+        assert_eq!(rcv_info.assoc_id, assoc_id, "{:#?}", rcv_info);
+        assert_eq!(rcv_info.sid, sid, "{:#?}", rcv_info);
+        assert_eq!(rcv_info.ppid, ppid, "{:#?}", rcv_info);
+    } else {
+        panic!("Should never come here!: {:#?}", data);
+    };
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn connected_try_recv_would_block() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    // Nothing is sent yet, so should return `WouldBlock`.
+    let result = connected.try_sctp_recv();
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(result.err().unwrap().kind(), std::io::ErrorKind::WouldBlock);
+
+    let result = accepted.ready(tokio::io::Interest::WRITABLE).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(result.unwrap().is_writable());
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        ..Default::default()
+    };
+    let result = accepted.try_sctp_send(senddata);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    loop {
+        let result = connected.ready(tokio::io::Interest::READABLE).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+        match connected.try_sctp_recv() {
+            Ok(NotificationOrData::Data(ReceivedData { payload, .. })) => {
+                assert_eq!(payload, b"hello world!".to_vec());
+                break;
+            }
+            Ok(notification) => panic!("Should never come here!: {:#?}", notification),
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => continue,
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn connected_try_recv_none_when_empty() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let result = connected.try_recv();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(result.unwrap().is_none());
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        ..Default::default()
+    };
+    let result = accepted.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    loop {
+        let result = connected.ready(tokio::io::Interest::READABLE).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+        let result = connected.try_recv();
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        match result.unwrap() {
+            Some(NotificationOrData::Data(ReceivedData { payload, .. })) => {
+                assert_eq!(payload, b"hello world!".to_vec());
+                break;
+            }
+            Some(notification) => panic!("Should never come here!: {:#?}", notification),
+            None => continue,
+        }
+    }
+}
+
+#[tokio::test]
+async fn connected_recv_into_reuses_buffer() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let mut buf = Vec::with_capacity(1024);
+    let buf_ptr = buf.as_ptr();
+
+    for payload in [b"hello world!".to_vec(), b"hello again!".to_vec()] {
+        let senddata = SendData {
+            payload: payload.clone(),
+            ..Default::default()
+        };
+        let result = accepted.sctp_send(senddata).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+        let result = connected.sctp_recv_into(&mut buf).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let received = result.unwrap();
+        assert!(
+            matches!(received, ReceivedInfo::Data { .. }),
+            "{:#?}",
+            received
+        );
+        assert_eq!(buf, payload);
+        assert_eq!(buf.as_ptr(), buf_ptr, "Buffer should not be reallocated.");
+    }
+}
+
+#[tokio::test]
+async fn connected_graceful_close_delivers_data() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        ..Default::default()
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let peer = async {
+        let result = accepted.sctp_recv().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let received = result.unwrap();
+        if let NotificationOrData::Data(ReceivedData { payload, .. }) = received {
+            assert_eq!(payload, b"hello world!".to_vec());
+        } else {
+            panic!("Should never come here!: {:#?}", received);
+        }
+
+        // Peer sees an end of file once the shutdown is complete.
+        let result = accepted.sctp_recv().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let received = result.unwrap();
+        if let NotificationOrData::Data(ReceivedData { payload, .. }) = received {
+            assert!(payload.is_empty(), "{:#?}", payload);
+        } else {
+            panic!("Should never come here!: {:#?}", received);
+        }
+    };
+
+    let (result, _) = tokio::join!(connected.close(), peer);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+}
+
+#[tokio::test]
+async fn connected_into_split_send_recv_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let (read_half, write_half) = connected.into_split();
+
+    let reader = tokio::spawn(async move { read_half.sctp_recv().await });
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        ..Default::default()
+    };
+    let result = accepted.sctp_send(senddata.clone()).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = reader.await.unwrap();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    if let NotificationOrData::Data(ReceivedData { payload, .. }) = received {
+        assert_eq!(payload, b"hello world!".to_vec());
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    }
+
+    // Read half is dropped, Write half should still be usable.
+    let result = write_half.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = accepted.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    if let NotificationOrData::Data(ReceivedData { payload, .. }) = received {
+        assert_eq!(payload, b"hello world!".to_vec());
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    }
+}
+
+#[tokio::test]
+async fn connected_one2one_abort_einval() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let result = accepted.abort(b"going away").await;
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(result.err().unwrap().raw_os_error(), Some(libc::EINVAL));
+}
+
+#[tokio::test]
+async fn connected_peeled_off_abort_peer_comm_lost() {
+    let (listener, connected, assoc_id) =
+        one2many_listener_associated(SocketToAssociation::OneToOne).await;
+
+    let peeled_off = listener.sctp_peeloff(assoc_id);
+    assert!(peeled_off.is_ok(), "{:#?}", peeled_off.err().unwrap());
+    let peeled_off = peeled_off.unwrap();
+
+    let result = peeled_off.abort(b"going away").await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let change = assert_peer_comm_lost(&connected).await;
+    assert!(info_has_reason(&change, b"going away"), "{:#?}", change);
+}
+
+#[tokio::test]
+async fn connected_linger_zero_drop_aborts() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result =
+        client_socket.sctp_subscribe_events(&[Event::Association], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let result = accepted.set_linger(Some(std::time::Duration::ZERO));
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = accepted.linger();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), Some(std::time::Duration::ZERO));
+
+    drop(accepted);
+
+    assert_peer_comm_lost(&connected).await;
+}
+
+#[tokio::test]
+async fn connected_stream_priority_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (_connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let result = accepted.sctp_set_stream_scheduler(0.into(), StreamScheduler::Prio);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = accepted.sctp_get_stream_scheduler(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), StreamScheduler::Prio);
+
+    let result = accepted.sctp_set_stream_priority(0.into(), 0, 1);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = accepted.sctp_set_stream_priority(0.into(), 1, 10);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = accepted.sctp_get_stream_priority(0.into(), 0);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 1);
+
+    let result = accepted.sctp_get_stream_priority(0.into(), 1);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 10);
+
+    let status = accepted.sctp_get_status(0.into());
+    assert!(status.is_ok(), "{:#?}", status.err().unwrap());
+    let outstreams = status.unwrap().outstreams;
+
+    let result = accepted.sctp_set_stream_priority(0.into(), outstreams, 1);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    let err = result.err().unwrap();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(
+        matches!(
+            SctpError::from(err),
+            SctpError::InvalidStream { sid, outstreams: n } if sid == outstreams && n == outstreams
+        ),
+        "{:#?}",
+        outstreams
+    );
+}
+
+#[tokio::test]
+async fn test_shutdown_event() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_request_rcvinfo(true);
+    assert!(result.is_ok(), "{:?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, client_assoc_id) = result.unwrap();
+    let result = connected.sctp_subscribe_events(&[Event::Shutdown], SubscribeEventAssocId::All);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    // drop the connected socket, so that should generate shutdown event.
+    drop(accepted);
+
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let data = result.unwrap();
+    assert!(
+        matches!(
+            data,
+            NotificationOrData::Notification(Notification::Shutdown(Shutdown { .. }))
+        ),
+        "{:#?}",
+        data
+    );
+
+    if let NotificationOrData::Notification(Notification::Shutdown(Shutdown { assoc_id, .. })) =
+        data
+    {
+        assert_eq!(
+            client_assoc_id, assoc_id,
+            "client_assoc_id: {}, Event assoc_id: {}",
+            client_assoc_id, assoc_id
+        );
+    } else {
+        panic!("Should never come here!: {:#?}", data);
+    }
+}
+
+#[tokio::test]
+async fn test_get_status() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_request_rcvinfo(true);
+    assert!(result.is_ok(), "{:?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, client_assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, client_addr) = accept.unwrap();
+
+    let result = connected.sctp_get_status(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let status = result.unwrap();
+    assert_eq!(
+        status.assoc_id, client_assoc_id,
+        "client assoc ID: {}, status assoc ID: {}",
+        client_assoc_id, status.assoc_id
+    );
+
+    let result = accepted.sctp_get_status(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let status = result.unwrap();
+    assert_eq!(
+        client_addr, status.peer_primary.address,
+        "Client Addres: {}, Peer Primary Address: {}",
+        client_addr, status.peer_primary.address
+    );
+}
+
+#[tokio::test]
+async fn connected_stream_counts_match_status() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.sctp_get_status(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let status = result.unwrap();
+
+    let result = connected.stream_counts(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), (status.instreams, status.outstreams));
+}
+
+#[tokio::test]
+async fn connected_sctp_send_checked_invalid_stream() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.stream_counts(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (_, outstreams) = result.unwrap();
+
+    let send_data = |sid| SendData {
+        payload: b"hello world!".to_vec(),
+        snd_info: Some(SendInfo {
+            sid,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let result = connected.sctp_send_checked(send_data(outstreams)).await;
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    let err = result.err().unwrap();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(matches!(
+        SctpError::from(err),
+        SctpError::InvalidStream { sid, outstreams: o } if sid == outstreams && o == outstreams
+    ));
+
+    let result = connected.sctp_send_checked(send_data(outstreams - 1)).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+}
+
+#[tokio::test]
+async fn test_get_peer_addr_info() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, client_assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.sctp_get_peer_addr_info(0.into(), bindaddr);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let peer_addr_info = result.unwrap();
+    assert_eq!(peer_addr_info.address, bindaddr);
+    assert_eq!(peer_addr_info.assoc_id, client_assoc_id);
+    assert!(peer_addr_info.mtu > 0, "{:#?}", peer_addr_info);
+}
+
+#[tokio::test]
+async fn connected_context_in_send_failed_notification() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket
+        .sctp_subscribe_events(&[Event::SendFailureEvent], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    // The peer never receives, so that the messages are queued and abandoned.
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (_accepted, _) = accept.unwrap();
+
+    let context = 0xdead;
+    let result = connected.set_context(0.into(), context);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.context(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), context);
+
+    let senddata = SendData {
+        payload: vec![0; 1000],
+        snd_info: None,
+        pr_info: Some(PrInfo {
+            policy: PrPolicy::Ttl,
+            value: 1,
+        }),
+        auth_info: None,
+        dst_addrs: vec![],
+    };
+    for _ in 0..1000 {
+        match connected.try_sctp_send(senddata.clone()) {
+            Ok(_) => continue,
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => break,
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let notification = result.unwrap();
+    if let NotificationOrData::Notification(Notification::SendFailed(SendFailed {
+        snd_info, ..
+    })) = notification
+    {
+        assert_eq!(snd_info.context, context, "{:#?}", snd_info);
+    } else {
+        panic!("Should never come here!: {:#?}", notification);
+    }
+}
+
+#[tokio::test]
+async fn connected_bindx_not_supported() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.sctp_bindx(&[bindaddr], BindxFlags::Add);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    let err = result.err().unwrap();
+    assert_eq!(err.raw_os_error(), Some(libc::EOPNOTSUPP), "{:#?}", err);
+    assert!(
+        matches!(SctpError::from(err), SctpError::NotSupported),
+        "Should be `SctpError::NotSupported`."
+    );
+}
+
+#[tokio::test]
+async fn connected_set_max_burst_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.get_max_burst(assoc_id);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let max_burst = result.unwrap() + 2;
+
+    let result = connected.set_max_burst(assoc_id, max_burst);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.get_max_burst(assoc_id);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), max_burst);
+}
+
+#[tokio::test]
+async fn connected_set_delayed_sack_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.set_delayed_sack(assoc_id, 100, 4);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.get_delayed_sack(assoc_id);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), (100, 4));
+
+    let result = connected.set_delayed_sack(assoc_id, 0, 4);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.get_delayed_sack(assoc_id);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), (0, 1));
+}
+
+#[tokio::test]
+async fn connected_unsupported_notification_retains_raw_bytes() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    // `SCTP_AUTHENTICATION_EVENT` is not parsed by the crate. When the peer does not support
+    // `AUTH`, the event (`SCTP_AUTH_NO_AUTH`) is delivered when the association is up.
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket
+        .sctp_subscribe_events(&[Event::Authentication], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    if let NotificationOrData::Notification(Notification::Unsupported {
+        ev_type,
+        length,
+        data,
+        ..
+    }) = received
+    {
+        assert_eq!(ev_type, Event::Authentication as u16);
+        assert_eq!(length as usize, data.len());
+        assert_eq!(u16::from_ne_bytes([data[0], data[1]]), ev_type);
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    }
+}
+
+#[test]
+fn association_change_peer_capabilities() {
+    let mut assoc_change = AssociationChange {
+        ev_type: Event::Association,
+        flags: 0,
+        length: 26,
+        state: AssocChangeState::CommUp,
+        error: 0,
+        ob_streams: 10,
+        ib_streams: 10,
+        assoc_id: 1.into(),
+        info: vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x7F],
+    };
+
+    assert_eq!(
+        assoc_change.peer_capabilities(),
+        vec![
+            PeerCapability::PartialReliability,
+            PeerCapability::Auth,
+            PeerCapability::Asconf,
+            PeerCapability::MultiBuf,
+            PeerCapability::ReConfig,
+            PeerCapability::Interleaving,
+            PeerCapability::Unknown(0x7F),
+        ]
+    );
+
+    assoc_change.state = AssocChangeState::Restart;
+    assert_eq!(assoc_change.peer_capabilities().len(), 7);
+
+    // The `info` contains the `ABORT` chunk for the other states.
+    assoc_change.state = AssocChangeState::CommLost;
+    assert!(assoc_change.peer_capabilities().is_empty());
+
+    // Raw bytes are still available.
+    assert_eq!(assoc_change.info.len(), 7);
+}
+
+#[tokio::test]
+async fn connected_recv_eof_after_peer_close() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    drop(accepted);
+
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    assert_eq!(received, NotificationOrData::Eof);
+}
+
+#[tokio::test]
+async fn connected_linger_off_drop_shuts_down_gracefully() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.set_linger(Some(std::time::Duration::ZERO));
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.set_linger(None);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let result = connected.linger();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), None);
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    drop(connected);
+
+    // Graceful shutdown: The peer sees an end of file and not an error (from an `ABORT`).
+    let result = accepted.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), NotificationOrData::Eof);
+}
+
+#[tokio::test]
+async fn connected_wait_sender_dry_queues_received() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    // The peer sends a message, that is received (and queued) while waiting.
+    let senddata = SendData {
+        payload: b"from the peer".to_vec(),
+        ..Default::default()
+    };
+    let result = accepted.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    const MESSAGES: usize = 100;
+    for i in 0..MESSAGES {
+        let senddata = SendData {
+            payload: vec![i as u8; 1024],
+            ..Default::default()
+        };
+        let result = connected.sctp_send(senddata).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    }
+
+    let result = connected.wait_sender_dry().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    // All the sent data is acknowledged and hence received by the peer.
+    for i in 0..MESSAGES {
+        let result = accepted.sctp_recv().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let received = result.unwrap();
+        if let NotificationOrData::Data(ReceivedData { payload, .. }) = received {
+            assert_eq!(payload, vec![i as u8; 1024]);
+        } else {
+            panic!("Should never come here!: {:#?}", received);
+        }
+    }
+
+    // The message from the peer is not lost.
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    if let NotificationOrData::Data(ReceivedData { payload, .. }) = received {
+        assert_eq!(payload, b"from the peer".to_vec());
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    }
+}
+
+#[tokio::test]
+async fn connected_close_timeout_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    // Data from the peer that is never received is discarded while closing.
+    let senddata = SendData {
+        payload: b"never received".to_vec(),
+        ..Default::default()
+    };
+    let result = accepted.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let peer = async {
+        let result = accepted.sctp_recv().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let received = result.unwrap();
+        assert!(
+            matches!(received, NotificationOrData::Eof),
+            "{:#?}",
+            received
+        );
+    };
+
+    let (result, _) = tokio::join!(
+        connected.close_timeout(std::time::Duration::from_secs(5)),
+        peer
+    );
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn connected_split_events_routes_data_and_notifications() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let result = accepted.sctp_subscribe_events(&[Event::Shutdown], SubscribeEventAssocId::All);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    const MESSAGES: u8 = 3;
+    for i in 0..MESSAGES {
+        let senddata = SendData {
+            payload: vec![i; 16],
+            ..Default::default()
+        };
+        let result = connected.sctp_send(senddata).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    }
+
+    let result = connected.close().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let (mut data, mut notifications) = accepted.split_events(1, 1);
+
+    for i in 0..MESSAGES {
+        let received = data.recv().await;
+        assert!(received.is_some());
+        let result = received.unwrap();
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        assert_eq!(result.unwrap().payload, vec![i; 16]);
+    }
+    let received = data.recv().await;
+    assert!(received.is_none(), "{:#?}", received);
+
+    let received = notifications.recv().await;
+    assert!(
+        matches!(received, Some(Notification::Shutdown(_))),
+        "{:#?}",
+        received
+    );
+    let received = notifications.recv().await;
+    assert!(received.is_none(), "{:#?}", received);
+}
+
+#[tokio::test]
+async fn connected_sctp_send_to() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, client_addr) = accept.unwrap();
+
+    let result = accepted
+        .sctp_send_to(client_addr, SendData::new(b"hello world!".to_vec()))
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    if let NotificationOrData::Data(ReceivedData { payload, .. }) = received {
+        assert_eq!(payload, b"hello world!".to_vec());
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    }
+
+    // Not an address of the peer.
+    let unknown = format!("127.0.0.99:{}", client_addr.port())
+        .parse()
+        .unwrap();
+    let result = accepted
+        .sctp_send_to(unknown, SendData::new(b"hello world!".to_vec()))
+        .await;
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+}
+
+#[tokio::test]
+async fn connected_default_prinfo_abandons_messages() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    // The peer never receives, so that the messages are queued and abandoned.
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (_accepted, _) = accept.unwrap();
+
+    let pr_info = PrInfo {
+        policy: PrPolicy::Ttl,
+        value: 1,
+    };
+    let result = connected.set_default_prinfo(0.into(), pr_info.clone());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.get_default_prinfo(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), pr_info);
+
+    let senddata = SendData::new(vec![0; 1000]);
+    for _ in 0..1000 {
+        match connected.try_sctp_send(senddata.clone()) {
+            Ok(_) => continue,
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => break,
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    // Sending on a full socket, abandons the expired messages.
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    let _ = connected.try_sctp_send(senddata);
+
+    let result = connected.pr_status(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let pr_status = result.unwrap();
+    assert!(
+        pr_status.abandoned_unsent + pr_status.abandoned_sent > 0,
+        "{:#?}",
+        pr_status
+    );
+}
+
+#[tokio::test]
+async fn connected_send_auth_info_unknown_key_failure() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    // No such key is installed.
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        snd_info: Some(SendInfo {
+            sid: 1,
+            ..Default::default()
+        }),
+        pr_info: None,
+        auth_info: Some(AuthInfo { key_number: 100 }),
+        dst_addrs: vec![],
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+}
+
+#[tokio::test]
+async fn connected_send_dst_addrs() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        dst_addrs: vec![bindaddr],
+        ..Default::default()
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    // IPv6 destination on an IPv4 socket.
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        dst_addrs: vec![bindaddr, "[::1]:0".parse().unwrap()],
+        ..Default::default()
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(
+        result.err().unwrap().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+}
+
+#[tokio::test]
+async fn connected_recv_into_partial_message_flags() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let result = accepted.sctp_send(SendData::new(vec![1; 16])).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    // The message is received in two parts, only the last part has the `EOR` flag.
+    let mut buf = Vec::with_capacity(8);
+    for eor in [false, true] {
+        let result = connected.sctp_recv_into(&mut buf).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let received = result.unwrap();
+        if let ReceivedInfo::Data { flags, .. } = received {
+            assert_eq!(flags.contains(RecvFlags::EOR), eor, "{:?}", flags);
+        } else {
+            panic!("Should never come here!: {:#?}", received);
+        }
+        assert_eq!(buf, vec![1; 8]);
+    }
+}
+
+#[tokio::test]
+async fn connected_try_clone_send_after_drop() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let result = connected.try_clone();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let cloned = result.unwrap();
+
+    // Dropping the original handle does not close the association.
+    drop(connected);
+
+    let sender = tokio::spawn(async move { cloned.sctp_send(SendData::new(vec![7; 8])).await });
+    let result = sender.await.unwrap();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let received = accepted.sctp_recv().await;
+    assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+    let received = received.unwrap();
+    if let NotificationOrData::Data(data) = received {
+        assert_eq!(data.payload, vec![7; 8]);
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    }
+}
+
+#[tokio::test]
+async fn connected_sctp_send_vectored_single_message() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let header = b"header:";
+    let body = b"hello world!";
+    let bufs = [std::io::IoSlice::new(header), std::io::IoSlice::new(body)];
+    let result = connected.sctp_send_vectored(&bufs, None).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), header.len() + body.len());
+
+    let received = accepted.sctp_recv().await;
+    assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+    let received = received.unwrap();
+    if let NotificationOrData::Data(data) = received {
+        assert_eq!(data.payload, b"header:hello world!".to_vec());
+        assert!(data.flags.contains(RecvFlags::EOR), "{:?}", data.flags);
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    }
+}
+
+#[cfg(feature = "bytes")]
+#[tokio::test]
+async fn connected_sctp_send_buf_take_payload() {
+    use bytes::Buf;
+
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let buf =
+        bytes::Bytes::from_static(b"header:").chain(bytes::Bytes::from_static(b"hello world!"));
+    let result = connected.sctp_send_buf(buf, None).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 19);
+
+    let received = accepted.sctp_recv().await;
+    assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+    let received = received.unwrap();
+    if let NotificationOrData::Data(mut data) = received {
+        let payload = data.take_payload();
+        assert_eq!(payload, &b"header:hello world!"[..]);
+        assert_eq!(payload.slice(7..), &b"hello world!"[..]);
+        assert!(data.payload.is_empty());
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    }
+}
+
+#[tokio::test]
+async fn connected_set_recv_capacity_large_message() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    assert_eq!(accepted.recv_capacity(), 4096);
+
+    let result = accepted.set_recv_capacity(0);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(
+        result.err().unwrap().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+
+    let result = accepted.set_recv_capacity(32 * 1024);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    // The setting is used for every subsequent receive call.
+    for _ in 0..2 {
+        let result = connected.sctp_send(SendData::new(vec![5; 32 * 1024])).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+        let received = accepted.sctp_recv().await;
+        assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+        let received = received.unwrap();
+        if let NotificationOrData::Data(data) = received {
+            assert_eq!(data.payload.len(), 32 * 1024);
+            assert!(data.flags.contains(RecvFlags::EOR), "{:?}", data.flags);
+        } else {
+            panic!("Should never come here!: {:#?}", received);
+        }
+        assert_eq!(accepted.recv_capacity(), 32 * 1024);
+    }
+}
+
+#[tokio::test]
+async fn connected_request_heartbeat() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.request_heartbeat(0.into(), bindaddr);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    // Not a peer address of the association.
+    let other: std::net::SocketAddr = "127.0.0.2:1".parse().unwrap();
+    let result = connected.request_heartbeat(0.into(), other);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(result.err().unwrap().raw_os_error(), Some(libc::EINVAL));
+}
+
+#[tokio::test]
+async fn connected_send_large_message_small_sndbuf() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    // The kernel doubles the value and enforces a minimum, still much smaller than the message.
+    let result =
+        connected.setsockopt_raw(libc::SOL_SOCKET, libc::SO_SNDBUF, &1024_i32.to_ne_bytes());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = accepted.set_recv_capacity(64 * 1024);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    // The message is either sent in full or the send fails, it is never sent partially.
+    let result = connected.sctp_send(SendData::new(vec![3; 64 * 1024])).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 64 * 1024);
+
+    let received = accepted.sctp_recv().await;
+    assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+    let received = received.unwrap();
+    if let NotificationOrData::Data(data) = received {
+        assert_eq!(data.payload.len(), 64 * 1024);
+        assert!(data.flags.contains(RecvFlags::EOR), "{:?}", data.flags);
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    }
+}
+
+#[tokio::test]
+async fn connected_send_waits_for_blocked_receiver() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let result =
+        connected.setsockopt_raw(libc::SOL_SOCKET, libc::SO_SNDBUF, &4096_i32.to_ne_bytes());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    const MESSAGES: usize = 256;
+    const SIZE: usize = 4096;
+
+    // The receiver only starts reading after a while, so the sender fills up the send buffer and
+    // has to wait for writability, without `WouldBlock` ever being surfaced.
+    let sender = async {
+        for i in 0..MESSAGES {
+            let result = connected
+                .sctp_send(SendData::new(vec![i as u8; SIZE]))
+                .await;
+            assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        }
+    };
+    let receiver = async {
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        for i in 0..MESSAGES {
+            let received = accepted.sctp_recv().await;
+            assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+            let received = received.unwrap();
+            if let NotificationOrData::Data(data) = received {
+                assert_eq!(data.payload, vec![i as u8; SIZE]);
+            } else {
+                panic!("Should never come here!: {:#?}", received);
+            }
+        }
+    };
+    tokio::join!(sender, receiver);
+}
+
+#[tokio::test]
+async fn connected_peek_then_recv_same_message() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+    let result = connected.sctp_subscribe_events(&[Event::Shutdown], SubscribeEventAssocId::All);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let result = accepted.sctp_send(SendData::new(vec![7, 8, 9])).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    // Peeking twice returns the same message, which is then received.
+    let peeked = connected.sctp_peek().await;
+    assert!(peeked.is_ok(), "{:#?}", peeked.err().unwrap());
+    let peeked = peeked.unwrap();
+    let peeked_again = connected.sctp_peek().await;
+    assert!(peeked_again.is_ok(), "{:#?}", peeked_again.err().unwrap());
+    assert_eq!(peeked, peeked_again.unwrap());
+
+    let received = connected.sctp_recv().await;
+    assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+    let received = received.unwrap();
+    if let (NotificationOrData::Data(peeked), NotificationOrData::Data(received)) =
+        (&peeked, &received)
+    {
+        assert_eq!(peeked.payload, vec![7, 8, 9]);
+        assert_eq!(peeked.payload, received.payload);
+    } else {
+        panic!("Should never come here!: {:#?}, {:#?}", peeked, received);
+    }
+
+    // Notifications can be peeked as well.
+    drop(accepted);
+
+    let peeked = connected.sctp_peek().await;
+    assert!(peeked.is_ok(), "{:#?}", peeked.err().unwrap());
+    let peeked = peeked.unwrap();
+    assert!(
+        matches!(
+            peeked,
+            NotificationOrData::Notification(Notification::Shutdown(_))
+        ),
+        "{:#?}",
+        peeked
+    );
+
+    let received = connected.sctp_recv().await;
+    assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+    assert_eq!(peeked, received.unwrap());
+}
+
+#[tokio::test]
+async fn connected_readable_try_sctp_recv_loop() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    // Nothing to receive yet.
+    tokio::select! {
+        result = accepted.readable() => panic!("Should never come here!: {:#?}", result),
+        _ = tokio::time::sleep(std::time::Duration::from_millis(100)) => {}
+    }
+
+    for _ in 0..3 {
+        let result = connected.writable().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let result = connected.try_sctp_send(SendData::new(b"hello world!".to_vec()));
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    }
+
+    // Edge triggered loop: Receive till `WouldBlock`, before waiting for the readiness again.
+    let mut received = 0;
+    while received < 3 {
+        let result = accepted.readable().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        loop {
+            match accepted.try_sctp_recv() {
+                Ok(NotificationOrData::Data(data)) => {
+                    assert_eq!(data.payload, b"hello world!".to_vec());
+                    received += 1;
+                }
+                Ok(received) => panic!("Should never come here!: {:#?}", received),
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => break,
+                Err(e) => panic!("Should never come here!: {:#?}", e),
+            }
+        }
+    }
+    assert_eq!(received, 3);
 }
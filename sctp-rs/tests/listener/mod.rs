@@ -1,6 +1,9 @@
-use crate::{create_client_socket, create_socket_bind_and_listen};
+use crate::{
+    assert_peer_comm_lost, create_client_socket, create_socket_bind_and_listen, info_has_reason,
+    one2many_listener_associated,
+};
 use sctp_rs::*;
-use std::net::SocketAddr;
+use std::net::{Ipv6Addr, SocketAddr, SocketAddrV6};
 
 // Tests for `accept` API for Listening Socket.
 #[tokio::test]
@@ -17,7 +20,7 @@ async fn listening_one_2_one_listen_accept_success() {
 
     // Get Peer Address
     let (accepted, _address) = accept.unwrap();
-    let result = accepted.sctp_getpaddrs(0);
+    let result = accepted.sctp_getpaddrs(0.into());
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
 }
 
@@ -34,6 +37,157 @@ async fn listening_one_2_many_listen_accept_failure() {
     assert!(accept.is_err(), "{:#?}", accept.ok().unwrap());
 }
 
+#[tokio::test]
+async fn listening_one_2_one_poll_accept_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    // No pending connection yet.
+    let pending =
+        std::future::poll_fn(|cx| std::task::Poll::Ready(listener.poll_accept(cx).is_pending()))
+            .await;
+    assert!(pending);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let assoc_id = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(assoc_id.is_ok(), "{:#?}", assoc_id.err().unwrap());
+
+    let accept = std::future::poll_fn(|cx| listener.poll_accept(cx)).await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+}
+
+#[tokio::test]
+async fn listening_one_2_many_accept_assoc_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToMany, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        ..Default::default()
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let accept = listener.accept_assoc().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, assoc_id, address) = accept.unwrap();
+    assert!(assoc_id > 0.into());
+    assert!(address.is_ipv4(), "{:#?}", address);
+
+    // Data sent before the peeloff is received on the peeled off socket.
+    let result = accepted.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    if let NotificationOrData::Data(ReceivedData { payload, .. }) = received {
+        assert_eq!(payload, b"hello world!".to_vec());
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    }
+}
+
+#[tokio::test]
+async fn listening_one_2_many_recv_from_address() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.bind("127.0.0.1:0".parse().unwrap());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let result = connected.sctp_getladdrs(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let client_addrs = result.unwrap();
+    assert_eq!(client_addrs.len(), 1, "{:#?}", client_addrs);
+
+    let result = connected
+        .sctp_send(SendData::new(b"hello world!".to_vec()))
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = listener.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    if let NotificationOrData::Data(ReceivedData { payload, from, .. }) = received {
+        assert_eq!(payload, b"hello world!".to_vec());
+        assert_eq!(from, Some(client_addrs[0]));
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    }
+}
+
+#[tokio::test]
+async fn listening_one_2_one_incoming_success() {
+    use futures_core::Stream;
+
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let mut clients = vec![];
+    for _ in 0..2 {
+        let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+        let result = client_socket.sctp_connectx(&[bindaddr]).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        clients.push(result.unwrap());
+    }
+
+    let mut incoming = listener.incoming();
+    for _ in 0..2 {
+        let next = std::future::poll_fn(|cx| std::pin::Pin::new(&mut incoming).poll_next(cx)).await;
+        assert!(next.is_some());
+        let accept = next.unwrap();
+        assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    }
+}
+
+#[tokio::test]
+async fn listening_one_2_many_sctp_send_assoc_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let result = listener.sctp_request_rcvinfo(true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToMany, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let result = connected.sctp_send(SendData::new(b"ping".to_vec())).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = listener.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    let assoc_id = if let NotificationOrData::Data(ReceivedData {
+        rcv_info: Some(rcv_info),
+        ..
+    }) = received
+    {
+        rcv_info.assoc_id
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    };
+
+    let result = listener
+        .sctp_send_assoc(assoc_id, SendData::new(b"pong".to_vec()))
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    if let NotificationOrData::Data(ReceivedData { payload, .. }) = received {
+        assert_eq!(payload, b"pong".to_vec());
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    }
+}
+
 // Tests for `shutdown` API for Listening Socket.
 // TODO:
 
@@ -52,7 +206,7 @@ async fn listening_sctp_bindx_add_success() {
 async fn listening_socket_no_connect_peeloff_failure() {
     let (listener, _) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
 
-    let result = listener.sctp_peeloff(42);
+    let result = listener.sctp_peeloff(42.into());
     assert!(result.is_err(), "{:#?}", result.ok().unwrap());
 }
 
@@ -69,7 +223,7 @@ async fn listening_socket_one2one_connected_peeloff_failure() {
     let assoc_id = client_socket.sctp_connectx(&[bindaddr]).await;
     assert!(assoc_id.is_ok(), "{:#?}", assoc_id.err().unwrap());
 
-    let received = listener.sctp_peeloff(0);
+    let received = listener.sctp_peeloff(0.into());
     assert!(received.is_err(), "{:#?}", received.ok().unwrap());
 }
 
@@ -111,8 +265,467 @@ async fn listening_socket_one2many_connected_peeloff_success() {
         assert!(received.is_ok(), "{:#?}", received.err().unwrap());
         assert!(state == AssocChangeState::CommUp, "{:#?}", state);
     } else {
-        assert!(false, "Should never come here!: {:#?}", notification);
+        panic!("Should never come here!: {:#?}", notification);
+    };
+}
+
+// Tests for `abort` API for Listening Socket.
+
+#[tokio::test]
+async fn listening_socket_one2many_abort_peer_comm_lost() {
+    let (listener, connected, assoc_id) =
+        one2many_listener_associated(SocketToAssociation::OneToMany).await;
+
+    let result = listener.abort(assoc_id, b"going away").await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let change = assert_peer_comm_lost(&connected).await;
+    assert!(info_has_reason(&change, b"going away"), "{:#?}", change);
+}
+
+#[tokio::test]
+async fn listening_socket_one2many_abort_association_peer_comm_lost() {
+    let (listener, connected, assoc_id) =
+        one2many_listener_associated(SocketToAssociation::OneToOne).await;
+
+    let result = listener.abort_association(assoc_id).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    assert_peer_comm_lost(&connected).await;
+}
+
+#[tokio::test]
+async fn listening_socket_one2many_reconfig_supported_off() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let result = listener.set_reconfig_supported(0.into(), false);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = listener.reconfig_supported(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(!result.unwrap());
+
+    let result =
+        listener.sctp_subscribe_events(&[Event::Association], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let result = listener.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let notification = result.unwrap();
+    let assoc_id = if let NotificationOrData::Notification(Notification::AssociationChange(
+        AssociationChange { assoc_id, .. },
+    )) = notification
+    {
+        assoc_id
+    } else {
+        panic!("Should never come here!: {:#?}", notification);
     };
+
+    // RE-CONFIG is not negotiated on either of the ends.
+    let result = listener.reconfig_supported(assoc_id);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(!result.unwrap());
+
+    let result = connected.reconfig_supported(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(!result.unwrap());
+}
+
+#[tokio::test]
+async fn listening_socket_one2many_recv_batch_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let payloads = [b"one".to_vec(), b"two".to_vec(), b"three".to_vec()];
+    for payload in &payloads {
+        let senddata = SendData {
+            payload: payload.clone(),
+            ..Default::default()
+        };
+        let result = connected.sctp_send(senddata).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    }
+
+    let mut received = vec![];
+    while received.len() < payloads.len() {
+        let result = listener.sctp_recv_batch(8).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let messages = result.unwrap();
+        assert!(
+            !messages.is_empty() && messages.len() <= 8,
+            "{:#?}",
+            messages
+        );
+        for message in messages {
+            if let NotificationOrData::Data(ReceivedData { payload, .. }) = message {
+                received.push(payload);
+            }
+        }
+    }
+    assert_eq!(received, payloads);
+}
+
+#[tokio::test]
+async fn listening_socket_one2many_recv_batch_rcvinfo_per_message() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+    let result = listener.sctp_request_rcvinfo(true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    const MESSAGES: u16 = 64;
+    for sid in 0..MESSAGES {
+        let senddata = SendData {
+            payload: sid.to_be_bytes().to_vec(),
+            snd_info: Some(SendInfo {
+                sid: sid % 8,
+                ppid: u32::from(sid),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let result = connected.sctp_send(senddata).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    }
+
+    // Every message in a batch carries its own ancillary data.
+    let mut received = 0;
+    while received < MESSAGES {
+        let result = listener.sctp_recv_batch(16).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        for message in result.unwrap() {
+            if let NotificationOrData::Data(ReceivedData {
+                payload, rcv_info, ..
+            }) = message
+            {
+                let sent = u16::from_be_bytes([payload[0], payload[1]]);
+                assert!(rcv_info.is_some());
+                let rcv_info = rcv_info.unwrap();
+                assert_eq!(rcv_info.sid, sent % 8);
+                assert_eq!(rcv_info.ppid, u32::from(sent));
+                received += 1;
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn listening_socket_one2many_subscribe_all_events() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let mask = EventMask::default().with(Event::Association, true);
+    let result = listener.sctp_subscribe_all_events(mask);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToMany, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = listener.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let notification = result.unwrap();
+    assert!(
+        matches!(
+            notification,
+            NotificationOrData::Notification(Notification::AssociationChange(AssociationChange {
+                state: AssocChangeState::CommUp,
+                ..
+            }))
+        ),
+        "{:#?}",
+        notification
+    );
+}
+
+#[tokio::test]
+async fn listening_socket_one2many_send_batch_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let mut clients = vec![];
+    for _ in 0..2 {
+        let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+        let result = client_socket.sctp_connectx(&[bindaddr]).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let (connected, assoc_id) = result.unwrap();
+
+        let result = connected.sctp_getladdrs(assoc_id);
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let client_addr = result.unwrap()[0];
+        clients.push((connected, client_addr));
+    }
+
+    let msgs = clients
+        .iter()
+        .map(|(_, client_addr)| {
+            (
+                *client_addr,
+                SendData {
+                    payload: b"hello everyone!".to_vec(),
+                    ..Default::default()
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    let result = listener.sctp_send_batch(msgs).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), clients.len());
+
+    for (connected, _) in &clients {
+        let result = connected.sctp_recv().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let received = result.unwrap();
+        if let NotificationOrData::Data(ReceivedData { payload, .. }) = received {
+            assert_eq!(payload, b"hello everyone!".to_vec());
+        } else {
+            panic!("Should never come here!: {:#?}", received);
+        }
+    }
+}
+
+#[tokio::test]
+async fn listening_socket_one2many_autoclose_idle_association() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let result = listener.set_autoclose(1);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = listener.autoclose();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 1);
+
+    let result =
+        listener.sctp_subscribe_events(&[Event::Association], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (_connected, _) = result.unwrap();
+
+    // The listener first receives `CommUp` for the association and then the association is
+    // gracefully closed after it is idle.
+    loop {
+        let result = listener.sctp_recv().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let notification = result.unwrap();
+        if let NotificationOrData::Notification(Notification::AssociationChange(
+            AssociationChange { state, .. },
+        )) = notification
+        {
+            if state == AssocChangeState::CommUp {
+                continue;
+            }
+            assert_eq!(state, AssocChangeState::ShutdownComplete, "{:#?}", state);
+            break;
+        } else {
+            panic!("Should never come here!: {:#?}", notification);
+        }
+    }
+}
+
+#[tokio::test]
+async fn listening_socket_one2one_autoclose_not_supported() {
+    let (listener, _) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let result = listener.set_autoclose(1);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+}
+
+#[tokio::test]
+async fn listening_socket_one2many_association_ids_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let result =
+        listener.sctp_subscribe_events(&[Event::Association], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = listener.association_count();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 0);
+
+    let mut clients = vec![];
+    let mut assoc_ids = vec![];
+    for _ in 0..2 {
+        let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+        let result = client_socket.sctp_connectx(&[bindaddr]).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        clients.push(result.unwrap());
+
+        let result = listener.sctp_recv().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let notification = result.unwrap();
+        if let NotificationOrData::Notification(Notification::AssociationChange(
+            AssociationChange { assoc_id, .. },
+        )) = notification
+        {
+            assoc_ids.push(assoc_id);
+        } else {
+            panic!("Should never come here!: {:#?}", notification);
+        }
+    }
+
+    let result = listener.association_count();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 2);
+
+    let result = listener.association_ids();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let mut ids = result.unwrap();
+    ids.sort();
+    assoc_ids.sort();
+    assert_eq!(ids, assoc_ids);
+}
+
+#[tokio::test]
+async fn listening_socket_multihomed_peer_address_change() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let secondaddr: SocketAddr = format!("127.0.0.2:{}", bindaddr.port()).parse().unwrap();
+    let result = listener.sctp_bindx(&[secondaddr], BindxFlags::Add);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result =
+        client_socket.sctp_subscribe_events(&[Event::Address], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let notification = result.unwrap();
+    if let NotificationOrData::Notification(Notification::PeerAddressChange(PeerAddressChange {
+        address,
+        state,
+        assoc_id: notification_assoc_id,
+        ..
+    })) = notification
+    {
+        assert!(
+            address == bindaddr || address == secondaddr,
+            "address: {:#?}",
+            address
+        );
+        assert!(
+            !matches!(state, PeerAddressState::Unknown(_)),
+            "state: {:#?}",
+            state
+        );
+        assert_eq!(notification_assoc_id, assoc_id);
+    } else {
+        panic!("Should never come here!: {:#?}", notification);
+    }
+}
+
+#[tokio::test]
+async fn listening_socket_subscribe_events_checked_success() {
+    let (listener, _) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let events = [Event::Association, Event::Shutdown];
+    let results = listener.sctp_subscribe_events_checked(&events, SubscribeEventAssocId::Future);
+    assert_eq!(results.len(), events.len());
+    for ((event, result), expected) in results.iter().zip(events.iter()) {
+        assert_eq!(event, expected);
+        assert!(result.is_ok(), "{:#?}: {:#?}", event, result);
+    }
+}
+
+#[tokio::test]
+async fn listening_socket_subscribe_events_all_success() {
+    let (listener, _) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let result = listener.sctp_subscribe_events(&Event::all(), SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = listener.sctp_unsubscribe_events(&Event::all(), SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+}
+
+#[tokio::test]
+async fn listening_socket_subscribe_events_reports_failed_events() {
+    let (listener, _) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let events = [Event::Association, Event::Unknown, Event::Shutdown];
+    let results = listener.sctp_subscribe_events_checked(&events, SubscribeEventAssocId::Future);
+    assert!(results[0].1.is_ok(), "{:#?}", results[0]);
+    assert!(results[1].1.is_err(), "{:#?}", results[1]);
+    assert!(results[2].1.is_ok(), "{:#?}", results[2]);
+
+    let result = listener.sctp_subscribe_events(&events, SubscribeEventAssocId::Future);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    match SctpError::from(result.err().unwrap()) {
+        SctpError::EventSubscription(failures) => {
+            assert_eq!(failures.len(), 1, "{:#?}", failures);
+            assert_eq!(failures[0].0, Event::Unknown);
+        }
+        e => panic!("Should never come here!: {:#?}", e),
+    }
+}
+
+// Returns the first IPv6 link-local address and the interface index it is configured on.
+fn ipv6_link_local_address() -> Option<(Ipv6Addr, u32)> {
+    let if_inet6 = std::fs::read_to_string("/proc/net/if_inet6").ok()?;
+    if_inet6.lines().find_map(|line| {
+        let mut fields = line.split_whitespace();
+        let addr = u128::from_str_radix(fields.next()?, 16).ok()?;
+        let ifindex = u32::from_str_radix(fields.next()?, 16).ok()?;
+        let addr = Ipv6Addr::from(addr);
+        if addr.segments()[0] == 0xfe80 {
+            Some((addr, ifindex))
+        } else {
+            None
+        }
+    })
+}
+
+#[tokio::test]
+async fn listening_socket_v6_link_local_scope_id_preserved() {
+    let (addr, ifindex) = match ipv6_link_local_address() {
+        Some(link_local) => link_local,
+        // No interface with a link-local address, nothing to test.
+        None => return,
+    };
+
+    let sctp_socket = Socket::new_v6(SocketToAssociation::OneToMany);
+    assert!(sctp_socket.is_ok(), "{:#?}", sctp_socket.err().unwrap());
+    let sctp_socket = sctp_socket.unwrap();
+
+    let bindaddr = SocketAddr::V6(SocketAddrV6::new(addr, 0, 0, ifindex));
+    let result = sctp_socket.bind(bindaddr);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let listener = sctp_socket.listen(10);
+    assert!(listener.is_ok(), "{:#?}", listener.err().unwrap());
+    let listener = listener.unwrap();
+
+    let result = listener.sctp_getladdrs(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let laddrs = result.unwrap();
+    assert_eq!(laddrs.len(), 1, "{:#?}", laddrs);
+    if let SocketAddr::V6(laddr) = laddrs[0] {
+        assert_eq!(laddr.ip(), &addr);
+        assert_eq!(laddr.scope_id(), ifindex, "{:#?}", laddr);
+    } else {
+        panic!("Should never come here!: {:#?}", laddrs[0]);
+    }
 }
 
 // Tests for `sctp_getpaddrs` for Listening Socket.
@@ -2,10 +2,19 @@
 
 static TEST_PORT_NO: AtomicU16 = AtomicU16::new(8080);
 
-use sctp_rs::{Listener, Socket, SocketToAssociation};
+use std::sync::atomic::AtomicU16;
+
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+use sctp_rs::{
+    AssocChangeState, AssociationChange, AssociationId, ConnectedSocket, Event, Listener,
+    Notification, NotificationOrData, Socket, SocketToAssociation, SubscribeEventAssocId,
+};
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 use std::net::SocketAddr;
-use std::sync::atomic::{AtomicU16, Ordering};
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+use std::sync::atomic::Ordering;
 
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 fn create_socket_bind_and_listen(
     association: SocketToAssociation,
     v4: bool,
@@ -30,6 +39,7 @@ fn create_socket_bind_and_listen(
     (listener.unwrap(), bindaddr)
 }
 
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 fn create_client_socket(association: SocketToAssociation, v4: bool) -> Socket {
     let client_socket = if v4 {
         Socket::new_v4(association)
@@ -41,6 +51,78 @@ fn create_client_socket(association: SocketToAssociation, v4: bool) -> Socket {
     client_socket.unwrap()
 }
 
+// Sets up an association from a `client` socket to a One to Many listener, with both the sides
+// subscribed to the `Association` events.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+async fn one2many_listener_associated(
+    client: SocketToAssociation,
+) -> (Listener, ConnectedSocket, AssociationId) {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let result =
+        listener.sctp_subscribe_events(&[Event::Association], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let client_socket = create_client_socket(client, true);
+    let result =
+        client_socket.sctp_subscribe_events(&[Event::Association], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let result = listener.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let notification = result.unwrap();
+    let assoc_id = if let NotificationOrData::Notification(Notification::AssociationChange(
+        AssociationChange { assoc_id, .. },
+    )) = notification
+    {
+        assoc_id
+    } else {
+        panic!("Should never come here!: {:#?}", notification);
+    };
+
+    (listener, connected, assoc_id)
+}
+
+// The client first receives `CommUp` for the association and then `CommLost`, which is returned.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+async fn assert_peer_comm_lost(connected: &ConnectedSocket) -> AssociationChange {
+    loop {
+        let result = connected.sctp_recv().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let notification = result.unwrap();
+        if let NotificationOrData::Notification(Notification::AssociationChange(change)) =
+            notification
+        {
+            if change.state == AssocChangeState::CommUp {
+                continue;
+            }
+            assert_eq!(change.state, AssocChangeState::CommLost, "{:#?}", change);
+            break change;
+        } else {
+            panic!("Should never come here!: {:#?}", notification);
+        }
+    }
+}
+
+// The `info` of the `CommLost` notification for an `ABORT` carries the error causes sent by the
+// peer, that include the abort reason.
+#[cfg(any(feature = "tokio", feature = "async-io"))]
+fn info_has_reason(change: &AssociationChange, reason: &[u8]) -> bool {
+    change
+        .info
+        .windows(reason.len())
+        .any(|window| window == reason)
+}
+
+#[cfg(feature = "blocking")]
+mod blocking;
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 mod connected_socket;
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 mod listener;
+#[cfg(any(feature = "tokio", feature = "async-io"))]
 mod socket;
@@ -36,6 +36,9 @@ async fn socket_connect_basic_send_recv_req_info_on_and_off() {
     let senddata = SendData {
         payload: b"hello world!".to_vec(),
         snd_info: None,
+        pr_info: None,
+        auth_info: None,
+        dst_addrs: vec![],
     };
     let result = listener.sctp_send(client_addr, senddata.clone()).await;
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
@@ -53,8 +56,12 @@ async fn socket_connect_basic_send_recv_req_info_on_and_off() {
         payload,
         rcv_info,
         nxt_info,
+        flags,
+        from,
     }) = data
     {
+        assert!(flags.contains(RecvFlags::EOR), "{:?}", flags);
+        assert!(from.is_some());
         assert!(
             payload == b"hello world!".to_vec(),
             "received_payload: {:?}",
@@ -69,7 +76,7 @@ async fn socket_connect_basic_send_recv_req_info_on_and_off() {
         );
         assert!(nxt_info.is_none(), "{:#?}", nxt_info.unwrap());
     } else {
-        assert!(false, "Should never come here!: {:#?}", data);
+        panic!("Should never come here!: {:#?}", data);
     };
 
     // Now turn off Request Receive Info on client socket
@@ -93,8 +100,12 @@ async fn socket_connect_basic_send_recv_req_info_on_and_off() {
         payload,
         rcv_info,
         nxt_info,
+        flags,
+        from,
     }) = data
     {
+        assert!(flags.contains(RecvFlags::EOR), "{:?}", flags);
+        assert!(from.is_some());
         assert!(
             payload == b"hello world!".to_vec(),
             "received_payload: {:?}",
@@ -103,7 +114,7 @@ async fn socket_connect_basic_send_recv_req_info_on_and_off() {
         assert!(rcv_info.is_none(), "{:#?}", rcv_info.unwrap());
         assert!(nxt_info.is_none(), "{:#?}", nxt_info.unwrap());
     } else {
-        assert!(false, "Should never come here!: {:#?}", data);
+        panic!("Should never come here!: {:#?}", data);
     };
 }
 
@@ -136,6 +147,9 @@ async fn socket_send_recv_nxtinfo_test() {
     let senddata = SendData {
         payload: b"hello world!".to_vec(),
         snd_info: None,
+        pr_info: None,
+        auth_info: None,
+        dst_addrs: vec![],
     };
     let result = listener.sctp_send(client_addr, senddata.clone()).await;
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
@@ -158,8 +172,12 @@ async fn socket_send_recv_nxtinfo_test() {
         payload,
         rcv_info,
         nxt_info,
+        flags,
+        from,
     }) = data
     {
+        assert!(flags.contains(RecvFlags::EOR), "{:?}", flags);
+        assert!(from.is_some());
         assert!(
             payload == b"hello world!".to_vec(),
             "received_payload: {:?}",
@@ -168,7 +186,7 @@ async fn socket_send_recv_nxtinfo_test() {
         assert!(rcv_info.is_none(), "{:#?}", rcv_info.unwrap());
         assert!(nxt_info.is_some());
     } else {
-        assert!(false, "Should never come here!: {:#?}", data);
+        panic!("Should never come here!: {:#?}", data);
     };
 
     // First Receive nxtinfo should not be none.
@@ -185,8 +203,12 @@ async fn socket_send_recv_nxtinfo_test() {
         payload,
         rcv_info,
         nxt_info,
+        flags,
+        from,
     }) = data
     {
+        assert!(flags.contains(RecvFlags::EOR), "{:?}", flags);
+        assert!(from.is_some());
         assert!(
             payload == b"hello world!".to_vec(),
             "received_payload: {:?}",
@@ -195,10 +217,42 @@ async fn socket_send_recv_nxtinfo_test() {
         assert!(rcv_info.is_none(), "{:#?}", rcv_info.unwrap());
         assert!(nxt_info.is_none(), "{:#?}", nxt_info.unwrap());
     } else {
-        assert!(false, "Should never come here!: {:#?}", data);
+        panic!("Should never come here!: {:#?}", data);
     };
 }
 
+#[tokio::test]
+async fn socket_get_init_params_success() {
+    let client_socket = create_client_socket(SocketToAssociation::OneToMany, true);
+
+    let init_params = InitParams {
+        ostreams: 100,
+        istreams: 5,
+        retries: 3,
+        timeout: 1000,
+    };
+    let result = client_socket.sctp_set_init_params(init_params.clone());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_get_init_params();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), init_params);
+
+    #[allow(deprecated)]
+    let result = client_socket.sctp_setup_init_params(10, 10, 0, 0);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_get_init_params();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let result = result.unwrap();
+    assert_eq!(
+        (result.ostreams, result.istreams),
+        (10, 10),
+        "{:#?}",
+        result
+    );
+}
+
 #[tokio::test]
 async fn socket_init_params_set_ostreams_success() {
     let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
@@ -210,7 +264,12 @@ async fn socket_init_params_set_ostreams_success() {
     let client_ostreams = 100;
     let client_istreams = 5;
     let client_socket = create_client_socket(SocketToAssociation::OneToMany, true);
-    let result = client_socket.sctp_setup_init_params(client_ostreams, client_istreams, 0, 0);
+    let init_params = InitParams {
+        ostreams: client_ostreams,
+        istreams: client_istreams,
+        ..Default::default()
+    };
+    let result = client_socket.sctp_set_init_params(init_params);
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
 
     let assoc_id = client_socket.sctp_connectx(&[bindaddr]).await;
@@ -250,7 +309,7 @@ async fn socket_init_params_set_ostreams_success() {
             ob_streams
         );
     } else {
-        assert!(false, "Should never come here!: {:#?}", notification);
+        panic!("Should never come here!: {:#?}", notification);
     };
 }
 
@@ -265,6 +324,643 @@ async fn socket_sctp_req_recv_info_success() {
     assert!(result.is_ok(), "{:?}", result.err().unwrap());
 }
 
+#[tokio::test]
+async fn socket_set_pr_supported_success() {
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.set_pr_supported(0.into(), false);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.pr_supported(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(!result.unwrap());
+
+    let result = client_socket.set_pr_supported(0.into(), true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.pr_supported(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(result.unwrap());
+}
+
+#[tokio::test]
+async fn socket_pr_supported_off_not_negotiated() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.set_pr_supported(0.into(), false);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    // Neither of the ends uses PR-SCTP, since the client did not advertise it.
+    let result = connected.pr_supported(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(!result.unwrap());
+
+    let result = accepted.pr_supported(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(!result.unwrap());
+}
+
+#[tokio::test]
+async fn socket_set_ecn_off_connect_success() {
+    let (_listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_set_ecn(false);
+    if let Err(ref e) = result {
+        if e.kind() == std::io::ErrorKind::Unsupported {
+            // Older kernels, nothing to test.
+            return;
+        }
+    }
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_get_ecn();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(!result.unwrap());
+
+    let result = client_socket.sctp_set_ecn(true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_get_ecn();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(result.unwrap());
+
+    let result = client_socket.sctp_set_ecn(false);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+}
+
+#[tokio::test]
+async fn socket_reconfig_supported_off_not_negotiated() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.set_reconfig_supported(0.into(), false);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.reconfig_supported(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(!result.unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.reconfig_supported(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(!result.unwrap());
+}
+
+#[tokio::test]
+async fn socket_set_tos_success() {
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.set_tos(0xb8);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.tos();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 0xb8);
+
+    let result = client_socket.set_traffic_class(0xb8);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(
+        result.err().unwrap().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+}
+
+#[tokio::test]
+async fn socket_set_traffic_class_success() {
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, false);
+
+    let result = client_socket.set_traffic_class(0xb8);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.traffic_class();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 0xb8);
+
+    let result = client_socket.set_tos(0xb8);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(
+        result.err().unwrap().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+}
+
+#[tokio::test]
+async fn socket_v6_only_success() {
+    let v6_socket = create_client_socket(SocketToAssociation::OneToOne, false);
+
+    let result = v6_socket.set_v6_only(true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = v6_socket.v6_only();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(result.unwrap());
+
+    let v4_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = v4_socket.set_v6_only(true);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(result.err().unwrap().raw_os_error(), Some(libc::EINVAL));
+}
+
+#[tokio::test]
+async fn socket_set_udp_encaps_port_success() {
+    let client_socket = create_client_socket(SocketToAssociation::OneToMany, true);
+
+    let result = client_socket.sctp_set_udp_encaps_port(0.into(), None, 9899);
+    if let Err(ref e) = result {
+        if e.kind() == std::io::ErrorKind::Unsupported {
+            // Older kernels, nothing to test.
+            return;
+        }
+    }
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_get_udp_encaps_port(0.into(), None);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 9899);
+
+    let result = client_socket.sctp_set_udp_encaps_port(0.into(), None, 0);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_get_udp_encaps_port(0.into(), None);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn socket_set_maxseg_success() {
+    let client_socket = create_client_socket(SocketToAssociation::OneToMany, true);
+
+    let result = client_socket.set_maxseg(0.into(), 1000);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.get_maxseg(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 1000);
+}
+
+#[tokio::test]
+async fn socket_bind_device_success() {
+    // Safety: Just a wrapper over the `libc` call.
+    if unsafe { libc::geteuid() } != 0 {
+        // Requires `CAP_NET_RAW`, nothing to test.
+        return;
+    }
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.bind_device(Some("lo"));
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.device();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), Some("lo".to_string()));
+
+    let result = client_socket.bind_device(None);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.device();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), None);
+}
+
+#[tokio::test]
+async fn socket_raw_sockopt_success() {
+    const SOL_SCTP: i32 = 132;
+    const SCTP_NODELAY: i32 = 3;
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.setsockopt_raw(SOL_SCTP, SCTP_NODELAY, &1_i32.to_ne_bytes());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let mut buf = [0_u8; 4];
+    let result = client_socket.getsockopt_raw(SOL_SCTP, SCTP_NODELAY, &mut buf);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), buf.len());
+    assert_eq!(i32::from_ne_bytes(buf), 1);
+}
+
+#[tokio::test]
+async fn socket_subscribe_events_before_connect_applies_to_association() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_subscribe_events(
+        &[Event::Association, Event::Shutdown],
+        SubscribeEventAssocId::Future,
+    );
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result =
+        client_socket.sctp_unsubscribe_events(&[Event::Shutdown], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    if let NotificationOrData::Notification(Notification::AssociationChange(AssociationChange {
+        state,
+        ..
+    })) = received
+    {
+        assert_eq!(state, AssocChangeState::CommUp, "{:#?}", state);
+    } else {
+        panic!("Should never come here!: {:#?}", received);
+    }
+}
+
+#[tokio::test]
+async fn socket_connectx_assoc_id_one_to_one() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    // The association ID is ignored for the One to One sockets.
+    let result = connected.sctp_get_status(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap().assoc_id, assoc_id);
+}
+
+#[tokio::test]
+async fn socket_connectx_assoc_id_one_to_many() {
+    let (_listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToMany, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, assoc_id) = result.unwrap();
+    assert_ne!(assoc_id, 0.into());
+
+    let result = connected.sctp_get_status(assoc_id);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap().assoc_id, assoc_id);
+
+    // There is no 'default' association for the One to Many sockets.
+    let result = connected.sctp_get_status(0.into());
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+}
+
+#[tokio::test]
+async fn socket_connectx_one_to_one_no_listen_failure() {
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let connect_addr: SocketAddr = "127.0.0.53:8080".parse().unwrap();
+
+    let result = client_socket.sctp_connectx(&[connect_addr]).await;
+    assert!(result.is_err(), "{:?}", result.ok().unwrap());
+    let err = result.err().unwrap();
+    assert_eq!(err.raw_os_error(), Some(libc::ECONNREFUSED));
+}
+
+#[tokio::test]
+async fn socket_connect_timeout_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket
+        .connect_timeout(&[bindaddr], std::time::Duration::from_secs(5))
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+}
+
+#[tokio::test]
+async fn socket_connect_timeout_expired() {
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    // An address from TEST-NET-1 (RFC 5737), that should never respond.
+    let connect_addr: SocketAddr = "192.0.2.1:8080".parse().unwrap();
+
+    let result = client_socket
+        .connect_timeout(&[connect_addr], std::time::Duration::from_millis(100))
+        .await;
+    assert!(result.is_err(), "{:?}", result.ok().unwrap());
+    let err = result.err().unwrap();
+    assert_eq!(err.raw_os_error(), Some(libc::ETIMEDOUT), "{:#?}", err);
+}
+
+#[tokio::test]
+async fn socket_bound_addrs_ephemeral_port() {
+    let sctp_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let bindaddr: SocketAddr = "127.0.0.1:0".parse().unwrap();
+    let result = sctp_socket.bind(bindaddr);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = sctp_socket.bound_addrs();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let bound_addrs = result.unwrap();
+    assert_eq!(bound_addrs.len(), 1, "{:#?}", bound_addrs);
+    assert_eq!(bound_addrs[0].ip(), bindaddr.ip());
+    assert_ne!(bound_addrs[0].port(), 0);
+
+    let result = sctp_socket.sctp_getladdrs(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), bound_addrs);
+}
+
+#[tokio::test]
+async fn socket_debug_fmt() {
+    let socket = create_client_socket(SocketToAssociation::OneToMany, true);
+    let debug = format!("{:?}", socket);
+    assert!(debug.starts_with("Socket { fd: "), "{}", debug);
+    assert!(debug.ends_with("style: Some(OneToMany) }"), "{}", debug);
+
+    let (listener, _) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let debug = format!("{:?}", listener);
+    assert!(debug.starts_with("Listener { fd: "), "{}", debug);
+    assert!(debug.ends_with("style: Some(OneToOne) }"), "{}", debug);
+}
+
+// Returns the result of `fcntl(fd, cmd)` where `fd` is parsed from the `Debug` output of `socket`.
+fn socket_fcntl(socket: &impl std::fmt::Debug, cmd: i32) -> i32 {
+    let debug = format!("{:?}", socket);
+    let fd: i32 = debug
+        .split("fd: ")
+        .nth(1)
+        .and_then(|rest| rest.split(',').next())
+        .unwrap()
+        .parse()
+        .unwrap();
+    // Safety: Just a wrapper over the `libc` call on a valid fd.
+    let flags = unsafe { libc::fcntl(fd, cmd) };
+    assert!(flags >= 0, "{:#?}", std::io::Error::last_os_error());
+    flags
+}
+
+#[tokio::test]
+async fn socket_created_non_blocking() {
+    let socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    assert_ne!(socket_fcntl(&socket, libc::F_GETFL) & libc::O_NONBLOCK, 0);
+
+    let (listener, _) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, false);
+    assert_ne!(socket_fcntl(&listener, libc::F_GETFL) & libc::O_NONBLOCK, 0);
+}
+
+#[tokio::test]
+async fn socket_cloexec_flags() {
+    fn is_cloexec(socket: &Socket) -> bool {
+        socket_fcntl(socket, libc::F_GETFD) & libc::FD_CLOEXEC != 0
+    }
+
+    let socket = Socket::new_v4(SocketToAssociation::OneToOne);
+    assert!(socket.is_ok(), "{:#?}", socket.err().unwrap());
+    assert!(is_cloexec(&socket.unwrap()));
+
+    let socket = Socket::new_v6_with_flags(SocketToAssociation::OneToMany, SocketFlags::NONE);
+    assert!(socket.is_ok(), "{:#?}", socket.err().unwrap());
+    assert!(!is_cloexec(&socket.unwrap()));
+}
+
+#[tokio::test]
+async fn socket_set_default_prinfo_success() {
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let pr_info = PrInfo {
+        policy: PrPolicy::Rtx,
+        value: 3,
+    };
+    let result = client_socket.set_default_prinfo(0.into(), pr_info.clone());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.get_default_prinfo(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), pr_info);
+}
+
+#[tokio::test]
+async fn socket_set_auto_asconf() {
+    let socket = create_client_socket(SocketToAssociation::OneToMany, true);
+
+    // Not allowed unless the socket is bound to the wildcard address.
+    let result = socket.set_auto_asconf(true);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(result.err().unwrap().raw_os_error(), Some(libc::EINVAL));
+
+    let result = socket.bind("0.0.0.0:0".parse().unwrap());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = socket.set_auto_asconf(true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = socket.auto_asconf();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(result.unwrap());
+
+    let result = socket.set_auto_asconf(false);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = socket.auto_asconf();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(!result.unwrap());
+}
+
+#[tokio::test]
+async fn socket_send_recv_data_io_event_rcvinfo() {
+    let client_socket = create_client_socket(SocketToAssociation::OneToMany, true);
+
+    // Only the legacy `SCTP_SNDRCV` ancillary data is delivered, `RcvInfo` is not requested.
+    let result = client_socket.sctp_subscribe_events(&[Event::DataIo], SubscribeEventAssocId::All);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+
+    let sock_and_assoc_id = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(
+        sock_and_assoc_id.is_ok(),
+        "{:#?}",
+        sock_and_assoc_id.err().unwrap()
+    );
+    let (connected, assoc_id) = sock_and_assoc_id.unwrap();
+
+    let laddrs = connected.sctp_getladdrs(assoc_id);
+    assert!(laddrs.is_ok(), "{:#?}", laddrs.err().unwrap());
+    let client_addr = laddrs.unwrap()[0];
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        snd_info: Some(SendInfo {
+            sid: 1,
+            ppid: 42_u32.to_be(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let result = listener.sctp_send(client_addr, senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let data = result.unwrap();
+    if let NotificationOrData::Data(ReceivedData {
+        payload, rcv_info, ..
+    }) = data
+    {
+        assert_eq!(payload, b"hello world!".to_vec());
+        assert!(rcv_info.is_some());
+        let rcv_info = rcv_info.unwrap();
+        assert_eq!(rcv_info.sid, 1);
+        assert_eq!(rcv_info.ppid, 42_u32.to_be());
+        assert_eq!(rcv_info.assoc_id, assoc_id);
+    } else {
+        panic!("Should never come here!: {:#?}", data);
+    }
+}
+
+#[tokio::test]
+async fn socket_request_rcvinfo_nxtinfo_read_back() {
+    for assoc in [
+        SocketToAssociation::OneToOne,
+        SocketToAssociation::OneToMany,
+    ] {
+        let socket = create_client_socket(assoc.clone(), true);
+
+        let result = socket.rcvinfo_requested();
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        assert!(!result.unwrap(), "{:?}", assoc);
+        let result = socket.nxtinfo_requested();
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        assert!(!result.unwrap(), "{:?}", assoc);
+
+        let result = socket.sctp_request_rcvinfo(true);
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let result = socket.rcvinfo_requested();
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        assert!(result.unwrap(), "{:?}", assoc);
+        // The options are independent of each other.
+        let result = socket.nxtinfo_requested();
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        assert!(!result.unwrap(), "{:?}", assoc);
+
+        let result = socket.sctp_request_nxtinfo(true);
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let result = socket.nxtinfo_requested();
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        assert!(result.unwrap(), "{:?}", assoc);
+
+        let result = socket.sctp_request_rcvinfo(false);
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let result = socket.rcvinfo_requested();
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        assert!(!result.unwrap(), "{:?}", assoc);
+    }
+}
+
+#[tokio::test]
+async fn socket_v6_listener_v4_client_mapped_v4_addr_off() {
+    let server_socket = create_client_socket(SocketToAssociation::OneToOne, false);
+
+    let result = server_socket.sctp_get_mapped_v4_addr();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(
+        result.unwrap(),
+        "IPv4 mapped addresses should be on by default."
+    );
+
+    let result = server_socket.sctp_set_mapped_v4_addr(false);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let port = crate::TEST_PORT_NO.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    let bindaddr: SocketAddr = format!("[::]:{}", port).parse().unwrap();
+    let result = server_socket.bind(bindaddr);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let listener = server_socket.listen(10);
+    assert!(listener.is_ok(), "{:#?}", listener.err().unwrap());
+    let listener = listener.unwrap();
+
+    let result = listener.sctp_get_mapped_v4_addr();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(!result.unwrap());
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let connect_addr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+    let result = client_socket.sctp_connectx(&[connect_addr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, client_addr) = accept.unwrap();
+    assert!(client_addr.is_ipv4(), "{:#?}", client_addr);
+
+    let result = accepted.sctp_getpaddrs(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let paddrs = result.unwrap();
+    assert!(paddrs.iter().all(|a| a.is_ipv4()), "{:#?}", paddrs);
+}
+
+#[tokio::test]
+async fn socket_v6_listener_v4_client_mapped_v4_addr_on() {
+    let server_socket = create_client_socket(SocketToAssociation::OneToOne, false);
+
+    let result = server_socket.sctp_set_mapped_v4_addr(true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let port = crate::TEST_PORT_NO.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    let bindaddr: SocketAddr = format!("[::]:{}", port).parse().unwrap();
+    let result = server_socket.bind(bindaddr);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let listener = server_socket.listen(10);
+    assert!(listener.is_ok(), "{:#?}", listener.err().unwrap());
+    let listener = listener.unwrap();
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let connect_addr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+    let result = client_socket.sctp_connectx(&[connect_addr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, client_addr) = accept.unwrap();
+    let is_mapped = |addr: &SocketAddr| match addr.ip() {
+        IpAddr::V6(ip) => ip.to_ipv4_mapped() == Some(Ipv4Addr::LOCALHOST),
+        IpAddr::V4(_) => false,
+    };
+    assert!(is_mapped(&client_addr), "{:#?}", client_addr);
+
+    let result = accepted.sctp_getpaddrs(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let paddrs = result.unwrap();
+    assert!(paddrs.iter().all(is_mapped), "{:#?}", paddrs);
+}
+
 #[tokio::test]
 async fn test_bind_success() {
     let sctp_socket = create_client_socket(SocketToAssociation::OneToOne, true);